@@ -17,6 +17,7 @@ join-game-header-missing-url-string-2 = bei!
 
 cancel-button-text = ABBRECHEN
 go-back-button-text = ZURÜCK
+footer-forget-me-button = VERGISS MICH
 
 ## Errors
 
@@ -25,6 +26,8 @@ error-web-socket-message-receive-connection-error = Die Kommunikation mit der Lo
 error-web-socket-message-receive-connection-close = Die Verbindung zur Lobby wurde geschlossen.
 error-web-socket-message-receive-message-send-error = Die Kommunikation mit der Lobby schlug fehl während eine Nachricht gesendet wurde.
 error-web-socket-handle-message-player-name-already-in-use = Der Spielername wird in der Lobby bereits verwendet, bitte wähle einen Anderen.
+error-web-socket-handle-message-rejoin-failed = Das Spiel konnte nicht fortgesetzt werden, weil die Lobby nicht mehr existiert oder du aus ihr entfernt wurdest.
+error-web-socket-handle-message-kicked = Du wurdest vom Gastgeber oder per Abstimmung der anderen Spieler aus der Lobby entfernt.
 
 ## Game Creation Form
 
@@ -55,18 +58,44 @@ game-creation-form-timer-wanted-label = Sekunden zum Beantworten
 game-creation-form-timer-wanted-placeholder = { game-creation-form-timer-wanted-label }
 game-creation-form-timer-wanted-explanation = Leer Lassen, wenn kein Timer zum Beantworten erwünscht ist.
 
+game-creation-form-solution-time-label = Sekunden zum Anzeigen der Lösung
+game-creation-form-solution-time-placeholder = 30
+game-creation-form-scoring-mode-label = Punktemodus
+game-creation-form-scoring-mode-classic = Klassisch
+game-creation-form-scoring-mode-speed-bonus = Geschwindigkeitsbonus
+game-creation-form-scoring-mode-confidence-betting = Wetteinsatz
+game-creation-form-scoring-mode-explanation = Beim Geschwindigkeitsbonus bringen schnellere richtige Antworten zusätzliche Punkte. Beim Wetteinsatz setzen Spieler Punkte auf ihre Antwort und gewinnen oder verlieren den Einsatz. Der Einsatz steht mit der Antwort fest und ein Einsatz ohne Antwort ist verloren.
+game-creation-form-points-for-correct-answer-label = Punkte für eine richtige Antwort
+game-creation-form-points-for-correct-answer-placeholder = 10
+game-creation-form-points-for-minority-bonus-label = Bonuspunkte für eine richtige Minderheit
+game-creation-form-points-for-minority-bonus-placeholder = 5
+game-creation-form-minority-threshold-label = Minderheitsschwelle in Prozent
+game-creation-form-minority-threshold-placeholder = 50
+game-creation-form-points-penalty-for-wrong-answer-label = Punktabzug für eine falsche Antwort
+game-creation-form-points-penalty-for-wrong-answer-placeholder = 0
+game-creation-form-scoring-explanation = Richtig Antwortende erhalten zusätzlich den Bonus, wenn weniger als die Schwelle aller Spieler richtig geantwortet haben. Leer lassen für die Standardwerte.
+
+game-creation-form-penalty-mode-label = Strafen
+game-creation-form-penalty-mode-alcohol = Schlücke Alkohol
+game-creation-form-penalty-mode-soft-drink = Softdrinks und kleine Aufgaben
+game-creation-form-penalty-mode-dare = Mutproben
+game-creation-form-penalty-mode-explanation = Wähle, was Spieler anstatt Schlücken machen müssen, falls nicht jeder auf deiner Party trinkt.
+
 game-creation-form-submit-value-create = ERSTELLEN
 game-creation-form-submit-value-join = BEITRETEN
+game-creation-form-display-button = ALS GASTGEBER-BILDSCHIRM ÖFFNEN
 
 game-creation-form-error-message-player-name-empty = Der { game-creation-form-username-label } fehlt.
 game-creation-form-error-message-invite-code-empty = Der { game-creation-form-invite-code-label } fehlt.
 game-creation-form-error-message-max-questions-invalid = Die { game-creation-form-max-questions-label } konnte nicht verarbeitet werden.
 game-creation-form-error-message-minimum-score-invalid = Die { -minimum-score } konnte nicht verarbeitet werden.
 game-creation-form-error-message-timer-wanted-invalid = Die { game-creation-form-timer-wanted-label } konnte nicht verarbeitet werden.
+game-creation-form-error-message-scoring-invalid = Die Lösungszeit oder die Punktwerte konnten nicht verarbeitet werden.
 
 ## Connecting View
 
 connecting-view-connecting-string = Verbinden...
+connecting-view-reconnecting-string = Verbindung verloren, neu verbinden...
 
 ## Play View
 
@@ -75,17 +104,79 @@ play-view-type-of-player-player = Spieler
 
 play-view-exit-the-game = Das Spiel verlassen
 
+play-view-tiebreaker = Stechen
+
 play-view-players-headline = Spieler:
 play-view-players-no-one-here = Keiner da!
 play-view-players-is-watching = Zuschauend
+play-view-players-is-host = Gastgeber
+play-view-players-is-captain = Kapitän
+play-view-players-is-tiebreaker-contender = Im Stechen
+play-view-players-is-eliminated = Ausgeschieden
+play-view-players-streak = 🔥 { $streak }
+play-view-players-team-headline = Team { $team }
+play-view-players-without-team-headline = Ohne Team
+play-view-players-host-controls-make-watcher = Zum Zuschauer machen
+play-view-players-host-controls-make-player = Zum Spieler machen
+play-view-players-host-controls-transfer-host = Zum Gastgeber machen
+play-view-players-host-controls-kick = Entfernen
+play-view-players-vote-kick = Rauswerfen abstimmen ({ $votes })
 play-view-players-points = { $points } { $points ->
         [one] Punkt
         *[other] Punkte
     }
+play-view-players-drinks-sips = trinkt { $sips }
+play-view-players-drinks-sips-to-hand-out = verteilt { $sips }
+play-view-players-drinks-penalties-to-hand-out = verteilt { $sips } { $sips ->
+        [one] Strafe
+        *[other] Strafen
+    }
 play-view-players-points-explanation = Du erhältst 10 Punkte bei einer korrekten Antwort und 5 weitere Punkte, wenn weniger als die Hälfte der Spieler korrekt lagen.
 
+play-view-chat-headline = Chat
+play-view-chat-no-messages = Noch keine Nachrichten. Sag Hallo!
+play-view-chat-input-placeholder = Schreib eine Nachricht…
+play-view-chat-send-button = Senden
+
 lobby-view-welcome-headline = Willkommen!
 lobby-view-start-game-button = STARTEN
+lobby-view-waiting-for-host = Warte darauf, dass der Gastgeber das Spiel startet...
+lobby-view-configuration-headline = Einstellungen
+lobby-view-configuration-submit-value = EINSTELLUNGEN ÜBERNEHMEN
+lobby-view-configuration-count-of-teams-label = Anzahl der Teams
+lobby-view-configuration-team-answer-mode-label = Teamantwort
+lobby-view-configuration-team-answer-mode-majority-vote = Mehrheitsentscheid
+lobby-view-configuration-team-answer-mode-captain = Kapitän entscheidet
+lobby-view-configuration-teams-explanation = Lass die Anzahl der Teams leer, um ohne Teams zu spielen. In Teams werden Punkte und Getränke pro Team anhand der Teamantwort verteilt.
+lobby-view-configuration-game-mode-label = Spielmodus
+lobby-view-configuration-game-mode-classic = Klassisch
+lobby-view-configuration-game-mode-elimination = Last One Standing
+lobby-view-configuration-game-mode-lives = Leben
+lobby-view-configuration-count-of-lives-label = Anzahl der Leben
+lobby-view-configuration-game-mode-explanation = Bei Last One Standing scheidet aus, wer falsch oder gar nicht antwortet, mit Leben kostet es eins der Leben. Antwortet niemand richtig, scheidet niemand aus und niemand verliert ein Leben. Das Spiel endet, wenn nur noch ein Spieler übrig ist.
+lobby-view-configuration-streak-length-for-bonus-label = Richtige Antworten in Folge, um heiß zu laufen
+lobby-view-configuration-points-for-streak-bonus-label = Bonuspunkte beim Heißlaufen
+lobby-view-configuration-streak-explanation = Wer heiß läuft, bekommt für jede weitere richtige Antwort die Bonuspunkte. Reißt so eine Serie, kostet das Schlucke. Ohne Bonuspunkte sind Serien ausgeschaltet.
+lobby-view-configuration-sudden-death-tiebreaker-label = Geteilten ersten Platz per Stechen entscheiden (nicht in Teams)
+lobby-view-configuration-marathon-label = Marathon: spielen, bis keine Frage mehr übrig ist oder der Gastgeber das Spiel beendet
+lobby-view-configuration-error-message-teams-invalid = Bitte gib mindestens zwei Teams ein oder lass die Anzahl der Teams leer.
+lobby-view-configuration-error-message-lives-invalid = Bitte gib mindestens ein Leben an.
+lobby-view-balance-teams-button = TEAMS AUSGLEICHEN
+
+## Game View Tiebreaker
+
+game-view-tiebreaker-headline = Stechen!
+game-view-end-game-button = SPIEL BEENDEN
+game-view-pause-game-button = PAUSE
+game-view-resume-game-button = FORTSETZEN
+game-view-paused-headline = Spiel pausiert
+game-view-paused-waiting-for-host = Warte darauf, dass der Gastgeber das Spiel fortsetzt.
+game-view-tiebreaker-contenders = Gleichauf auf dem ersten Platz: { $players }
+game-view-tiebreaker-contender = Antworte richtig, um drin zu bleiben, wer zuletzt übrig ist, gewinnt.
+game-view-tiebreaker-eliminated = Du bist aus dem Stechen ausgeschieden.
+game-view-tiebreaker-watching = Schau zu, wer den ersten Platz holt.
+
+game-view-eliminated = Du bist ausgeschieden, schau den anderen beim Weiterspielen zu.
 
 ## Game View Question Playing State
 
@@ -94,9 +185,15 @@ game-view-question-playing-state-remaining-seconds = Es {$seconds ->
         *[other] verbleiben { $seconds } Sekunden
     }
 game-view-question-playing-state-infinite-remaining-seconds = {""}
+game-view-question-playing-state-vote-to-skip = Frage überspringen ({ $votes })
+game-view-question-playing-state-voted-to-skip = Für Überspringen gestimmt ({ $votes })
 
 game-view-question-playing-state-selection-button-the-onion = THE ONION
 game-view-question-playing-state-selection-button-not-the-onion = NOT THE ONION
+game-view-question-playing-state-stake-label = Dein Einsatz (du hast { $points } Punkte)
+game-view-question-playing-state-stake-button = Einsatz setzen
+game-view-question-playing-state-stake-placed = Du setzt { $points } Punkte.
+game-view-question-playing-state-captain-answers = Besprich dich mit deinem Team, { $captain } antwortet als Kapitän!
 
 ## Game View Solution Playing State
 
@@ -113,12 +210,39 @@ game-view-solution-playing-state-sub-headline-not-the-onion = Es ist NOT THE ONI
 game-view-solution-playing-state-sub-headline-player-answer-correct = Deine Antwort war richtig!
 game-view-solution-playing-state-sub-headline-player-answer-wrong = Deine Antwort war falsch!
 game-view-solution-playing-state-sub-headline-player-answer-missing = Deine Antwort hat gefehlt.
+game-view-solution-playing-state-sub-headline-player-stake-won = Du hast deinen Einsatz von { $points } Punkten gewonnen.
+game-view-solution-playing-state-sub-headline-player-stake-lost = Du hast deinen Einsatz von { $points } Punkten verloren.
+game-view-solution-playing-state-sub-headline-player-on-fire = Du läufst heiß mit { $streak } richtigen Antworten in Folge!
+game-view-solution-playing-state-sub-headline-player-streak-broken = Deine Serie ist gerissen.
+game-view-solution-playing-state-sub-headline-player-drinks-sips = Trink { $sips } { $sips ->
+        [one] Schluck
+        *[other] Schlücke
+    }!
+game-view-solution-playing-state-sub-headline-player-drinks-sips-to-hand-out = Verteile { $sips } { $sips ->
+        [one] Schluck
+        *[other] Schlücke
+    } an die Anderen!
+game-view-solution-playing-state-sub-headline-player-drinks-penalties-to-hand-out = Verteile { $sips } { $sips ->
+        [one] Strafe
+        *[other] Strafen
+    } an die Anderen!
 
 game-view-solution-playing-state-link-to-newspaper-posting-anchor-text = Link zum Artikel
+game-view-solution-playing-state-report-label = Stimmt etwas mit dieser Schlagzeile nicht?
+game-view-solution-playing-state-report-deleted-post = Gelöschter Beitrag
+game-view-solution-playing-state-report-duplicate = Doppelt
+game-view-solution-playing-state-report-needs-image = Braucht das Bild
+game-view-solution-playing-state-report-misleading = Irreführend
+game-view-solution-playing-state-report-thanks = Danke für das Melden dieser Schlagzeile!
+game-view-solution-playing-state-fastest-correct-answer = Schnellste richtige Antwort: { $player } ({ $seconds } s)
 
 ## Aftermath View
 
 aftermath-view-headline = Das Spiel ist vorbei
+aftermath-view-count-of-played-questions = { $count ->
+        [one] Es wurde eine Frage gespielt.
+        *[other] Es wurden { $count } Fragen gespielt.
+    }
 aftermath-view-next-round = NOCHMAL SPIELEN!
 aftermath-view-next-round-clicked = Warte auf andere Spieler für nächste Runde...
 aftermath-view-ranking-headline = Rangliste
@@ -127,3 +251,63 @@ aftermath-view-ranking-players-points = { $points } { $points ->
         [one] Punkt
         *[other] Punkte
     }
+aftermath-view-elimination-order-headline = Ausscheidungsreihenfolge
+aftermath-view-elimination-order-entry = Frage { $question }: { $players }
+
+aftermath-view-drinks-headline = Getränke
+aftermath-view-drinks-most-sips-taken = Meiste getrunkene Schlücke: { $players } ({ $sips } { $sips ->
+        [one] Schluck
+        *[other] Schlücke
+    })
+aftermath-view-drinks-most-sips-handed-out = Meiste verteilte Schlücke: { $players } ({ $sips } { $sips ->
+        [one] Schluck
+        *[other] Schlücke
+    })
+aftermath-view-drinks-most-penalties-taken = Meiste erhaltene Strafen: { $players } ({ $sips } { $sips ->
+        [one] Strafe
+        *[other] Strafen
+    })
+aftermath-view-drinks-most-penalties-handed-out = Meiste verteilte Strafen: { $players } ({ $sips } { $sips ->
+        [one] Strafe
+        *[other] Strafen
+    })
+
+aftermath-view-response-times-headline = Antwortzeiten
+aftermath-view-response-times-average = { $player }: { $seconds } s im Durchschnitt
+
+## Display
+
+display-view-count-of-answers = { $answers } / { $players } haben geantwortet
+display-view-scoreboard-headline = Punktestand
+display-view-close = Bildschirm schließen
+
+## Penalty Cards
+
+penalty-card-soft-drink-1 = Trink { $sips } { $sips ->
+        [one] Schluck
+        *[other] Schlücke
+    } von deinem Softdrink!
+penalty-card-soft-drink-2 = Mach { $sips } { $sips ->
+        [one] Liegestütz
+        *[other] Liegestütze
+    }!
+penalty-card-soft-drink-3 = Trink { $sips } { $sips ->
+        [one] Schluck
+        *[other] Schlücke
+    } Wasser!
+penalty-card-soft-drink-4 = Mach { $sips } { $sips ->
+        [one] Hampelmann
+        *[other] Hampelmänner
+    }!
+penalty-card-soft-drink-5 = Trink { $sips } { $sips ->
+        [one] Schluck
+        *[other] Schlücke
+    } von einem Softdrink, den dein linker Nachbar aussucht!
+penalty-card-dare-1 = Sprich bis zur nächsten Frage mit einem Akzent!
+penalty-card-dare-2 = Erzähl der Gruppe eine peinliche Geschichte!
+penalty-card-dare-3 = Lass die Gruppe dir bis zum Ende des Spiels einen Spitznamen geben!
+penalty-card-dare-4 = Sing den Refrain eines Liedes, das die Gruppe aussucht!
+penalty-card-dare-5 = Mach deine beste Imitation eines anderen Spielers!
+penalty-card-dare-6 = Beantworte die nächste Frage auf einem Bein stehend!
+penalty-card-dare-7 = Mach jedem Spieler im Raum ein Kompliment!
+penalty-card-dare-8 = Lies die nächste Schlagzeile wie ein Nachrichtensprecher vor!
@@ -16,6 +16,7 @@ join-game-header-missing-url-string-2 = {""}
 
 cancel-button-text = CANCEL
 go-back-button-text = GO BACK
+footer-forget-me-button = FORGET ME
 
 ## Errors
 
@@ -24,6 +25,8 @@ error-web-socket-message-receive-connection-error = The communication with the l
 error-web-socket-message-receive-connection-close = The connection to the lobby has been closed.
 error-web-socket-message-receive-message-send-error = The communication with the lobby failed when sending a message.
 error-web-socket-handle-message-player-name-already-in-use = The player name is already in use in this lobby, please choose another one.
+error-web-socket-handle-message-rejoin-failed = The game could not be resumed, because the lobby does not exist anymore or you have been removed from it.
+error-web-socket-handle-message-kicked = You have been removed from the lobby by the host or by a vote of the other players.
 
 
 ## Game Creation Form
@@ -55,18 +58,44 @@ game-creation-form-timer-wanted-label = Seconds to answer
 game-creation-form-timer-wanted-placeholder = { game-creation-form-timer-wanted-label }
 game-creation-form-timer-wanted-explanation = Leave Blank if no timer while answering is wished.
 
+game-creation-form-solution-time-label = Seconds to show the solution
+game-creation-form-solution-time-placeholder = 30
+game-creation-form-scoring-mode-label = Scoring mode
+game-creation-form-scoring-mode-classic = Classic
+game-creation-form-scoring-mode-speed-bonus = Speed bonus
+game-creation-form-scoring-mode-confidence-betting = Confidence betting
+game-creation-form-scoring-mode-explanation = With a speed bonus, faster correct answers earn extra points. With confidence betting, players stake their points on their answer and win or lose the stake. The stake is locked with the answer and a stake without an answer is lost.
+game-creation-form-points-for-correct-answer-label = Points for a correct answer
+game-creation-form-points-for-correct-answer-placeholder = 10
+game-creation-form-points-for-minority-bonus-label = Bonus points for a correct minority
+game-creation-form-points-for-minority-bonus-placeholder = 5
+game-creation-form-minority-threshold-label = Minority threshold in percent
+game-creation-form-minority-threshold-placeholder = 50
+game-creation-form-points-penalty-for-wrong-answer-label = Points lost for a wrong answer
+game-creation-form-points-penalty-for-wrong-answer-placeholder = 0
+game-creation-form-scoring-explanation = Correct players get the bonus on top, if fewer than the threshold of all players answered correctly. Leave Blank for the default values.
+
+game-creation-form-penalty-mode-label = Penalties
+game-creation-form-penalty-mode-alcohol = Sips of alcohol
+game-creation-form-penalty-mode-soft-drink = Soft drinks and small challenges
+game-creation-form-penalty-mode-dare = Dares
+game-creation-form-penalty-mode-explanation = Choose what players have to do instead of taking sips if not everyone at your party drinks.
+
 game-creation-form-submit-value-create = CREATE
 game-creation-form-submit-value-join = JOIN
+game-creation-form-display-button = OPEN AS HOST DISPLAY
 
 game-creation-form-error-message-player-name-empty = Playername is missing.
 game-creation-form-error-message-invite-code-empty = Invite Code is missing.
 game-creation-form-error-message-max-questions-invalid = Count of Questions could not be parsed.
 game-creation-form-error-message-minimum-score-invalid = { -minimum-score } could not be parsed.
 game-creation-form-error-message-timer-wanted-invalid = Timer could not be parsed.
+game-creation-form-error-message-scoring-invalid = Solution time or scoring values could not be parsed.
 
 ## Connecting View
 
 connecting-view-connecting-string = Connecting...
+connecting-view-reconnecting-string = Connection lost, reconnecting...
 
 ## Play View
 
@@ -75,17 +104,79 @@ play-view-type-of-player-player = Player
 
 play-view-exit-the-game = Exit The Game
 
+play-view-tiebreaker = Tiebreaker
+
 play-view-players-headline = Players:
 play-view-players-no-one-here = No one here!
 play-view-players-is-watching = Watching
+play-view-players-is-host = Host
+play-view-players-is-captain = Captain
+play-view-players-is-tiebreaker-contender = In the tiebreaker
+play-view-players-is-eliminated = Eliminated
+play-view-players-streak = 🔥 { $streak }
+play-view-players-team-headline = Team { $team }
+play-view-players-without-team-headline = Without Team
+play-view-players-host-controls-make-watcher = Make watcher
+play-view-players-host-controls-make-player = Make player
+play-view-players-host-controls-transfer-host = Make host
+play-view-players-host-controls-kick = Kick
+play-view-players-vote-kick = Vote to kick ({ $votes })
 play-view-players-points = { $points } { $points ->
         [one] Point
         *[other] Points
     }
+play-view-players-drinks-sips = drinks { $sips }
+play-view-players-drinks-sips-to-hand-out = hands out { $sips }
+play-view-players-drinks-penalties-to-hand-out = hands out { $sips } { $sips ->
+        [one] penalty
+        *[other] penalties
+    }
 play-view-players-points-explanation = You get 10 points on a correct answer, and 5 additional points if less than half of players guessed correctly.
 
+play-view-chat-headline = Chat
+play-view-chat-no-messages = No messages yet. Say hello!
+play-view-chat-input-placeholder = Write a message…
+play-view-chat-send-button = Send
+
 lobby-view-welcome-headline = Welcome!
 lobby-view-start-game-button = START
+lobby-view-waiting-for-host = Waiting for the host to start the game...
+lobby-view-configuration-headline = Settings
+lobby-view-configuration-submit-value = APPLY SETTINGS
+lobby-view-configuration-count-of-teams-label = Number of teams
+lobby-view-configuration-team-answer-mode-label = Team answer
+lobby-view-configuration-team-answer-mode-majority-vote = Majority vote
+lobby-view-configuration-team-answer-mode-captain = Captain decides
+lobby-view-configuration-teams-explanation = Leave the number of teams empty to play without teams. In teams, points and drinks are settled per team by the team answer.
+lobby-view-configuration-game-mode-label = Game mode
+lobby-view-configuration-game-mode-classic = Classic
+lobby-view-configuration-game-mode-elimination = Last one standing
+lobby-view-configuration-game-mode-lives = Lives
+lobby-view-configuration-count-of-lives-label = Number of lives
+lobby-view-configuration-game-mode-explanation = In last one standing a wrong or missing answer eliminates a player, with lives it costs one of the lives. Nobody is eliminated or loses a life, when nobody answers correctly. The game ends, when only one player is left.
+lobby-view-configuration-streak-length-for-bonus-label = Correct answers in a row for being on fire
+lobby-view-configuration-points-for-streak-bonus-label = Bonus points while on fire
+lobby-view-configuration-streak-explanation = Players on fire get the bonus points for every further correct answer. Breaking such a streak costs sips. Without bonus points, streaks are turned off.
+lobby-view-configuration-sudden-death-tiebreaker-label = Decide a tied first place by sudden death (not in teams)
+lobby-view-configuration-marathon-label = Marathon: play until no question is left or the host ends the game
+lobby-view-configuration-error-message-teams-invalid = Please enter at least two teams or leave the number of teams empty.
+lobby-view-configuration-error-message-lives-invalid = Please enter at least one life.
+lobby-view-balance-teams-button = BALANCE TEAMS
+
+## Game View Tiebreaker
+
+game-view-tiebreaker-headline = Sudden Death!
+game-view-end-game-button = END GAME
+game-view-pause-game-button = PAUSE
+game-view-resume-game-button = RESUME
+game-view-paused-headline = Game paused
+game-view-paused-waiting-for-host = Waiting for the host to resume the game.
+game-view-tiebreaker-contenders = Tied for the first place: { $players }
+game-view-tiebreaker-contender = Answer correctly to stay in, the last one standing wins.
+game-view-tiebreaker-eliminated = You have been eliminated from the tiebreaker.
+game-view-tiebreaker-watching = Watch who takes the first place.
+
+game-view-eliminated = You have been eliminated, watch the others play on.
 
 ## Game View Question Playing State
 
@@ -94,9 +185,15 @@ game-view-question-playing-state-remaining-seconds = { $seconds } {$seconds ->
         *[other] seconds
     } to go!
 game-view-question-playing-state-infinite-remaining-seconds = {""}
+game-view-question-playing-state-vote-to-skip = Skip question ({ $votes })
+game-view-question-playing-state-voted-to-skip = Voted to skip ({ $votes })
 
 game-view-question-playing-state-selection-button-the-onion = THE ONION
 game-view-question-playing-state-selection-button-not-the-onion = NOT THE ONION
+game-view-question-playing-state-stake-label = Your stake (you have { $points } points)
+game-view-question-playing-state-stake-button = Place stake
+game-view-question-playing-state-stake-placed = You bet { $points } points.
+game-view-question-playing-state-captain-answers = Discuss with your team, { $captain } answers as your captain!
 
 ## Game View Solution Playing State
 
@@ -113,12 +210,39 @@ game-view-solution-playing-state-sub-headline-not-the-onion = It's NOT THE ONION
 game-view-solution-playing-state-sub-headline-player-answer-correct = Your answer was correct!
 game-view-solution-playing-state-sub-headline-player-answer-wrong = Your answer was wrong!
 game-view-solution-playing-state-sub-headline-player-answer-missing = Your answer was missing.
+game-view-solution-playing-state-sub-headline-player-stake-won = You won your stake of { $points } points.
+game-view-solution-playing-state-sub-headline-player-stake-lost = You lost your stake of { $points } points.
+game-view-solution-playing-state-sub-headline-player-on-fire = You are on fire with { $streak } correct answers in a row!
+game-view-solution-playing-state-sub-headline-player-streak-broken = Your streak is broken.
+game-view-solution-playing-state-sub-headline-player-drinks-sips = Take { $sips } { $sips ->
+        [one] sip
+        *[other] sips
+    }!
+game-view-solution-playing-state-sub-headline-player-drinks-sips-to-hand-out = Hand out { $sips } { $sips ->
+        [one] sip
+        *[other] sips
+    } to the others!
+game-view-solution-playing-state-sub-headline-player-drinks-penalties-to-hand-out = Hand out { $sips } { $sips ->
+        [one] penalty
+        *[other] penalties
+    } to the others!
 
 game-view-solution-playing-state-link-to-newspaper-posting-anchor-text = Link to post
+game-view-solution-playing-state-report-label = Something wrong with this headline?
+game-view-solution-playing-state-report-deleted-post = Deleted post
+game-view-solution-playing-state-report-duplicate = Duplicate
+game-view-solution-playing-state-report-needs-image = Needs the image
+game-view-solution-playing-state-report-misleading = Misleading
+game-view-solution-playing-state-report-thanks = Thanks for reporting this headline!
+game-view-solution-playing-state-fastest-correct-answer = Fastest correct answer: { $player } ({ $seconds } s)
 
 ## Aftermath View
 
 aftermath-view-headline = The Game Has Ended
+aftermath-view-count-of-played-questions = { $count ->
+        [one] One question was played.
+        *[other] { $count } questions were played.
+    }
 aftermath-view-next-round = PLAY AGAIN!
 aftermath-view-next-round-clicked = Waiting on other players for net round...
 aftermath-view-ranking-headline = Ranking
@@ -127,3 +251,63 @@ aftermath-view-ranking-players-points = { $points } { $points ->
         [one] Point
         *[other] Points
     }
+aftermath-view-elimination-order-headline = Elimination Order
+aftermath-view-elimination-order-entry = Question { $question }: { $players }
+
+aftermath-view-drinks-headline = Drinks
+aftermath-view-drinks-most-sips-taken = Most sips taken: { $players } ({ $sips } { $sips ->
+        [one] sip
+        *[other] sips
+    })
+aftermath-view-drinks-most-sips-handed-out = Most sips handed out: { $players } ({ $sips } { $sips ->
+        [one] sip
+        *[other] sips
+    })
+aftermath-view-drinks-most-penalties-taken = Most penalties taken: { $players } ({ $sips } { $sips ->
+        [one] penalty
+        *[other] penalties
+    })
+aftermath-view-drinks-most-penalties-handed-out = Most penalties handed out: { $players } ({ $sips } { $sips ->
+        [one] penalty
+        *[other] penalties
+    })
+
+aftermath-view-response-times-headline = Response Times
+aftermath-view-response-times-average = { $player }: { $seconds } s on average
+
+## Display
+
+display-view-count-of-answers = { $answers } / { $players } answered
+display-view-scoreboard-headline = Scoreboard
+display-view-close = Close display
+
+## Penalty Cards
+
+penalty-card-soft-drink-1 = Take { $sips } { $sips ->
+        [one] sip
+        *[other] sips
+    } of your soft drink!
+penalty-card-soft-drink-2 = Do { $sips } push-{ $sips ->
+        [one] up
+        *[other] ups
+    }!
+penalty-card-soft-drink-3 = Drink { $sips } { $sips ->
+        [one] sip
+        *[other] sips
+    } of water!
+penalty-card-soft-drink-4 = Do { $sips } jumping { $sips ->
+        [one] jack
+        *[other] jacks
+    }!
+penalty-card-soft-drink-5 = Take { $sips } { $sips ->
+        [one] sip
+        *[other] sips
+    } of a soft drink your left neighbour picks!
+penalty-card-dare-1 = Speak with an accent until the next question!
+penalty-card-dare-2 = Tell the group an embarrassing story!
+penalty-card-dare-3 = Let the group choose a nickname for you until the end of the game!
+penalty-card-dare-4 = Sing the chorus of a song the group chooses!
+penalty-card-dare-5 = Do your best impression of another player!
+penalty-card-dare-6 = Answer the next question standing on one leg!
+penalty-card-dare-7 = Compliment every player in the room!
+penalty-card-dare-8 = Read the next headline aloud like a news anchor!
@@ -0,0 +1,140 @@
+use std::rc::Rc;
+
+use fluent_templates::LanguageIdentifier;
+
+use onion_or_not_the_onion_drinking_game_2_shared_library::model::game::{
+    ChatContent, ChatMessage, Emoji, Game, MAXIMUM_CHAT_MESSAGE_LENGTH,
+};
+
+use web_sys::{HtmlInputElement, SubmitEvent};
+
+use yew::{
+    classes, function_component, html, use_context, use_node_ref, use_state_eq, Callback, Classes,
+    Html, NodeRef,
+};
+
+use crate::components::locale::{locale, LocaleComponent};
+
+#[function_component(ChatComponent)]
+pub fn chat_component(props: &ChatProps) -> Html {
+    let langid: LanguageIdentifier = use_context().expect("Missing LanguageIdentifier context.");
+    let game: Rc<Game> = use_context().expect("Missing Game context.");
+
+    let is_expanded = use_state_eq(|| false);
+    let chat_text_node_ref = use_node_ref();
+
+    let cloned_is_expanded = is_expanded.clone();
+    let onclick_toggle = Callback::from(move |_| cloned_is_expanded.set(!*cloned_is_expanded));
+
+    html! {
+        <aside class={classes!("chat-container", props.class.clone())}>
+            <button class={classes!("chat-container__toggle")} onclick={onclick_toggle} type="button">
+                <LocaleComponent keyid="play-view-chat-headline"/>
+                { format!(" ({})", game.chat_history.len()) }
+            </button>
+            if *is_expanded {
+                { view_chat_history(&game) }
+                { view_reactions(props) }
+                { view_chat_form(props, &langid, &chat_text_node_ref) }
+            }
+        </aside>
+    }
+}
+
+fn view_chat_history(game: &Rc<Game>) -> Html {
+    if game.chat_history.is_empty() {
+        html! {
+            <p class={classes!("chat-container__empty")}>
+                <LocaleComponent keyid="play-view-chat-no-messages"/>
+            </p>
+        }
+    } else {
+        html! {
+            <ol class={classes!("chat-history")}>
+                { for game.chat_history.iter().rev().map(|chat_message| view_chat_message(game, chat_message)) }
+            </ol>
+        }
+    }
+}
+
+fn view_chat_message(game: &Rc<Game>, chat_message: &ChatMessage) -> Html {
+    let mut element_classes = classes!("chat-history__message");
+    if chat_message.player_id == game.this_player_id {
+        element_classes.push("chat-history__message--own");
+    }
+    let content = match &chat_message.content {
+        ChatContent::Text(text) => html! {
+            <span class={classes!("chat-history__text")}>{ text }</span>
+        },
+        ChatContent::Reaction(emoji) => html! {
+            <span class={classes!("chat-history__reaction")}>{ emoji.to_string() }</span>
+        },
+    };
+    html! {
+        <li class={element_classes}>
+            <span class={classes!("chat-history__player-name")}>{ chat_message.player_name.to_string() }</span>
+            { content }
+        </li>
+    }
+}
+
+fn view_reactions(props: &ChatProps) -> Html {
+    let reaction_buttons = Emoji::ALL
+        .into_iter()
+        .map(|emoji| {
+            let cloned_on_react = props.on_react.clone();
+            let onclick = Callback::from(move |_| cloned_on_react.emit(emoji));
+            html! {
+                <button class={classes!("chat-reactions__button")} {onclick} type="button">
+                    { emoji.to_string() }
+                </button>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <div class={classes!("chat-reactions")}>
+            { reaction_buttons }
+        </div>
+    }
+}
+
+fn view_chat_form(
+    props: &ChatProps,
+    langid: &LanguageIdentifier,
+    chat_text_node_ref: &NodeRef,
+) -> Html {
+    let cloned_on_send_chat_message = props.on_send_chat_message.clone();
+    let cloned_chat_text_node_ref = chat_text_node_ref.clone();
+    let onsubmit = Callback::from(move |event: SubmitEvent| {
+        event.prevent_default();
+        event.stop_propagation();
+        let chat_text_element = cloned_chat_text_node_ref
+            .cast::<HtmlInputElement>()
+            .unwrap();
+        let chat_text = chat_text_element.value().trim().to_string();
+        if !chat_text.is_empty() {
+            cloned_on_send_chat_message.emit(chat_text);
+            chat_text_element.set_value("");
+        }
+    });
+
+    html! {
+        <form class={classes!("chat-form")} {onsubmit}>
+            <input class={classes!("chat-form__input")} type="text" ref={chat_text_node_ref.clone()}
+                maxlength={MAXIMUM_CHAT_MESSAGE_LENGTH.to_string()}
+                placeholder={locale("play-view-chat-input-placeholder", langid)} />
+            <button class={classes!("button", "chat-form__button")} type="submit">
+                <LocaleComponent keyid="play-view-chat-send-button"/>
+            </button>
+        </form>
+    }
+}
+
+#[derive(yew::Properties, PartialEq)]
+pub struct ChatProps {
+    #[prop_or_default]
+    pub class: Classes,
+    pub on_send_chat_message: Callback<String>,
+    pub on_react: Callback<Emoji>,
+}
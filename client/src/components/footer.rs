@@ -8,7 +8,7 @@ use tap::{TapFallible, TapOptional};
 
 use yew::{classes, function_component, html, use_context, Callback, Html};
 
-use crate::components::locale::{locale, LOCALES};
+use crate::components::locale::{locale, LocaleComponent, LOCALES};
 
 #[function_component(FooterComponent)]
 pub fn footer_component(props: &FooterProps) -> Html {
@@ -48,11 +48,19 @@ pub fn footer_component(props: &FooterProps) -> Html {
             )
         });
 
+    let on_forget_me = props.on_forget_me.clone();
+    let onclick_forget_me = Callback::from(move |_| on_forget_me.emit(()));
+
     html! {
         <footer class={classes!("footer")}>
             <nav class={classes!("footer__locales-selection")}>
                 {locale_change_buttons}
             </nav>
+            <div class={classes!("footer__forget-me")}>
+                <button type="button" class={classes!("button", "footer__forget-me-button")} onclick={onclick_forget_me}>
+                    <LocaleComponent keyid="footer-forget-me-button"/>
+                </button>
+            </div>
             <div class={classes!("footer__copyright")}>{"\u{00a9} 2023 Thimo \"Tiquthon\" Neumann"}</div>
             if let Some(build_information) = build_information {
                 <div class={classes!("footer__build-stamp")}>
@@ -67,6 +75,8 @@ pub fn footer_component(props: &FooterProps) -> Html {
 pub struct FooterProps {
     #[prop_or_default]
     pub on_change_language_identifier: Callback<LanguageIdentifier>,
+    #[prop_or_default]
+    pub on_forget_me: Callback<()>,
 }
 
 struct BuildInformation {
@@ -1,17 +1,39 @@
-use yew::{classes, function_component, html, use_effect_with_deps, use_state_eq, Html};
+use qrcode::render::svg;
+use qrcode::QrCode;
+
+use yew::virtual_dom::{VNode, VRaw};
+use yew::{
+    classes, function_component, html, use_effect_with_deps, use_state_eq, AttrValue, Callback,
+    Html,
+};
 
 use crate::components::locale::LocaleComponent;
+use crate::routes::Route;
 use crate::utils::{retrieve_browser_location, ReplaceProtocol};
 
 #[function_component(JoinGameComponent)]
 pub fn join_game_component(props: &JoinGameComponentProps) -> Html {
     let optional_url_for_view = use_state_eq::<Option<String>, _>(|| None);
+    let optional_join_link = use_state_eq::<Option<String>, _>(|| None);
+    let is_qr_code_enlarged = use_state_eq(|| false);
 
     let cloned_optional_url_for_view = optional_url_for_view.clone();
+    let cloned_optional_join_link = optional_join_link.clone();
+    let cloned_invite_code = props.invite_code.clone();
     use_effect_with_deps(
         move |_| {
             let url_for_view = retrieve_browser_location_without_protocol();
             cloned_optional_url_for_view.set(Some(url_for_view));
+
+            let join_link = format!(
+                "{}{}",
+                retrieve_browser_location(None, None),
+                Route::Join {
+                    invite_code: cloned_invite_code
+                }
+                .to_path()
+            );
+            cloned_optional_join_link.set(Some(join_link));
         },
         (),
     );
@@ -45,16 +67,62 @@ pub fn join_game_component(props: &JoinGameComponentProps) -> Html {
         }
     };
 
+    let qr_code_html = match (&*optional_join_link, props.with_qr_code) {
+        (Some(join_link), true) => {
+            let mut qr_code_classes = classes!("join-panel__qr-code");
+            if *is_qr_code_enlarged {
+                qr_code_classes.push("join-panel__qr-code--enlarged");
+            }
+            let cloned_is_qr_code_enlarged = is_qr_code_enlarged.clone();
+            let onclick_qr_code = Callback::from(move |_| {
+                cloned_is_qr_code_enlarged.set(!*cloned_is_qr_code_enlarged)
+            });
+            html! {
+                <button class={qr_code_classes} onclick={onclick_qr_code} type="button">
+                    { render_qr_code_svg(join_link) }
+                </button>
+            }
+        }
+        (None, _) | (_, false) => html! {},
+    };
+
     html! {
         <aside class={classes!("join-panel")}>
             { inner_html }
+            { qr_code_html }
         </aside>
     }
 }
 
+/// Renders the link as QR code in an inline SVG, so that it can be scanned by the party guests.
+fn render_qr_code_svg(link: &str) -> Html {
+    match QrCode::new(link.as_bytes()) {
+        Ok(qr_code) => {
+            let svg = qr_code
+                .render::<svg::Color>()
+                .min_dimensions(200, 200)
+                .quiet_zone(true)
+                .build();
+            // The XML declaration isn't needed, when the SVG is part of the HTML
+            let svg = svg
+                .find("<svg")
+                .map_or(svg.as_str(), |start_of_svg| &svg[start_of_svg..]);
+            VNode::VRaw(VRaw {
+                html: AttrValue::from(svg.to_string()),
+            })
+        }
+        Err(error) => {
+            log::error!("Failed creating QR code for \"{link}\" ({error})");
+            html! {}
+        }
+    }
+}
+
 #[derive(yew::Properties, PartialEq)]
 pub struct JoinGameComponentProps {
     pub invite_code: String,
+    #[prop_or_default]
+    pub with_qr_code: bool,
 }
 
 fn retrieve_browser_location_without_protocol() -> String {
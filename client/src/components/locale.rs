@@ -142,3 +142,7 @@ pub fn store_language_identifier_to_persistent_storage(
     LocalStorage::set(LOCAL_STORAGE_KEY_LANGUAGE_IDENTIFIER, language_identifier.to_string())
         .with_context(|| format!("Could not store selected LanguageIdentifier to LocalStorage \"{LOCAL_STORAGE_KEY_LANGUAGE_IDENTIFIER}\"."))
 }
+
+pub fn forget_language_identifier_in_persistent_storage() {
+    LocalStorage::delete(LOCAL_STORAGE_KEY_LANGUAGE_IDENTIFIER);
+}
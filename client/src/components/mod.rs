@@ -1,7 +1,9 @@
+pub mod chat;
 pub mod footer;
 pub mod header;
 pub mod join_game;
 pub mod locale;
+pub mod penalty;
 pub mod player_name_type_exit_headline;
 pub mod playerlist;
 pub mod svg;
@@ -0,0 +1,27 @@
+use onion_or_not_the_onion_drinking_game_2_shared_library::model::game::{
+    PenaltyCard, PenaltyMode,
+};
+
+use yew::{function_component, html, Html};
+
+use crate::components::locale::{locale_args, LocaleComponent};
+
+#[function_component(PenaltyCardComponent)]
+pub fn penalty_card_component(props: &PenaltyCardProps) -> Html {
+    let penalty_mode = match props.penalty_card.penalty_mode {
+        PenaltyMode::Alcohol => "alcohol",
+        PenaltyMode::SoftDrink => "soft-drink",
+        PenaltyMode::Dare => "dare",
+    };
+    let keyid = format!("penalty-card-{penalty_mode}-{}", props.penalty_card.number);
+
+    html! {
+        <LocaleComponent {keyid} args={locale_args([("sips", props.sips.into())])} />
+    }
+}
+
+#[derive(yew::Properties, PartialEq)]
+pub struct PenaltyCardProps {
+    pub penalty_card: PenaltyCard,
+    pub sips: u16,
+}
@@ -19,15 +19,21 @@ pub fn player_name_type_exit_headline_component(
     let player_name = this_player.name.to_string();
     let is_watcher = this_player.is_watcher();
 
-    let count_of_questions = game.configuration.count_of_questions.to_string();
+    // A marathon has no fixed number of questions
+    let optional_count_of_questions =
+        (!game.configuration.marathon).then(|| game.configuration.count_of_questions.to_string());
 
-    let number_of_current_question = match &game.game_state {
-        GameState::InLobby => "0".to_string(),
+    let optional_number_of_current_question = match &game.game_state {
+        GameState::InLobby => Some("0".to_string()),
         GameState::Playing {
             index_of_current_question,
             ..
-        } => (index_of_current_question + 1).to_string(),
-        GameState::Aftermath { .. } => count_of_questions.to_string(),
+        } => Some((index_of_current_question + 1).to_string()),
+        GameState::Tiebreaker { .. } => None,
+        GameState::Aftermath {
+            count_of_played_questions,
+            ..
+        } => Some(count_of_played_questions.to_string()),
     };
 
     let cloned_on_exit_game_wished = props.on_exit_game_wished.clone();
@@ -45,9 +51,15 @@ pub fn player_name_type_exit_headline_component(
                     }
                 }</span>
                 {" | "}
-                {number_of_current_question}
-                {" / "}
-                {count_of_questions}
+                if let Some(number_of_current_question) = optional_number_of_current_question {
+                    {number_of_current_question}
+                    if let Some(count_of_questions) = optional_count_of_questions {
+                        {" / "}
+                        {count_of_questions}
+                    }
+                } else {
+                    <LocaleComponent keyid="play-view-tiebreaker"/>
+                }
                 {" | "}
                 <button type="button" class={classes!("button", "player-type-and-exit__exit-game-link")} onclick={onclick_exit_game}>
                     <LocaleComponent keyid="play-view-exit-the-game"/>
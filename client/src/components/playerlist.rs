@@ -3,13 +3,19 @@ use std::rc::Rc;
 use fluent_templates::LanguageIdentifier;
 
 use onion_or_not_the_onion_drinking_game_2_shared_library::model::game::{
-    Game, GameState, PlayType, Player, PlayingState,
+    Drinks, Game, GameState, PenaltyMode, PlayType, Player, PlayerId, PlayingState, TeamId,
+    TeamSetup,
 };
 
-use yew::{classes, function_component, html, use_context, Classes, Html};
+use web_sys::HtmlSelectElement;
+
+use yew::{
+    classes, function_component, html, use_context, Callback, Classes, Event, Html, TargetCast,
+};
 
 use crate::components::locale::{locale_args, LocaleComponent};
-use crate::components::svg::{CORRECT_SVG, FAST_FORWARD_SVG, INCORRECT_SVG, PENCIL_SVG};
+use crate::components::penalty::PenaltyCardComponent;
+use crate::components::svg::{CORRECT_SVG, FAST_FORWARD_SVG, HEART_SVG, INCORRECT_SVG, PENCIL_SVG};
 
 #[function_component(PlayerListComponent)]
 pub fn player_list_component(props: &PlayerListProps) -> Html {
@@ -21,7 +27,7 @@ pub fn player_list_component(props: &PlayerListProps) -> Html {
             <h3 class={classes!("player-list-container__headline")}>
                 <LocaleComponent keyid="play-view-players-headline"/>
             </h3>
-            { view_player_list(&game) }
+            { view_player_list(&game, props) }
             <p class={classes!("player-list-container__points-explanation")}>
                 <LocaleComponent keyid="play-view-players-points-explanation" />
             </p>
@@ -29,32 +35,112 @@ pub fn player_list_component(props: &PlayerListProps) -> Html {
     }
 }
 
-fn view_player_list(game: &Rc<Game>) -> Html {
+fn view_player_list(game: &Rc<Game>, props: &PlayerListProps) -> Html {
     if game.players.is_empty() {
         html! {
             <p class={classes!("player-list-container__empty")}>
                 <LocaleComponent keyid="play-view-players-no-one-here"/>
             </p>
         }
+    } else if let Some(team_setup) = game.configuration.team_setup {
+        view_player_list_grouped_by_team(game, team_setup, props)
     } else {
-        let player_list_items = game
-            .players
-            .iter()
-            .map(|player: &Player| view_player(game, player))
-            .collect::<Html>();
+        view_players(game, game.players.iter(), props)
+    }
+}
+
+fn view_player_list_grouped_by_team(
+    game: &Rc<Game>,
+    team_setup: TeamSetup,
+    props: &PlayerListProps,
+) -> Html {
+    let teams_html = (0..team_setup.count_of_teams)
+        .map(TeamId)
+        .map(|team| {
+            html! {
+                <>
+                    <h4 class={classes!("player-list-container__team-headline")}>
+                        <LocaleComponent keyid="play-view-players-team-headline"
+                            args={locale_args([("team", team.to_string().into())])}/>
+                    </h4>
+                    { view_players(game, game.players.iter().filter(|player| player.team == Some(team)), props) }
+                </>
+            }
+        })
+        .collect::<Html>();
+
+    let mut players_without_team = game
+        .players
+        .iter()
+        .filter(|player| player.team.is_none())
+        .peekable();
+    let players_without_team_html = if players_without_team.peek().is_some() {
         html! {
-            <ul class={classes!("player-list-container__list", "player-list")}>
-                { player_list_items }
-            </ul>
+            <>
+                <h4 class={classes!("player-list-container__team-headline")}>
+                    <LocaleComponent keyid="play-view-players-without-team-headline"/>
+                </h4>
+                { view_players(game, players_without_team, props) }
+            </>
         }
+    } else {
+        Html::default()
+    };
+
+    html! {
+        <>
+            { teams_html }
+            { players_without_team_html }
+        </>
+    }
+}
+
+fn view_players<'a>(
+    game: &Rc<Game>,
+    players: impl Iterator<Item = &'a Player>,
+    props: &PlayerListProps,
+) -> Html {
+    let player_list_items = players
+        .map(|player: &Player| view_player(game, player, props))
+        .collect::<Html>();
+    html! {
+        <ul class={classes!("player-list-container__list", "player-list")}>
+            { player_list_items }
+        </ul>
     }
 }
 
-fn view_player(game: &Rc<Game>, player: &Player) -> Html {
+fn view_player(game: &Rc<Game>, player: &Player, props: &PlayerListProps) -> Html {
     let is_this_player = player.id == game.this_player_id;
+    let is_host = game.host == Some(player.id);
+    let is_captain = player.team.map_or(false, |team| {
+        game.get_team_captain(team)
+            .map_or(false, |captain| captain.id == player.id)
+    });
+    let with_host_controls = props.with_host_controls && game.is_this_player_host();
+    let elimination_status_keyid = match &game.game_state {
+        GameState::Playing {
+            eliminated_players, ..
+        } if eliminated_players.contains(&player.id) => Some("play-view-players-is-eliminated"),
+        GameState::Aftermath {
+            eliminated_players, ..
+        } if eliminated_players
+            .iter()
+            .any(|(eliminated_player, _)| *eliminated_player == player.id) =>
+        {
+            Some("play-view-players-is-eliminated")
+        }
+        GameState::Tiebreaker { contenders, .. } if contenders.contains(&player.id) => {
+            Some("play-view-players-is-tiebreaker-contender")
+        }
+        GameState::Tiebreaker { eliminated, .. } if eliminated.contains(&player.id) => {
+            Some("play-view-players-is-eliminated")
+        }
+        _ => None,
+    };
 
     let points_or_watching_html = match &player.play_type {
-        PlayType::Player { points } => {
+        PlayType::Player { points, .. } => {
             html! {
                 <LocaleComponent keyid="play-view-players-points" args={locale_args([("points", points.into())])}/>
             }
@@ -74,21 +160,173 @@ fn view_player(game: &Rc<Game>, player: &Player) -> Html {
         <li class={classes!("player-list-element")}>
             <span class={classes!(is_this_player.then_some("player-list-element__player-name--is-current-user"))}>
                 {player.name.to_string()}
+                if is_host {
+                    <span class={classes!("player-list-element__player-is-host")}>
+                        {" ("}
+                        <LocaleComponent keyid="play-view-players-is-host"/>
+                        {")"}
+                    </span>
+                }
+                if is_captain {
+                    <span class={classes!("player-list-element__player-is-captain")}>
+                        {" ("}
+                        <LocaleComponent keyid="play-view-players-is-captain"/>
+                        {")"}
+                    </span>
+                }
+                if let Some(elimination_status_keyid) = elimination_status_keyid {
+                    <span class={classes!("player-list-element__player-elimination-status")}>
+                        {" ("}
+                        <LocaleComponent keyid={elimination_status_keyid}/>
+                        {")"}
+                    </span>
+                }
             </span>
             { view_player_state(game, player) }
+            { view_player_lives(game, player) }
+            { view_player_streak(game, player) }
             <span class={classes!("player-list-element__points-or-watching")}>
                 { points_or_watching_html }
             </span>
+            if with_host_controls && !player.is_watcher() {
+                if let Some(team_setup) = game.configuration.team_setup {
+                    { view_team_selection(player, team_setup, props) }
+                }
+            }
+            if with_host_controls && !is_this_player {
+                { view_host_controls(player, props) }
+            }
+            if !with_host_controls && !is_this_player {
+                { view_kick_vote(game, player, props) }
+            }
         </li>
     }
 }
 
+fn view_team_selection(player: &Player, team_setup: TeamSetup, props: &PlayerListProps) -> Html {
+    let player_id = player.id;
+
+    let cloned_on_assign_team = props.on_assign_team.clone();
+    let onchange_team = Callback::from(move |event: Event| {
+        let value = event.target_unchecked_into::<HtmlSelectElement>().value();
+        match value.parse::<u8>() {
+            Ok(team) => cloned_on_assign_team.emit((player_id, TeamId(team))),
+            Err(error) => log::error!("Could not parse team \"{value}\" ({error})."),
+        }
+    });
+
+    html! {
+        <select class={classes!("input-field", "player-list-element__team-selection")}
+            onchange={onchange_team}>
+            {
+                (0..team_setup.count_of_teams)
+                    .map(TeamId)
+                    .map(|team| html! {
+                        <option value={team.0.to_string()} selected={player.team == Some(team)}>
+                            <LocaleComponent keyid="play-view-players-team-headline"
+                                args={locale_args([("team", team.to_string().into())])}/>
+                        </option>
+                    })
+                    .collect::<Html>()
+            }
+        </select>
+    }
+}
+
+fn view_host_controls(player: &Player, props: &PlayerListProps) -> Html {
+    let player_id = player.id;
+
+    let cloned_on_kick_player = props.on_kick_player.clone();
+    let onclick_kick_player = Callback::from(move |_| cloned_on_kick_player.emit(player_id));
+
+    let cloned_on_switch_play_type = props.on_switch_play_type.clone();
+    let onclick_switch_play_type =
+        Callback::from(move |_| cloned_on_switch_play_type.emit(player_id));
+
+    let cloned_on_transfer_host = props.on_transfer_host.clone();
+    let onclick_transfer_host = Callback::from(move |_| cloned_on_transfer_host.emit(player_id));
+
+    let switch_play_type_keyid = match player.play_type {
+        PlayType::Player { .. } => "play-view-players-host-controls-make-watcher",
+        PlayType::Watcher => "play-view-players-host-controls-make-player",
+    };
+
+    html! {
+        <span class={classes!("player-list-element__host-controls")}>
+            <button class={classes!("button", "player-list-element__host-controls-button")}
+                onclick={onclick_switch_play_type} type="button">
+                <LocaleComponent keyid={switch_play_type_keyid}/>
+            </button>
+            <button class={classes!("button", "player-list-element__host-controls-button")}
+                onclick={onclick_transfer_host} type="button">
+                <LocaleComponent keyid="play-view-players-host-controls-transfer-host"/>
+            </button>
+            <button class={classes!("button", "player-list-element__host-controls-button")}
+                onclick={onclick_kick_player} type="button">
+                <LocaleComponent keyid="play-view-players-host-controls-kick"/>
+            </button>
+        </span>
+    }
+}
+
+fn view_kick_vote(game: &Rc<Game>, player: &Player, props: &PlayerListProps) -> Html {
+    let player_id = player.id;
+    let voters = game.kick_votes.get(&player_id);
+    let count_of_votes = voters.map_or(0, |voters| voters.len());
+    let has_voted = voters.map_or(false, |voters| voters.contains(&game.this_player_id));
+
+    let cloned_on_vote_kick = props.on_vote_kick.clone();
+    let onclick_vote_kick = Callback::from(move |_| cloned_on_vote_kick.emit(player_id));
+
+    html! {
+        <span class={classes!("player-list-element__host-controls")}>
+            <button class={classes!("button", "player-list-element__host-controls-button")}
+                onclick={onclick_vote_kick} type="button" disabled={has_voted}>
+                <LocaleComponent keyid="play-view-players-vote-kick"
+                    args={locale_args([("votes", count_of_votes.into())])}/>
+            </button>
+        </span>
+    }
+}
+
+fn view_player_lives(game: &Rc<Game>, player: &Player) -> Html {
+    match (&game.game_state, player.lives) {
+        (GameState::InLobby, _) | (_, None) | (_, Some(0)) => Html::default(),
+        (_, Some(lives)) => html! {
+            <span class={classes!("player-list-element__player-lives")}>
+                { (0..lives).map(|_| html! {
+                    <span class={classes!("player-list-element__player-life")}>{HEART_SVG}</span>
+                }).collect::<Html>() }
+            </span>
+        },
+    }
+}
+
+fn view_player_streak(game: &Rc<Game>, player: &Player) -> Html {
+    let is_on_fire = !player.is_watcher()
+        && player.streak > 0
+        && game.configuration.scoring_rules.is_on_fire(player.streak);
+    match game.game_state {
+        GameState::Playing { .. } if is_on_fire => html! {
+            <span class={classes!("player-list-element__player-streak")}>
+                <LocaleComponent keyid="play-view-players-streak"
+                    args={locale_args([("streak", player.streak.into())])}/>
+            </span>
+        },
+        _ => Html::default(),
+    }
+}
+
 fn view_player_state(game: &Rc<Game>, player: &Player) -> Html {
     match &game.game_state {
         GameState::InLobby => html! {},
         GameState::Playing {
             playing_state: PlayingState::Question { answers, .. },
             ..
+        }
+        | GameState::Tiebreaker {
+            playing_state: PlayingState::Question { answers, .. },
+            ..
         } => {
             let user_has_answered: bool = answers.contains(&player.id);
             if user_has_answered {
@@ -105,6 +343,18 @@ fn view_player_state(game: &Rc<Game>, player: &Player) -> Html {
                     current_question,
                     answers,
                     skip_request,
+                    drinks,
+                    ..
+                },
+            ..
+        }
+        | GameState::Tiebreaker {
+            playing_state:
+                PlayingState::Solution {
+                    current_question,
+                    answers,
+                    skip_request,
+                    drinks,
                     ..
                 },
             ..
@@ -144,11 +394,19 @@ fn view_player_state(game: &Rc<Game>, player: &Player) -> Html {
                 None => Html::default(),
             };
 
+            let user_drinks_html = match drinks.get(&player.id) {
+                Some(player_drinks) => {
+                    view_player_drinks(player_drinks, game.configuration.penalty_mode)
+                }
+                None => Html::default(),
+            };
+
             html! {
                 <>
                     { user_wants_to_skip_html }
                     { user_has_answered_html }
                     { user_has_correct_answer_html }
+                    { user_drinks_html }
                 </>
             }
         }
@@ -156,8 +414,52 @@ fn view_player_state(game: &Rc<Game>, player: &Player) -> Html {
     }
 }
 
+fn view_player_drinks(drinks: &Drinks, penalty_mode: PenaltyMode) -> Html {
+    let sips_html = match (drinks.sips, drinks.penalty_card) {
+        (0, _) => Html::default(),
+        (sips, Some(penalty_card)) => html! {
+            <PenaltyCardComponent {penalty_card} {sips} />
+        },
+        (sips, None) => html! {
+            <LocaleComponent keyid="play-view-players-drinks-sips" args={locale_args([("sips", sips.into())])}/>
+        },
+    };
+    let sips_to_hand_out_html = if drinks.sips_to_hand_out > 0 {
+        let keyid = match penalty_mode {
+            PenaltyMode::Alcohol => "play-view-players-drinks-sips-to-hand-out",
+            PenaltyMode::SoftDrink | PenaltyMode::Dare => {
+                "play-view-players-drinks-penalties-to-hand-out"
+            }
+        };
+        html! {
+            <LocaleComponent {keyid} args={locale_args([("sips", drinks.sips_to_hand_out.into())])}/>
+        }
+    } else {
+        Html::default()
+    };
+
+    html! {
+        <span class={classes!("player-list-element__player-drinks")}>
+            { sips_html }
+            { sips_to_hand_out_html }
+        </span>
+    }
+}
+
 #[derive(yew::Properties, PartialEq)]
 pub struct PlayerListProps {
     #[prop_or_default]
     pub class: Classes,
+    #[prop_or_default]
+    pub with_host_controls: bool,
+    #[prop_or_default]
+    pub on_kick_player: Callback<PlayerId>,
+    #[prop_or_default]
+    pub on_switch_play_type: Callback<PlayerId>,
+    #[prop_or_default]
+    pub on_transfer_host: Callback<PlayerId>,
+    #[prop_or_default]
+    pub on_assign_team: Callback<(PlayerId, TeamId)>,
+    #[prop_or_default]
+    pub on_vote_kick: Callback<PlayerId>,
 }
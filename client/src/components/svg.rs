@@ -7,6 +7,9 @@ pub const CORRECT_SVG: Html = VNode::VRaw(VRaw {
 pub const FAST_FORWARD_SVG: Html = VNode::VRaw(VRaw {
     html: AttrValue::Static(include_str!("../../assets/fast-forward.svg")),
 });
+pub const HEART_SVG: Html = VNode::VRaw(VRaw {
+    html: AttrValue::Static(include_str!("../../assets/heart.svg")),
+});
 pub const INCORRECT_SVG: Html = VNode::VRaw(VRaw {
     html: AttrValue::Static(include_str!("../../assets/incorrect.svg")),
 });
@@ -11,22 +11,43 @@ use yew::{
 use crate::components::footer::FooterComponent;
 use crate::components::header::HeaderComponent;
 use crate::components::locale::{
+    forget_language_identifier_in_persistent_storage,
     load_or_else_browser_select_language_identifier_and_log_warnings,
+    select_language_identifier_by_browser_setting,
     store_language_identifier_to_persistent_storage_and_log_warnings,
 };
-use crate::routes::index::IndexComponent;
+use crate::preferences::forget_preferences_in_persistent_storage;
+use crate::routes::display::DisplayComponent;
+use crate::routes::index::{DisplayLobby, IndexComponent};
+use crate::routes::play::session::{
+    forget_session_in_persistent_storage, load_session_from_persistent_storage_and_log_warnings,
+};
 use crate::routes::play::{CreateJoinLobby, PlayComponent};
+use crate::routes::Route;
+use crate::utils::{replace_browser_route, retrieve_browser_route};
 
 pub mod components;
+pub mod preferences;
 pub mod routes;
 pub mod utils;
 
 #[function_component(AppComponent)]
-pub fn app_component() -> Html {
+pub fn app_component(props: &AppComponentProps) -> Html {
     let langid = use_state_eq::<LanguageIdentifier, _>(|| langid!("en-US"));
-    let state = use_state_eq(|| AppState::Index);
+    // The server renders with the requested path, because it has no browser location
+    let initial_route = use_state_eq(|| match &props.path {
+        Some(path) => Route::split_path(path).1,
+        None => retrieve_browser_route(),
+    });
+    let state = use_state_eq(|| AppState::Index {
+        prefilled_invite_code: initial_route.invite_code().map(ToString::to_string),
+    });
+    // Changing the key mounts the index anew, so it drops the pre-filled entries
+    let index_key = use_state_eq(|| 0_usize);
 
     let cloned_langid = langid.clone();
+    let cloned_state = state.clone();
+    let cloned_initial_route = initial_route.clone();
     use_effect_with_deps(
         move |_| {
             let optional_loaded_langid =
@@ -34,12 +55,35 @@ pub fn app_component() -> Html {
             if let Some(loaded_langid) = optional_loaded_langid {
                 cloned_langid.set(loaded_langid);
             }
+
+            // Resume a game, which was left by reloading or closing the page,
+            // unless the link leads to another lobby
+            let optional_rejoin_lobby = load_session_from_persistent_storage_and_log_warnings()
+                .filter(|rejoin_lobby| {
+                    cloned_initial_route
+                        .invite_code()
+                        .map_or(true, |invite_code| {
+                            invite_code == rejoin_lobby.invite_code.0
+                        })
+                });
+            if let Some(rejoin_lobby) = optional_rejoin_lobby {
+                cloned_state.set(AppState::Play {
+                    create_join_lobby: CreateJoinLobby::Rejoin(rejoin_lobby),
+                });
+            } else if let Route::Play { invite_code } = &*cloned_initial_route {
+                // Without a session the game can only be joined again
+                replace_browser_route(&Route::Join {
+                    invite_code: invite_code.clone(),
+                });
+            }
         },
         (),
     );
 
     let inner_components = match &*state {
-        AppState::Index => {
+        AppState::Index {
+            prefilled_invite_code,
+        } => {
             let cloned_state = state.clone();
             let on_join_lobby = Callback::from(move |join_lobby| {
                 cloned_state.set(AppState::Play {
@@ -54,18 +98,42 @@ pub fn app_component() -> Html {
                 });
             });
 
+            let cloned_state = state.clone();
+            let on_display_lobby = Callback::from(move |display_lobby| {
+                cloned_state.set(AppState::Display { display_lobby });
+            });
+
             html! {
-                <IndexComponent {on_join_lobby} {on_create_lobby} />
+                <IndexComponent key={*index_key} {on_join_lobby} {on_create_lobby} {on_display_lobby}
+                    prefilled_invite_code={prefilled_invite_code.clone()} />
             }
         }
         AppState::Play { create_join_lobby } => {
             let cloned_state = state.clone();
-            let on_go_back_to_index = Callback::from(move |_| cloned_state.set(AppState::Index));
+            let on_go_back_to_index = Callback::from(move |_| {
+                replace_browser_route(&Route::Index);
+                cloned_state.set(AppState::Index {
+                    prefilled_invite_code: None,
+                });
+            });
 
             html! {
                 <PlayComponent create_join_lobby={create_join_lobby.clone()} {on_go_back_to_index} />
             }
         }
+        AppState::Display { display_lobby } => {
+            let cloned_state = state.clone();
+            let on_go_back_to_index = Callback::from(move |_| {
+                replace_browser_route(&Route::Index);
+                cloned_state.set(AppState::Index {
+                    prefilled_invite_code: None,
+                });
+            });
+
+            html! {
+                <DisplayComponent display_lobby={display_lobby.clone()} {on_go_back_to_index} />
+            }
+        }
     };
 
     let cloned_langid = langid.clone();
@@ -74,17 +142,47 @@ pub fn app_component() -> Html {
         cloned_langid.set(lid);
     });
 
+    let cloned_langid = langid.clone();
+    let cloned_index_key = index_key.clone();
+    let on_forget_me = Callback::from(move |_| {
+        forget_preferences_in_persistent_storage();
+        forget_language_identifier_in_persistent_storage();
+        forget_session_in_persistent_storage();
+        let browser_langid = select_language_identifier_by_browser_setting()
+            .unwrap_or_else(|error| {
+                log::warn!("While browser selecting language identifier: {error}");
+                None
+            })
+            .unwrap_or_else(|| langid!("en-US"));
+        cloned_langid.set(browser_langid);
+        cloned_index_key.set(*cloned_index_key + 1);
+    });
+
     html! {
         <ContextProvider<LanguageIdentifier> context={(*langid).clone()}>
             <HeaderComponent />
             { inner_components }
-            <FooterComponent {on_change_language_identifier} />
+            <FooterComponent {on_change_language_identifier} {on_forget_me} />
         </ContextProvider<LanguageIdentifier>>
     }
 }
 
+#[derive(yew::Properties, PartialEq, Default)]
+pub struct AppComponentProps {
+    /// The requested path, while rendering on the server.
+    #[prop_or_default]
+    pub path: Option<String>,
+}
+
 #[derive(PartialEq)]
 enum AppState {
-    Index,
-    Play { create_join_lobby: CreateJoinLobby },
+    Index {
+        prefilled_invite_code: Option<String>,
+    },
+    Play {
+        create_join_lobby: CreateJoinLobby,
+    },
+    Display {
+        display_lobby: DisplayLobby,
+    },
 }
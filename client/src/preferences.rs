@@ -0,0 +1,56 @@
+use gloo_storage::errors::StorageError;
+use gloo_storage::{LocalStorage, Storage};
+
+const LOCAL_STORAGE_KEY_PREFERENCES: &str = "preferences";
+
+/// The entries of the create/join form, which are pre-filled on the next visit.
+#[derive(Debug, Default, PartialEq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Preferences {
+    pub player_name: Option<String>,
+    pub just_watch: bool,
+    pub count_of_questions: Option<u64>,
+    pub minimum_score_per_question: Option<i64>,
+    pub maximum_answer_seconds_per_question: Option<u64>,
+}
+
+pub fn load_preferences_from_persistent_storage_and_log_warnings() -> Option<Preferences> {
+    load_preferences_from_persistent_storage()
+        .map_err(|inspect_error| {
+            log::warn!("While loading preferences: {inspect_error}");
+            inspect_error
+        })
+        .ok()
+        .flatten()
+}
+
+pub fn load_preferences_from_persistent_storage() -> anyhow::Result<Option<Preferences>> {
+    use anyhow::Context;
+
+    match LocalStorage::get::<Preferences>(LOCAL_STORAGE_KEY_PREFERENCES) {
+        Ok(preferences) => Ok(Some(preferences)),
+        Err(StorageError::KeyNotFound(_)) => Ok(None),
+        Err(error) => Err(error).with_context(|| {
+            format!(
+                "Could not get preferences from key \"{LOCAL_STORAGE_KEY_PREFERENCES}\" from LocalStorage."
+            )
+        }),
+    }
+}
+
+pub fn store_preferences_to_persistent_storage_and_log_warnings(preferences: &Preferences) {
+    if let Err(error) = store_preferences_to_persistent_storage(preferences) {
+        log::warn!("While storing preferences ({error}).");
+    }
+}
+
+pub fn store_preferences_to_persistent_storage(preferences: &Preferences) -> anyhow::Result<()> {
+    use anyhow::Context;
+
+    LocalStorage::set(LOCAL_STORAGE_KEY_PREFERENCES, preferences).with_context(|| {
+        format!("Could not store preferences to LocalStorage \"{LOCAL_STORAGE_KEY_PREFERENCES}\".")
+    })
+}
+
+pub fn forget_preferences_in_persistent_storage() {
+    LocalStorage::delete(LOCAL_STORAGE_KEY_PREFERENCES);
+}
@@ -0,0 +1,365 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use fluent_templates::LanguageIdentifier;
+
+use gloo_net::websocket::Message;
+
+use onion_or_not_the_onion_drinking_game_2_shared_library::model::game::{
+    Answer, Game, GameState, PlayType, PlayingState,
+};
+use onion_or_not_the_onion_drinking_game_2_shared_library::model::network::TimestampedServerMessage;
+
+use yew::{
+    classes, function_component, html, use_context, use_effect_with_deps, use_force_update,
+    use_state, Callback, ContextProvider, Html, UseForceUpdateHandle,
+};
+
+use crate::components::join_game::JoinGameComponent;
+use crate::components::locale::{locale_args, LocaleComponent};
+use crate::routes::index::DisplayLobby;
+use crate::routes::play::connecting::{ConnectingComponent, ConnectingComponentState};
+use crate::routes::play::play_state::{ConnectingErrorLocaleKeyId, PlayState};
+use crate::routes::play::server_clock::ServerClock;
+use crate::routes::play::CreateJoinLobby;
+use crate::utils::{
+    format_milliseconds_as_seconds, retrieve_browser_location, REPLACE_PROTOCOL_WEBSOCKET,
+};
+
+/// A big-screen view of the game for the whole party, which doesn't take part in it.
+#[function_component(DisplayComponent)]
+pub fn display_component(props: &DisplayComponentProps) -> Html {
+    let _langid = use_context::<LanguageIdentifier>().expect("Missing LanguageIdentifier context.");
+
+    let force_update = use_force_update();
+
+    let play_state = Rc::clone(&*use_state(|| Rc::new(RefCell::new(PlayState::None))));
+
+    let server_clock = Rc::clone(&*use_state(|| Rc::new(Cell::new(ServerClock::default()))));
+
+    let cloned_display_lobby = props.display_lobby.clone();
+    let cloned_play_state = Rc::clone(&play_state);
+    let cloned_server_clock = Rc::clone(&server_clock);
+    let cloned_force_update = force_update.clone();
+    use_effect_with_deps(
+        move |_| {
+            connect(
+                &cloned_play_state,
+                &cloned_server_clock,
+                &cloned_force_update,
+                &cloned_display_lobby,
+            )
+        },
+        (),
+    );
+
+    // The countdown has to be updated, even if the server doesn't send anything
+    let update_timer = use_state::<Option<gloo_timers::callback::Interval>, _>(|| None);
+    let update_by_interval_callback = Callback::from(move |_| force_update.force_update());
+    use_effect_with_deps(
+        move |_| {
+            update_timer.set(Some(gloo_timers::callback::Interval::new(500, move || {
+                update_by_interval_callback.emit(());
+            })))
+        },
+        (),
+    );
+
+    let cloned_play_state = Rc::clone(&play_state);
+    let cloned_on_go_back_to_index = props.on_go_back_to_index.clone();
+    let on_close = Callback::from(move |_| {
+        RefCell::borrow(&cloned_play_state).close(cloned_on_go_back_to_index.clone())
+    });
+
+    let borrowed_play_state = RefCell::borrow(&play_state);
+    match &*borrowed_play_state {
+        PlayState::Connecting { .. } => {
+            let state = ConnectingComponentState::Connecting;
+            html! { <ConnectingComponent {state} on_cancel={on_close} /> }
+        }
+        PlayState::Playing { game, .. } => {
+            let game_rc = Rc::new(AsRef::as_ref(game).clone());
+            let onclick_close = Callback::from(move |_| on_close.emit(()));
+            html! {
+                <ContextProvider<Rc<Game>> context={Rc::clone(&game_rc)}>
+                    <JoinGameComponent invite_code={game_rc.invite_code.to_string()} with_qr_code={true} />
+                    <main class={classes!("main", "display-view")}>
+                        { view_display_header(&game_rc) }
+                        { view_display_content(&game_rc, &server_clock.get()) }
+                        { view_scoreboard(&game_rc) }
+                        <button class={classes!("button", "display-view__close-button")} onclick={onclick_close} type="button">
+                            <LocaleComponent keyid="display-view-close"/>
+                        </button>
+                    </main>
+                </ContextProvider<Rc<Game>>>
+            }
+        }
+        PlayState::ConnectingError {
+            locale_keyid,
+            error,
+        } => {
+            let cloned_on_go_back_to_index = props.on_go_back_to_index.clone();
+            let on_go_back = Callback::from(move |_| cloned_on_go_back_to_index.emit(()));
+
+            let state = ConnectingComponentState::Failed {
+                locale_key_id: locale_keyid.key_id().to_string(),
+                error: error.as_ref().map(ToString::to_string),
+            };
+
+            html! { <ConnectingComponent {state} {on_go_back} /> }
+        }
+        PlayState::None => Default::default(),
+    }
+}
+
+fn view_display_header(game: &Rc<Game>) -> Html {
+    let optional_progress = match &game.game_state {
+        GameState::Playing {
+            index_of_current_question,
+            ..
+        } => {
+            // A marathon has no fixed number of questions
+            let progress = if game.configuration.marathon {
+                (index_of_current_question + 1).to_string()
+            } else {
+                format!(
+                    "{} / {}",
+                    index_of_current_question + 1,
+                    game.configuration.count_of_questions
+                )
+            };
+            Some(html! { <>{progress}</> })
+        }
+        GameState::Tiebreaker { .. } => {
+            Some(html! { <LocaleComponent keyid="game-view-tiebreaker-headline"/> })
+        }
+        GameState::InLobby | GameState::Aftermath { .. } => None,
+    };
+
+    html! {
+        <header class={classes!("display-view__header")}>
+            if let Some(progress) = optional_progress {
+                <p class={classes!("display-view__progress")}>{progress}</p>
+            }
+        </header>
+    }
+}
+
+fn view_display_content(game: &Rc<Game>, server_clock: &ServerClock) -> Html {
+    match &game.game_state {
+        GameState::InLobby => html! {
+            <h1 class={classes!("display-view__headline")}>
+                <LocaleComponent keyid="lobby-view-waiting-for-host"/>
+            </h1>
+        },
+        GameState::Playing { playing_state, .. } | GameState::Tiebreaker { playing_state, .. } => {
+            html! {
+                <>
+                    { view_playing_state(game, server_clock, playing_state) }
+                    if game.paused_at.is_some() {
+                        <p class={classes!("display-view__paused")}>
+                            <LocaleComponent keyid="game-view-paused-headline"/>
+                        </p>
+                    }
+                </>
+            }
+        }
+        GameState::Aftermath { .. } => html! {
+            <h1 class={classes!("display-view__headline")}>
+                <LocaleComponent keyid="aftermath-view-headline"/>
+            </h1>
+        },
+    }
+}
+
+fn view_playing_state(
+    game: &Rc<Game>,
+    server_clock: &ServerClock,
+    playing_state: &PlayingState,
+) -> Html {
+    match playing_state {
+        PlayingState::Question {
+            current_question,
+            time_until,
+            answers,
+            ..
+        } => {
+            let count_of_answering_players = match &game.game_state {
+                GameState::Tiebreaker { contenders, .. } => contenders.len(),
+                _ => game
+                    .players
+                    .iter()
+                    .filter(|player| !player.is_watcher())
+                    .count(),
+            };
+            html! {
+                <>
+                    <h1 class={classes!("display-view__headline")}>
+                        {current_question.title.clone()}
+                    </h1>
+                    <p class={classes!("display-view__status")}>
+                        <LocaleComponent keyid="display-view-count-of-answers"
+                            args={locale_args([
+                                ("answers", answers.len().into()),
+                                ("players", count_of_answering_players.into()),
+                            ])} />
+                        if let Some(time_until) = time_until {
+                            {" | "}
+                            <LocaleComponent keyid="game-view-question-playing-state-remaining-seconds"
+                                args={locale_args([("seconds", (*time_until - game.paused_at.unwrap_or_else(|| server_clock.now())).num_seconds().into())])} />
+                        }
+                    </p>
+                </>
+            }
+        }
+        PlayingState::Solution {
+            current_question,
+            time_until,
+            fastest_correct_answer,
+            ..
+        } => {
+            let (sub_headline_locale, question_result_css_class) = match current_question.answer {
+                Answer::TheOnion => (
+                    "game-view-solution-playing-state-sub-headline-the-onion",
+                    "question-result--correct",
+                ),
+                Answer::NotTheOnion => (
+                    "game-view-solution-playing-state-sub-headline-not-the-onion",
+                    "question-result--wrong",
+                ),
+            };
+            let duration = *time_until - game.paused_at.unwrap_or_else(|| server_clock.now());
+            html! {
+                <>
+                    <p class={classes!("question-result", "display-view__solution", question_result_css_class)}>
+                        <LocaleComponent keyid={sub_headline_locale} />
+                    </p>
+                    <h1 class={classes!("display-view__headline")}>
+                        {current_question.question.title.clone()}
+                    </h1>
+                    if let Some(preview_image_url) = current_question.preview_image_url.clone() {
+                        <img class={classes!("display-view__picture")} src={preview_image_url}/>
+                    }
+                    <p class={classes!("display-view__status")}>
+                        if let Some((_, player_name, response_time)) = fastest_correct_answer {
+                            <LocaleComponent keyid="game-view-solution-playing-state-fastest-correct-answer"
+                                args={locale_args([
+                                    ("player", player_name.to_string().into()),
+                                    ("seconds", format_milliseconds_as_seconds(*response_time).into()),
+                                ])} />
+                            {" | "}
+                        }
+                        <LocaleComponent keyid="game-view-solution-playing-state-remaining-seconds"
+                            args={locale_args([("seconds", duration.num_seconds().into())])} />
+                    </p>
+                </>
+            }
+        }
+    }
+}
+
+fn view_scoreboard(game: &Rc<Game>) -> Html {
+    let ranked_players: Vec<(String, u16)> = match &game.game_state {
+        GameState::Aftermath { ranked_players, .. } => ranked_players
+            .iter()
+            .map(|(_, player_name, points)| (player_name.to_string(), *points))
+            .collect(),
+        GameState::InLobby | GameState::Playing { .. } | GameState::Tiebreaker { .. } => {
+            let mut ranked_players: Vec<(String, u16)> = game
+                .players
+                .iter()
+                .filter_map(|player| match player.play_type {
+                    PlayType::Player { points, .. } => Some((player.name.to_string(), points)),
+                    PlayType::Watcher => None,
+                })
+                .collect();
+            ranked_players.sort_by(|(_, a), (_, b)| b.cmp(a));
+            ranked_players
+        }
+    };
+
+    html! {
+        <section class={classes!("display-view__scoreboard")}>
+            <h2 class={classes!("display-view__scoreboard-headline")}>
+                <LocaleComponent keyid="display-view-scoreboard-headline"/>
+            </h2>
+            if ranked_players.is_empty() {
+                <p>
+                    <LocaleComponent keyid="play-view-players-no-one-here"/>
+                </p>
+            } else {
+                <ol class={classes!("display-view__scoreboard-list")}>
+                    { for ranked_players.into_iter().map(|(player_name, points)| html! {
+                        <li class={classes!("display-view__scoreboard-entry")}>
+                            <span>{player_name}</span>
+                            <LocaleComponent keyid="play-view-players-points"
+                                args={locale_args([("points", points.into())])} />
+                        </li>
+                    }) }
+                </ol>
+            }
+        </section>
+    }
+}
+
+fn connect(
+    play_state: &Rc<RefCell<PlayState>>,
+    server_clock: &Rc<Cell<ServerClock>>,
+    force_update: &UseForceUpdateHandle,
+    display_lobby: &DisplayLobby,
+) {
+    let web_socket_address_root =
+        retrieve_browser_location(Some(REPLACE_PROTOCOL_WEBSOCKET), Some("/api"));
+    log::debug!("Retrieved web_socket_address_root as {web_socket_address_root}");
+
+    let cloned_force_update = force_update.clone();
+    let cloned_play_state = Rc::clone(play_state);
+    let cloned_server_clock = Rc::clone(server_clock);
+    let on_message_received = Callback::from(move |msg| {
+        let new_play_state = match msg {
+            Ok(Message::Bytes(bytes)) => {
+                let TimestampedServerMessage {
+                    sent_at,
+                    server_message,
+                } = TimestampedServerMessage::try_from(&bytes[..]).unwrap();
+                cloned_server_clock.set(cloned_server_clock.get().synchronized(sent_at));
+                let optional_new_play_state =
+                    { RefCell::borrow(&cloned_play_state).handle_server_message(server_message) };
+                let Some(new_play_state) = optional_new_play_state else {
+                    return;
+                };
+                new_play_state
+            }
+            Ok(Message::Text(text)) => {
+                log::warn!("Received text from WebSocket \"{text}\"; ignoring it...");
+                return;
+            }
+            Err(error) => {
+                log::warn!("An error occurred: {error} ({error:?})");
+                PlayState::ConnectingError {
+                    locale_keyid: ConnectingErrorLocaleKeyId::from(&error),
+                    error: Some(error.into()),
+                }
+            }
+        };
+        *RefCell::borrow_mut(&cloned_play_state) = new_play_state;
+        cloned_force_update.force_update();
+    });
+
+    let cloned_force_update = force_update.clone();
+    let on_connection_closed = Callback::from(move |_| cloned_force_update.force_update());
+
+    *RefCell::borrow_mut(play_state) = PlayState::connect(
+        &web_socket_address_root,
+        on_message_received,
+        on_connection_closed,
+        &CreateJoinLobby::Display(display_lobby.clone()),
+    );
+    force_update.force_update();
+}
+
+#[derive(yew::Properties, PartialEq)]
+pub struct DisplayComponentProps {
+    pub display_lobby: DisplayLobby,
+    pub on_go_back_to_index: Callback<()>,
+}
@@ -2,7 +2,11 @@ use std::collections::HashMap;
 
 use fluent_templates::LanguageIdentifier;
 
-use web_sys::{HtmlInputElement, SubmitEvent};
+use onion_or_not_the_onion_drinking_game_2_shared_library::model::game::{
+    PenaltyMode, ScoringMode,
+};
+
+use web_sys::{HtmlInputElement, HtmlSelectElement, SubmitEvent};
 
 use yew::platform::spawn_local;
 use yew::{
@@ -11,16 +15,56 @@ use yew::{
 };
 
 use crate::components::locale::{locale, locale_args, LocaleComponent};
+use crate::preferences::{
+    load_preferences_from_persistent_storage_and_log_warnings,
+    store_preferences_to_persistent_storage_and_log_warnings, Preferences,
+};
 use crate::utils::retrieve_browser_location;
 
+pub const PENALTY_MODE_VALUE_ALCOHOL: &str = "alcohol";
+pub const PENALTY_MODE_VALUE_SOFT_DRINK: &str = "soft_drink";
+pub const PENALTY_MODE_VALUE_DARE: &str = "dare";
+
+pub const SCORING_MODE_VALUE_CLASSIC: &str = "classic";
+pub const SCORING_MODE_VALUE_SPEED_BONUS: &str = "speed_bonus";
+pub const SCORING_MODE_VALUE_CONFIDENCE_BETTING: &str = "confidence_betting";
+
 #[function_component(IndexComponent)]
 pub fn index_component(props: &IndexComponentProps) -> Html {
     let langid = use_context::<LanguageIdentifier>().expect("Missing LanguageIdentifier context.");
 
     let node_refs = use_state_eq(NodeRefs::default);
-    let form_mode = use_state_eq(FormMode::default);
+    // An invite link fills in the invite code, so that only the name is missing for joining
+    let form_mode = use_state_eq(|| match props.prefilled_invite_code {
+        Some(_) => FormMode::new_join_game(),
+        None => FormMode::default(),
+    });
     let error_messages = use_state_eq(ErrorMessages::default);
     let question_scores_distribution = use_state_eq::<Option<HashMap<u64, usize>>, _>(|| None);
+    let preferences = use_state_eq(Preferences::default);
+
+    let cloned_preferences = preferences.clone();
+    let cloned_form_mode = form_mode.clone();
+    use_effect_with_deps(
+        move |_| {
+            if let Some(loaded_preferences) =
+                load_preferences_from_persistent_storage_and_log_warnings()
+            {
+                // The question count keeps its entered value across renders, so it's filled in directly
+                if let (FormMode::CreateGame { node_refs, .. }, Some(count_of_questions)) =
+                    (&*cloned_form_mode, loaded_preferences.count_of_questions)
+                {
+                    if let Some(question_count_element) =
+                        node_refs.question_count_node_ref.cast::<HtmlInputElement>()
+                    {
+                        question_count_element.set_value(&count_of_questions.to_string());
+                    }
+                }
+                cloned_preferences.set(loaded_preferences);
+            }
+        },
+        (),
+    );
 
     let cloned_question_scores_distribution = question_scores_distribution.clone();
     use_effect_with_deps(
@@ -64,7 +108,7 @@ pub fn index_component(props: &IndexComponentProps) -> Html {
                 <LocaleComponent keyid="game-title-description"/>
             </p>
 
-            { view_form(props, &langid, &node_refs, &form_mode, &error_messages, &question_scores_distribution) }
+            { view_form(props, &langid, &node_refs, &form_mode, &error_messages, &question_scores_distribution, &preferences) }
         </main>
     }
 }
@@ -76,12 +120,14 @@ fn view_form(
     form_mode: &UseStateHandle<FormMode>,
     error_messages: &UseStateHandle<ErrorMessages>,
     question_scores_distribution: &UseStateHandle<Option<HashMap<u64, usize>>>,
+    preferences: &UseStateHandle<Preferences>,
 ) -> Html {
     let cloned_on_join_lobby = props.on_join_lobby.clone();
     let cloned_on_create_lobby = props.on_create_lobby.clone();
     let cloned_node_refs = node_refs.clone();
     let cloned_form_mode = form_mode.clone();
     let cloned_error_messages = error_messages.clone();
+    let cloned_preferences = preferences.clone();
     let onsubmit = Callback::from(move |event: SubmitEvent| {
         event.prevent_default();
         event.stop_propagation();
@@ -91,6 +137,7 @@ fn view_form(
             &cloned_node_refs,
             &cloned_form_mode,
             &cloned_error_messages,
+            &cloned_preferences,
         );
     });
 
@@ -118,7 +165,11 @@ fn view_form(
             .cast()
             .map(|question_count_element: HtmlInputElement| question_count_element.value()),
     };
-    let question_count_value = question_count_value.unwrap_or_else(|| "10".to_string());
+    let question_count_value = question_count_value.unwrap_or_else(|| {
+        preferences
+            .count_of_questions
+            .map_or_else(|| "10".to_string(), |count| count.to_string())
+    });
 
     let create_game_form_html = match &**form_mode {
         FormMode::JoinGame => Default::default(),
@@ -198,7 +249,8 @@ fn view_form(
                         min={minimum_available_score}
                         placeholder={locale("game-creation-form-minimum-score-placeholder", langid)}
                         ref={node_refs.minimum_score_node_ref.clone()}
-                        type="number" />
+                        type="number"
+                        value={preferences.minimum_score_per_question.map(|score| score.to_string())} />
                     if let Some(lang_key_id) = error_messages.optional_minimum_score_error_message_lang_key_id {
                         <p class={classes!("form-error-paragraph", "game-create-join-form__error_paragraph")}>
                             <LocaleComponent keyid={lang_key_id}/>
@@ -225,7 +277,8 @@ fn view_form(
                         id="timer"
                         placeholder={locale("game-creation-form-timer-wanted-placeholder", langid)}
                         ref={node_refs.timer_node_ref.clone()}
-                        type="number" />
+                        type="number"
+                        value={preferences.maximum_answer_seconds_per_question.map(|seconds| seconds.to_string())} />
                     if let Some(lang_key_id) = error_messages.optional_timer_error_message_lang_key_id {
                         <p class={classes!("form-error-paragraph", "game-create-join-form__error_paragraph")}>
                             <LocaleComponent keyid={lang_key_id}/>
@@ -234,11 +287,158 @@ fn view_form(
                     <p class={classes!("form-description-paragraph", "game-create-join-form__description-paragraph")}>
                         <LocaleComponent keyid="game-creation-form-timer-wanted-explanation"/>
                     </p>
+
+                    <label for="solution_time">
+                        <span class={classes!("form-input-label")}>
+                            <LocaleComponent keyid="game-creation-form-solution-time-label"/>
+                            {":"}
+                        </span>
+                        {" "}
+                    </label>
+                    <input autocomplete="off"
+                        class={classes!("input-field")}
+                        id="solution_time"
+                        min="1"
+                        placeholder={locale("game-creation-form-solution-time-placeholder", langid)}
+                        ref={node_refs.solution_time_node_ref.clone()}
+                        type="number" />
+
+                    <label for="scoring_mode">
+                        <span class={classes!("form-input-label")}>
+                            <LocaleComponent keyid="game-creation-form-scoring-mode-label"/>
+                            {":"}
+                        </span>
+                        {" "}
+                    </label>
+                    <select class={classes!("input-field")}
+                        id="scoring_mode"
+                        ref={node_refs.scoring_mode_node_ref.clone()}>
+                        <option value={SCORING_MODE_VALUE_CLASSIC} selected={true}>
+                            {locale("game-creation-form-scoring-mode-classic", langid)}
+                        </option>
+                        <option value={SCORING_MODE_VALUE_SPEED_BONUS}>
+                            {locale("game-creation-form-scoring-mode-speed-bonus", langid)}
+                        </option>
+                        <option value={SCORING_MODE_VALUE_CONFIDENCE_BETTING}>
+                            {locale("game-creation-form-scoring-mode-confidence-betting", langid)}
+                        </option>
+                    </select>
+                    <p class={classes!("form-description-paragraph", "game-create-join-form__description-paragraph")}>
+                        <LocaleComponent keyid="game-creation-form-scoring-mode-explanation"/>
+                    </p>
+
+                    <label for="points_for_correct_answer">
+                        <span class={classes!("form-input-label")}>
+                            <LocaleComponent keyid="game-creation-form-points-for-correct-answer-label"/>
+                            {":"}
+                        </span>
+                        {" "}
+                    </label>
+                    <input autocomplete="off"
+                        class={classes!("input-field")}
+                        id="points_for_correct_answer"
+                        min="0"
+                        placeholder={locale("game-creation-form-points-for-correct-answer-placeholder", langid)}
+                        ref={node_refs.points_for_correct_answer_node_ref.clone()}
+                        type="number" />
+
+                    <label for="points_for_minority_bonus">
+                        <span class={classes!("form-input-label")}>
+                            <LocaleComponent keyid="game-creation-form-points-for-minority-bonus-label"/>
+                            {":"}
+                        </span>
+                        {" "}
+                    </label>
+                    <input autocomplete="off"
+                        class={classes!("input-field")}
+                        id="points_for_minority_bonus"
+                        min="0"
+                        placeholder={locale("game-creation-form-points-for-minority-bonus-placeholder", langid)}
+                        ref={node_refs.points_for_minority_bonus_node_ref.clone()}
+                        type="number" />
+
+                    <label for="minority_threshold">
+                        <span class={classes!("form-input-label")}>
+                            <LocaleComponent keyid="game-creation-form-minority-threshold-label"/>
+                            {":"}
+                        </span>
+                        {" "}
+                    </label>
+                    <input autocomplete="off"
+                        class={classes!("input-field")}
+                        id="minority_threshold"
+                        max="100"
+                        min="1"
+                        placeholder={locale("game-creation-form-minority-threshold-placeholder", langid)}
+                        ref={node_refs.minority_threshold_node_ref.clone()}
+                        type="number" />
+
+                    <label for="points_penalty_for_wrong_answer">
+                        <span class={classes!("form-input-label")}>
+                            <LocaleComponent keyid="game-creation-form-points-penalty-for-wrong-answer-label"/>
+                            {":"}
+                        </span>
+                        {" "}
+                    </label>
+                    <input autocomplete="off"
+                        class={classes!("input-field")}
+                        id="points_penalty_for_wrong_answer"
+                        min="0"
+                        placeholder={locale("game-creation-form-points-penalty-for-wrong-answer-placeholder", langid)}
+                        ref={node_refs.points_penalty_for_wrong_answer_node_ref.clone()}
+                        type="number" />
+                    if let Some(lang_key_id) = error_messages.optional_scoring_error_message_lang_key_id {
+                        <p class={classes!("form-error-paragraph", "game-create-join-form__error_paragraph")}>
+                            <LocaleComponent keyid={lang_key_id}/>
+                        </p>
+                    }
+                    <p class={classes!("form-description-paragraph", "game-create-join-form__description-paragraph")}>
+                        <LocaleComponent keyid="game-creation-form-scoring-explanation"/>
+                    </p>
+
+                    <label for="penalty_mode">
+                        <span class={classes!("form-input-label")}>
+                            <LocaleComponent keyid="game-creation-form-penalty-mode-label"/>
+                            {":"}
+                        </span>
+                        {" "}
+                    </label>
+                    <select class={classes!("input-field")}
+                        id="penalty_mode"
+                        ref={node_refs.penalty_mode_node_ref.clone()}>
+                        <option value={PENALTY_MODE_VALUE_ALCOHOL} selected={true}>
+                            {locale("game-creation-form-penalty-mode-alcohol", langid)}
+                        </option>
+                        <option value={PENALTY_MODE_VALUE_SOFT_DRINK}>
+                            {locale("game-creation-form-penalty-mode-soft-drink", langid)}
+                        </option>
+                        <option value={PENALTY_MODE_VALUE_DARE}>
+                            {locale("game-creation-form-penalty-mode-dare", langid)}
+                        </option>
+                    </select>
+                    <p class={classes!("form-description-paragraph", "game-create-join-form__description-paragraph")}>
+                        <LocaleComponent keyid="game-creation-form-penalty-mode-explanation"/>
+                    </p>
                 </>
             }
         }
     };
 
+    // A display only needs the invite code, because it doesn't take part in the game
+    let cloned_on_display_lobby = props.on_display_lobby.clone();
+    let cloned_invite_code_node_ref = node_refs.invite_code_node_ref.clone();
+    let onclick_display = Callback::from(move |_| {
+        let invite_code: String = cloned_invite_code_node_ref
+            .cast::<HtmlInputElement>()
+            .unwrap()
+            .value()
+            .trim()
+            .to_string();
+        if !invite_code.is_empty() {
+            cloned_on_display_lobby.emit(DisplayLobby { invite_code });
+        }
+    });
+
     let form_submit_button_value = match &**form_mode {
         FormMode::JoinGame => locale("game-creation-form-submit-value-join", langid),
         FormMode::CreateGame { .. } => locale("game-creation-form-submit-value-create", langid),
@@ -258,7 +458,8 @@ fn view_form(
                 placeholder={locale("game-creation-form-username-placeholder", langid)}
                 required={true}
                 ref={node_refs.player_name_node_ref.clone()}
-                type="text" />
+                type="text"
+                value={preferences.player_name.clone()} />
             if let Some(lang_key_id) = error_messages.optional_player_name_error_message_lang_key_id {
                 <p class={classes!("form-error-paragraph", "game-create-join-form__error_paragraph")}>
                     <LocaleComponent keyid={lang_key_id}/>
@@ -275,6 +476,7 @@ fn view_form(
             <input autocomplete="off"
                 class={classes!("input-field")}
                 id="invite_code"
+                value={props.prefilled_invite_code.clone().unwrap_or_default()}
                 maxlength="4"
                 onkeyup={invite_code_onkeyup}
                 placeholder={locale("game-creation-form-invite-code-placeholder", langid)}
@@ -292,6 +494,7 @@ fn view_form(
 
             <label class={classes!("form-just-watch-label", "game-create-join-form__just-watch-label")}>
                 <input type="checkbox"
+                    checked={preferences.just_watch}
                     ref={node_refs.just_watch_node_ref.clone()} />
                 {" "}
                 <LocaleComponent keyid="game-creation-form-just-watch-label"/>
@@ -300,6 +503,11 @@ fn view_form(
             { create_game_form_html }
 
             <input class={classes!("button", "game-create-join-form__submit-button")} value={form_submit_button_value} type="submit" />
+            if **form_mode == FormMode::JoinGame {
+                <button class={classes!("button", "game-create-join-form__display-button")} onclick={onclick_display} type="button">
+                    <LocaleComponent keyid="game-creation-form-display-button"/>
+                </button>
+            }
         </form>
     }
 }
@@ -310,6 +518,7 @@ fn process_form_submission(
     node_refs: &NodeRefs,
     form_mode: &UseStateHandle<FormMode>,
     error_messages: &UseStateHandle<ErrorMessages>,
+    preferences: &Preferences,
 ) {
     let mut new_error_messages = ErrorMessages::default();
 
@@ -348,6 +557,11 @@ fn process_form_submission(
             }
 
             if new_error_messages.no_error_set() {
+                store_preferences_to_persistent_storage_and_log_warnings(&Preferences {
+                    player_name: Some(player_name.clone()),
+                    just_watch,
+                    ..preferences.clone()
+                });
                 on_join_lobby.emit(JoinLobby {
                     player_name,
                     invite_code,
@@ -406,13 +620,86 @@ fn process_form_submission(
                 "game-creation-form-error-message-timer-wanted-invalid",
             );
 
+            let solution_seconds = parse_trimmed_optional_input(
+                &node_refs.solution_time_node_ref,
+                &mut new_extended_error_messages.optional_scoring_error_message_lang_key_id,
+                "solution time",
+                "game-creation-form-error-message-scoring-invalid",
+            );
+
+            let points_for_correct_answer = parse_trimmed_optional_input(
+                &node_refs.points_for_correct_answer_node_ref,
+                &mut new_extended_error_messages.optional_scoring_error_message_lang_key_id,
+                "points for correct answer",
+                "game-creation-form-error-message-scoring-invalid",
+            );
+
+            let points_for_minority_bonus = parse_trimmed_optional_input(
+                &node_refs.points_for_minority_bonus_node_ref,
+                &mut new_extended_error_messages.optional_scoring_error_message_lang_key_id,
+                "points for minority bonus",
+                "game-creation-form-error-message-scoring-invalid",
+            );
+
+            let minority_threshold_in_percent = parse_trimmed_optional_input(
+                &node_refs.minority_threshold_node_ref,
+                &mut new_extended_error_messages.optional_scoring_error_message_lang_key_id,
+                "minority threshold",
+                "game-creation-form-error-message-scoring-invalid",
+            );
+
+            let points_penalty_for_wrong_answer = parse_trimmed_optional_input(
+                &node_refs.points_penalty_for_wrong_answer_node_ref,
+                &mut new_extended_error_messages.optional_scoring_error_message_lang_key_id,
+                "points penalty for wrong answer",
+                "game-creation-form-error-message-scoring-invalid",
+            );
+
+            let scoring_mode = match node_refs
+                .scoring_mode_node_ref
+                .cast::<HtmlSelectElement>()
+                .unwrap()
+                .value()
+                .as_str()
+            {
+                SCORING_MODE_VALUE_SPEED_BONUS => ScoringMode::SpeedBonus,
+                SCORING_MODE_VALUE_CONFIDENCE_BETTING => ScoringMode::ConfidenceBetting,
+                _ => ScoringMode::Classic,
+            };
+
+            let penalty_mode = match node_refs
+                .penalty_mode_node_ref
+                .cast::<HtmlSelectElement>()
+                .unwrap()
+                .value()
+                .as_str()
+            {
+                PENALTY_MODE_VALUE_SOFT_DRINK => PenaltyMode::SoftDrink,
+                PENALTY_MODE_VALUE_DARE => PenaltyMode::Dare,
+                _ => PenaltyMode::Alcohol,
+            };
+
             if new_error_messages.no_error_set() && new_extended_error_messages.no_error_set() {
+                store_preferences_to_persistent_storage_and_log_warnings(&Preferences {
+                    player_name: Some(player_name.clone()),
+                    just_watch,
+                    count_of_questions: question_count,
+                    minimum_score_per_question: minimum_score,
+                    maximum_answer_seconds_per_question: timer,
+                });
                 on_create_lobby.emit(CreateLobby {
                     player_name,
                     just_watch,
                     count_of_questions: question_count,
                     minimum_score_per_question: minimum_score,
                     maximum_answer_seconds_per_question: timer,
+                    solution_seconds,
+                    scoring_mode,
+                    points_for_correct_answer,
+                    points_for_minority_bonus,
+                    minority_threshold_in_percent,
+                    points_penalty_for_wrong_answer,
+                    penalty_mode,
                 });
             }
 
@@ -432,6 +719,10 @@ pub struct IndexComponentProps {
     pub on_join_lobby: Callback<JoinLobby>,
     #[prop_or_default]
     pub on_create_lobby: Callback<CreateLobby>,
+    #[prop_or_default]
+    pub on_display_lobby: Callback<DisplayLobby>,
+    #[prop_or_default]
+    pub prefilled_invite_code: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -441,6 +732,11 @@ pub struct JoinLobby {
     pub just_watch: bool,
 }
 
+#[derive(Debug, PartialEq, Clone)]
+pub struct DisplayLobby {
+    pub invite_code: String,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct CreateLobby {
     pub player_name: String,
@@ -448,6 +744,13 @@ pub struct CreateLobby {
     pub count_of_questions: Option<u64>,
     pub minimum_score_per_question: Option<i64>,
     pub maximum_answer_seconds_per_question: Option<u64>,
+    pub solution_seconds: Option<u64>,
+    pub scoring_mode: ScoringMode,
+    pub points_for_correct_answer: Option<u16>,
+    pub points_for_minority_bonus: Option<u16>,
+    pub minority_threshold_in_percent: Option<u8>,
+    pub points_penalty_for_wrong_answer: Option<u16>,
+    pub penalty_mode: PenaltyMode,
 }
 
 #[derive(Default, PartialEq)]
@@ -490,6 +793,7 @@ struct ExtendedErrorMessages {
     optional_question_count_error_message_lang_key_id: Option<&'static str>,
     optional_minimum_score_error_message_lang_key_id: Option<&'static str>,
     optional_timer_error_message_lang_key_id: Option<&'static str>,
+    optional_scoring_error_message_lang_key_id: Option<&'static str>,
 }
 
 impl ExtendedErrorMessages {
@@ -500,6 +804,7 @@ impl ExtendedErrorMessages {
                 .optional_minimum_score_error_message_lang_key_id
                 .is_none()
             && self.optional_timer_error_message_lang_key_id.is_none()
+            && self.optional_scoring_error_message_lang_key_id.is_none()
     }
 }
 
@@ -508,6 +813,13 @@ struct ExtendedNodeRefs {
     question_count_node_ref: NodeRef,
     minimum_score_node_ref: NodeRef,
     timer_node_ref: NodeRef,
+    solution_time_node_ref: NodeRef,
+    scoring_mode_node_ref: NodeRef,
+    points_for_correct_answer_node_ref: NodeRef,
+    points_for_minority_bonus_node_ref: NodeRef,
+    minority_threshold_node_ref: NodeRef,
+    points_penalty_for_wrong_answer_node_ref: NodeRef,
+    penalty_mode_node_ref: NodeRef,
 }
 
 #[derive(Default, PartialEq)]
@@ -1,2 +1,48 @@
+pub mod display;
 pub mod index;
 pub mod play;
+
+/// The routes of the client, which are reflected in the path of the browser location,
+/// so that an invite can be shared as a link.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Route {
+    Index,
+    Join { invite_code: String },
+    Play { invite_code: String },
+}
+
+impl Route {
+    /// Splits the path into the base path, under which the game is hosted, and the route.
+    pub fn split_path(path: &str) -> (&str, Route) {
+        let trimmed_path = path.strip_suffix('/').unwrap_or(path);
+        if let Some((rest, invite_code)) = trimmed_path.rsplit_once('/') {
+            let invite_code = urlencoding::decode(invite_code)
+                .map(|invite_code| invite_code.into_owned())
+                .unwrap_or_else(|_| invite_code.to_string());
+            if !invite_code.is_empty() {
+                if let Some(base_path) = rest.strip_suffix("/join") {
+                    return (base_path, Route::Join { invite_code });
+                }
+                if let Some(base_path) = rest.strip_suffix("/play") {
+                    return (base_path, Route::Play { invite_code });
+                }
+            }
+        }
+        (trimmed_path, Route::Index)
+    }
+
+    pub fn to_path(&self) -> String {
+        match self {
+            Route::Index => "/".to_string(),
+            Route::Join { invite_code } => format!("/join/{}", urlencoding::encode(invite_code)),
+            Route::Play { invite_code } => format!("/play/{}", urlencoding::encode(invite_code)),
+        }
+    }
+
+    pub fn invite_code(&self) -> Option<&str> {
+        match self {
+            Route::Index => None,
+            Route::Join { invite_code } | Route::Play { invite_code } => Some(invite_code),
+        }
+    }
+}
@@ -4,7 +4,7 @@ use std::rc::Rc;
 use itertools::Itertools;
 
 use onion_or_not_the_onion_drinking_game_2_shared_library::model::game::{
-    Game, GameState, PlayType,
+    Game, GameState, PenaltyMode, PlayType, PlayerId, PlayerName,
 };
 
 use yew::{classes, function_component, html, use_context, Callback, Html};
@@ -13,6 +13,7 @@ use crate::components::join_game::JoinGameComponent;
 use crate::components::locale::{locale_args, LocaleComponent};
 use crate::components::player_name_type_exit_headline::PlayerNameTypeExitHeadlineComponent;
 use crate::components::playerlist::PlayerListComponent;
+use crate::utils::format_milliseconds_as_seconds;
 
 #[function_component(AftermathComponent)]
 pub fn aftermath_component(props: &AftermathComponentProps) -> Html {
@@ -24,27 +25,50 @@ pub fn aftermath_component(props: &AftermathComponentProps) -> Html {
     let on_exit_game_wished = Callback::from(move |_| cloned_on_exit_game_wish.emit(()));
 
     let optional_ranking_html: Option<Html> = match &game.game_state {
-        GameState::Aftermath { ranked_players, .. } => {
-            let ranked_players = ranked_players.clone();
+        GameState::Aftermath {
+            ranked_players,
+            tiebreaker_winner,
+            eliminated_players,
+            ..
+        } => {
+            // Players, who stayed in the game longer, rank above eliminated ones, and the winner
+            // of the tiebreaker ranks above the players sharing their points
+            let ranked_players: Vec<(PlayerName, (usize, bool, u16))> = ranked_players
+                .iter()
+                .map(|(player_id, player_name, points)| {
+                    let index_of_eliminating_question = eliminated_players
+                        .iter()
+                        .find(|(eliminated_player, _)| eliminated_player == player_id)
+                        .map_or(usize::MAX, |(_, index_of_question)| *index_of_question);
+                    (
+                        player_name.clone(),
+                        (
+                            index_of_eliminating_question,
+                            *tiebreaker_winner == Some(*player_id),
+                            *points,
+                        ),
+                    )
+                })
+                .collect();
             if ranked_players.is_empty() {
                 None
             } else {
-                let possible_points: HashMap<u16, usize> = ranked_players
+                let possible_ranking_keys: HashMap<(usize, bool, u16), usize> = ranked_players
                     .iter()
-                    .map(|(_, _, c)| *c)
+                    .map(|(_, ranking_key)| *ranking_key)
                     .unique()
                     .sorted()
                     .rev()
                     .enumerate()
-                    .map(|(index, points)| (points, index))
+                    .map(|(index, ranking_key)| (ranking_key, index))
                     .collect();
                 Some(
                     ranked_players
                         .into_iter()
-                        .sorted_by_key(|(_, _, points)| *points)
+                        .sorted_by_key(|(_, ranking_key)| *ranking_key)
                         .rev()
-                        .map(|(_, player_name, points)| {
-                            let rank = *possible_points.get(&points).unwrap() + 1;
+                        .map(|(player_name, ranking_key @ (_, _, points))| {
+                            let rank = *possible_ranking_keys.get(&ranking_key).unwrap() + 1;
                             let ranking_css_class = match rank {
                                 1 => "player-ranking__placement--first_place",
                                 2 => "player-ranking__placement--second_place",
@@ -64,7 +88,49 @@ pub fn aftermath_component(props: &AftermathComponentProps) -> Html {
                 )
             }
         }
-        GameState::InLobby | GameState::Playing { .. } => unreachable!(),
+        GameState::InLobby | GameState::Playing { .. } | GameState::Tiebreaker { .. } => {
+            unreachable!()
+        }
+    };
+
+    let optional_drink_summary_html: Option<Html> = match &game.game_state {
+        GameState::Aftermath {
+            drink_leaderboard, ..
+        } => view_drink_summary(drink_leaderboard, game.configuration.penalty_mode),
+        GameState::InLobby | GameState::Playing { .. } | GameState::Tiebreaker { .. } => {
+            unreachable!()
+        }
+    };
+
+    let optional_elimination_order_html: Option<Html> = match &game.game_state {
+        GameState::Aftermath {
+            ranked_players,
+            eliminated_players,
+            ..
+        } => view_elimination_order(ranked_players, eliminated_players),
+        GameState::InLobby | GameState::Playing { .. } | GameState::Tiebreaker { .. } => {
+            unreachable!()
+        }
+    };
+
+    let count_of_played_questions = match &game.game_state {
+        GameState::Aftermath {
+            count_of_played_questions,
+            ..
+        } => *count_of_played_questions,
+        GameState::InLobby | GameState::Playing { .. } | GameState::Tiebreaker { .. } => {
+            unreachable!()
+        }
+    };
+
+    let optional_response_times_html: Option<Html> = match &game.game_state {
+        GameState::Aftermath {
+            average_response_times,
+            ..
+        } => view_average_response_times(average_response_times),
+        GameState::InLobby | GameState::Playing { .. } | GameState::Tiebreaker { .. } => {
+            unreachable!()
+        }
     };
 
     html! {
@@ -75,6 +141,10 @@ pub fn aftermath_component(props: &AftermathComponentProps) -> Html {
                 <h1>
                     <LocaleComponent keyid="aftermath-view-headline" />
                 </h1>
+                <p class={classes!("aftermath-count-of-played-questions")}>
+                    <LocaleComponent keyid="aftermath-view-count-of-played-questions"
+                        args={locale_args([("count", count_of_played_questions.into())])} />
+                </p>
                 {
                     match &game.get_this_player().unwrap().play_type {
                         PlayType::Player { .. } => {
@@ -82,7 +152,9 @@ pub fn aftermath_component(props: &AftermathComponentProps) -> Html {
                                 GameState::Aftermath { restart_requests, .. } => {
                                     restart_requests.contains(&game.get_this_player().unwrap().id)
                                 }
-                                GameState::InLobby | GameState::Playing { .. } => unreachable!(),
+                                GameState::InLobby | GameState::Playing { .. } | GameState::Tiebreaker { .. } => {
+            unreachable!()
+        }
                             };
                             if has_skipped {
                                 html! {
@@ -121,14 +193,190 @@ pub fn aftermath_component(props: &AftermathComponentProps) -> Html {
                         }
                     }
                 }
-                <PlayerListComponent class={classes!("play-primary-content__player-list")} />
+                if let Some(elimination_order_html) = optional_elimination_order_html {
+                    <h2>
+                        <LocaleComponent keyid="aftermath-view-elimination-order-headline" />
+                    </h2>
+                    { elimination_order_html }
+                }
+                if let Some(drink_summary_html) = optional_drink_summary_html {
+                    <h2>
+                        <LocaleComponent keyid="aftermath-view-drinks-headline" />
+                    </h2>
+                    { drink_summary_html }
+                }
+                if let Some(response_times_html) = optional_response_times_html {
+                    <h2>
+                        <LocaleComponent keyid="aftermath-view-response-times-headline" />
+                    </h2>
+                    { response_times_html }
+                }
+                <PlayerListComponent class={classes!("play-primary-content__player-list")}
+                    on_vote_kick={props.on_vote_kick.clone()} />
             </section>
         </main>
     }
 }
 
+fn view_drink_summary(
+    drink_leaderboard: &[(PlayerId, PlayerName, u16, u16)],
+    penalty_mode: PenaltyMode,
+) -> Option<Html> {
+    let (most_sips_taken_keyid, most_sips_handed_out_keyid) = match penalty_mode {
+        PenaltyMode::Alcohol => (
+            "aftermath-view-drinks-most-sips-taken",
+            "aftermath-view-drinks-most-sips-handed-out",
+        ),
+        PenaltyMode::SoftDrink | PenaltyMode::Dare => (
+            "aftermath-view-drinks-most-penalties-taken",
+            "aftermath-view-drinks-most-penalties-handed-out",
+        ),
+    };
+
+    let most_sips_taken = drink_leaderboard
+        .iter()
+        .map(|(_, _, sips_taken, _)| *sips_taken)
+        .max()
+        .filter(|sips_taken| *sips_taken > 0);
+    let most_sips_handed_out = drink_leaderboard
+        .iter()
+        .map(|(_, _, _, sips_handed_out)| *sips_handed_out)
+        .max()
+        .filter(|sips_handed_out| *sips_handed_out > 0);
+
+    if most_sips_taken.is_none() && most_sips_handed_out.is_none() {
+        return None;
+    }
+
+    let most_sips_taken_html = match most_sips_taken {
+        Some(most_sips_taken) => {
+            let player_names = drink_leaderboard
+                .iter()
+                .filter(|(_, _, sips_taken, _)| *sips_taken == most_sips_taken)
+                .map(|(_, player_name, _, _)| player_name.to_string())
+                .join(", ");
+            html! {
+                <li class={classes!("drink-summary__entry")}>
+                    <LocaleComponent
+                        keyid={most_sips_taken_keyid}
+                        args={locale_args([("players", player_names.into()), ("sips", most_sips_taken.into())])} />
+                </li>
+            }
+        }
+        None => Html::default(),
+    };
+
+    let most_sips_handed_out_html = match most_sips_handed_out {
+        Some(most_sips_handed_out) => {
+            let player_names = drink_leaderboard
+                .iter()
+                .filter(|(_, _, _, sips_handed_out)| *sips_handed_out == most_sips_handed_out)
+                .map(|(_, player_name, _, _)| player_name.to_string())
+                .join(", ");
+            html! {
+                <li class={classes!("drink-summary__entry")}>
+                    <LocaleComponent
+                        keyid={most_sips_handed_out_keyid}
+                        args={locale_args([("players", player_names.into()), ("sips", most_sips_handed_out.into())])} />
+                </li>
+            }
+        }
+        None => Html::default(),
+    };
+
+    Some(html! {
+        <ul class={classes!("drink-summary")}>
+            { most_sips_taken_html }
+            { most_sips_handed_out_html }
+        </ul>
+    })
+}
+
+fn view_elimination_order(
+    ranked_players: &[(PlayerId, PlayerName, u16)],
+    eliminated_players: &[(PlayerId, usize)],
+) -> Option<Html> {
+    if eliminated_players.is_empty() {
+        return None;
+    }
+
+    // Players eliminated by the same question share their place in the elimination order
+    let mut eliminations_per_question: Vec<(usize, Vec<String>)> = Vec::new();
+    for (eliminated_player, index_of_question) in eliminated_players {
+        let Some((_, player_name, _)) = ranked_players
+            .iter()
+            .find(|(player_id, _, _)| player_id == eliminated_player)
+        else {
+            continue;
+        };
+        match eliminations_per_question.last_mut() {
+            Some((last_index_of_question, player_names))
+                if last_index_of_question == index_of_question =>
+            {
+                player_names.push(player_name.to_string());
+            }
+            _ => {
+                eliminations_per_question.push((*index_of_question, vec![player_name.to_string()]))
+            }
+        }
+    }
+
+    Some(html! {
+        <ol class={classes!("elimination-order")}>
+            {
+                eliminations_per_question
+                    .into_iter()
+                    .map(|(index_of_question, player_names)| {
+                        html! {
+                            <li class={classes!("elimination-order__entry")}>
+                                <LocaleComponent
+                                    keyid="aftermath-view-elimination-order-entry"
+                                    args={locale_args([
+                                        ("players", player_names.join(", ").into()),
+                                        ("question", (index_of_question + 1).into()),
+                                    ])} />
+                            </li>
+                        }
+                    })
+                    .collect::<Html>()
+            }
+        </ol>
+    })
+}
+
+fn view_average_response_times(
+    average_response_times: &[(PlayerId, PlayerName, u64)],
+) -> Option<Html> {
+    if average_response_times.is_empty() {
+        return None;
+    }
+
+    Some(html! {
+        <ul class={classes!("response-times")}>
+            {
+                average_response_times
+                    .iter()
+                    .map(|(_, player_name, average_response_time)| {
+                        html! {
+                            <li class={classes!("response-times__entry")}>
+                                <LocaleComponent
+                                    keyid="aftermath-view-response-times-average"
+                                    args={locale_args([
+                                        ("player", player_name.to_string().into()),
+                                        ("seconds", format_milliseconds_as_seconds(*average_response_time).into()),
+                                    ])} />
+                            </li>
+                        }
+                    })
+                    .collect::<Html>()
+            }
+        </ul>
+    })
+}
+
 #[derive(yew::Properties, PartialEq)]
 pub struct AftermathComponentProps {
     pub on_exit_game_wish: Callback<()>,
     pub on_play_again_wish: Callback<()>,
+    pub on_vote_kick: Callback<PlayerId>,
 }
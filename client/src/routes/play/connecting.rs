@@ -9,13 +9,17 @@ pub fn connecting_component(props: &ConnectingComponentProps) -> Html {
     let _langid = use_context::<LanguageIdentifier>().expect("Missing LanguageIdentifier context.");
 
     match &props.state {
-        ConnectingComponentState::Connecting => {
+        ConnectingComponentState::Connecting | ConnectingComponentState::Reconnecting => {
+            let keyid = match &props.state {
+                ConnectingComponentState::Reconnecting => "connecting-view-reconnecting-string",
+                _ => "connecting-view-connecting-string",
+            };
             let cloned_on_cancel = props.on_cancel.clone();
             let cancel_button_onclick = Callback::from(move |_| cloned_on_cancel.emit(()));
             html! {
                 <main class={classes!("main", "main--padding-normal", "connecting-view")}>
                     <p class={classes!("connecting-view__main-text")}>
-                        <LocaleComponent keyid="connecting-view-connecting-string" />
+                        <LocaleComponent {keyid} />
                     </p>
                     <button class={classes!("button", "connecting-view__button")}
                         onclick={cancel_button_onclick} type="button">
@@ -62,6 +66,7 @@ pub struct ConnectingComponentProps {
 #[derive(PartialEq)]
 pub enum ConnectingComponentState {
     Connecting,
+    Reconnecting,
     Failed {
         locale_key_id: String,
         error: Option<String>,
@@ -1,24 +1,31 @@
+use std::collections::HashSet;
 use std::rc::Rc;
 
-use chrono::Utc;
-
 use onion_or_not_the_onion_drinking_game_2_shared_library::model::game::{
-    Answer, Game, GameState, PlayingState,
+    Answer, DrinkReason, Drinks, Emoji, Game, GameState, PenaltyMode, PlayType, PlayerId,
+    PlayingState, ReportReason, ScoringMode, TeamAnswerMode,
 };
 
+use web_sys::HtmlInputElement;
+
 use yew::{
     classes, function_component, html, use_context, use_effect_with_deps, use_force_update,
-    use_state, Callback, Html,
+    use_node_ref, use_state, Callback, Html, NodeRef,
 };
 
+use crate::components::chat::ChatComponent;
 use crate::components::join_game::JoinGameComponent;
 use crate::components::locale::{locale_args, LocaleComponent};
+use crate::components::penalty::PenaltyCardComponent;
 use crate::components::player_name_type_exit_headline::PlayerNameTypeExitHeadlineComponent;
 use crate::components::playerlist::PlayerListComponent;
+use crate::routes::play::server_clock::ServerClock;
+use crate::utils::format_milliseconds_as_seconds;
 
 #[function_component(GameComponent)]
 pub fn game_component(props: &GameComponentProps) -> Html {
     let game: Rc<Game> = use_context().expect("Missing Game context.");
+    let server_clock: ServerClock = use_context().expect("Missing ServerClock context.");
 
     let update_timer = use_state::<Option<gloo_timers::callback::Interval>, _>(|| None);
     let force_update = use_force_update();
@@ -32,6 +39,8 @@ pub fn game_component(props: &GameComponentProps) -> Html {
         (),
     );
 
+    let stake_node_ref = use_node_ref();
+
     let invite_code = game.invite_code.to_string();
 
     let cloned_on_exit_game_wish = props.on_exit_game_wish.clone();
@@ -42,42 +51,169 @@ pub fn game_component(props: &GameComponentProps) -> Html {
             <JoinGameComponent {invite_code} />
             <section class={classes!("centered-primary-content", "play-primary-content")}>
                 <PlayerNameTypeExitHeadlineComponent {on_exit_game_wished} />
-                { view_remaining_time(props, &game) }
-                { view_question_or_solution(props, &game) }
-                <PlayerListComponent class={classes!("play-primary-content__player-list")} />
+                { view_tiebreaker_notice(&game) }
+                { view_eliminated_notice(&game) }
+                { view_remaining_time(props, &game, &server_clock) }
+                { view_question_or_solution(props, &game, &server_clock, &stake_node_ref) }
+                <PlayerListComponent class={classes!("play-primary-content__player-list")}
+                    on_vote_kick={props.on_vote_kick.clone()} />
+                { view_host_game_controls(props, &game) }
+                <ChatComponent class={classes!("play-primary-content__chat")}
+                    on_send_chat_message={props.on_send_chat_message.clone()}
+                    on_react={props.on_react.clone()} />
             </section>
+            { view_paused_overlay(props, &game) }
         </main>
     }
 }
-fn view_remaining_time(props: &GameComponentProps, game: &Rc<Game>) -> Html {
+
+fn view_tiebreaker_notice(game: &Rc<Game>) -> Html {
+    match &game.game_state {
+        GameState::Tiebreaker {
+            contenders,
+            eliminated,
+            ..
+        } => {
+            let contender_names = game
+                .players
+                .iter()
+                .filter(|player| contenders.contains(&player.id))
+                .map(|player| player.name.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let this_player_notice_keyid = if contenders.contains(&game.this_player_id) {
+                "game-view-tiebreaker-contender"
+            } else if eliminated.contains(&game.this_player_id) {
+                "game-view-tiebreaker-eliminated"
+            } else {
+                "game-view-tiebreaker-watching"
+            };
+            html! {
+                <section class={classes!("tiebreaker-notice")}>
+                    <h2 class={classes!("tiebreaker-notice__headline")}>
+                        <LocaleComponent keyid="game-view-tiebreaker-headline" />
+                    </h2>
+                    <p>
+                        <LocaleComponent keyid="game-view-tiebreaker-contenders"
+                            args={locale_args([("players", contender_names.into())])} />
+                        <br/>
+                        <LocaleComponent keyid={this_player_notice_keyid} />
+                    </p>
+                </section>
+            }
+        }
+        GameState::InLobby | GameState::Playing { .. } | GameState::Aftermath { .. } => {
+            Html::default()
+        }
+    }
+}
+
+fn view_eliminated_notice(game: &Rc<Game>) -> Html {
+    match &game.game_state {
+        GameState::Playing {
+            eliminated_players, ..
+        } if eliminated_players.contains(&game.this_player_id) => html! {
+            <p class={classes!("eliminated-notice")}>
+                <LocaleComponent keyid="game-view-eliminated" />
+            </p>
+        },
+        _ => Html::default(),
+    }
+}
+
+fn view_host_game_controls(props: &GameComponentProps, game: &Rc<Game>) -> Html {
+    if game.is_this_player_host() {
+        let cloned_on_pause_game = props.on_pause_game.clone();
+        let onclick_pause_game_button = Callback::from(move |_| cloned_on_pause_game.emit(()));
+        let cloned_on_end_game = props.on_end_game.clone();
+        let onclick_end_game_button = Callback::from(move |_| cloned_on_end_game.emit(()));
+        html! {
+            <section class={classes!("host-game-controls")}>
+                <button type="button" class={classes!("button", "host-game-controls__button")} onclick={onclick_pause_game_button}>
+                    <LocaleComponent keyid="game-view-pause-game-button" />
+                </button>
+                <button type="button" class={classes!("button", "host-game-controls__button")} onclick={onclick_end_game_button}>
+                    <LocaleComponent keyid="game-view-end-game-button" />
+                </button>
+            </section>
+        }
+    } else {
+        Html::default()
+    }
+}
+
+fn view_paused_overlay(props: &GameComponentProps, game: &Rc<Game>) -> Html {
+    if game.paused_at.is_none() {
+        return Html::default();
+    }
+
+    let cloned_on_resume_game = props.on_resume_game.clone();
+    let onclick_resume_game_button = Callback::from(move |_| cloned_on_resume_game.emit(()));
+    html! {
+        <section class={classes!("paused-overlay")}>
+            <h1 class={classes!("paused-overlay__headline")}>
+                <LocaleComponent keyid="game-view-paused-headline" />
+            </h1>
+            if game.is_this_player_host() {
+                <button type="button" class={classes!("button")} onclick={onclick_resume_game_button}>
+                    <LocaleComponent keyid="game-view-resume-game-button" />
+                </button>
+            } else {
+                <p>
+                    <LocaleComponent keyid="game-view-paused-waiting-for-host" />
+                </p>
+            }
+        </section>
+    }
+}
+
+fn view_remaining_time(
+    props: &GameComponentProps,
+    game: &Rc<Game>,
+    server_clock: &ServerClock,
+) -> Html {
     let this_player_is_watcher = game.get_this_player().unwrap().is_watcher();
 
-    if let GameState::Playing { playing_state, .. } = &game.game_state {
+    if let Some(playing_state) = game.game_state.playing_state() {
         match playing_state {
-            PlayingState::Question { time_until, .. } => match time_until {
-                Some(some_time_until) => {
-                    let duration = *some_time_until - Utc::now();
-                    html! {
-                        <section class={classes!("remaining-question-time")}>
+            PlayingState::Question {
+                time_until,
+                skip_request,
+                ..
+            } => {
+                let remaining_time_html = match time_until {
+                    Some(some_time_until) => {
+                        let duration =
+                            *some_time_until - game.paused_at.unwrap_or_else(|| server_clock.now());
+                        html! {
                             <LocaleComponent keyid="game-view-question-playing-state-remaining-seconds"
                                 args={locale_args([("seconds", duration.num_seconds().into())])} />
-                        </section>
+                        }
                     }
-                }
-                None => {
-                    html! {
-                        <section class={classes!("remaining-question-time")}>
+                    None => {
+                        html! {
                             <LocaleComponent keyid="game-view-question-playing-state-infinite-remaining-seconds" />
-                        </section>
+                        }
                     }
+                };
+                // Only regular questions can be skipped, not the ones of the tiebreaker
+                let can_vote_to_skip =
+                    !this_player_is_watcher && matches!(game.game_state, GameState::Playing { .. });
+                html! {
+                    <section class={classes!("remaining-question-time")}>
+                        { remaining_time_html }
+                        if can_vote_to_skip {
+                            { view_question_skip_vote(props, game, skip_request) }
+                        }
+                    </section>
                 }
-            },
+            }
             PlayingState::Solution {
                 time_until,
                 skip_request,
                 ..
             } => {
-                let duration = *time_until - Utc::now();
+                let duration = *time_until - game.paused_at.unwrap_or_else(|| server_clock.now());
                 html! {
                     <section class={classes!("remaining-solution-time")}>
                         <LocaleComponent keyid="game-view-solution-playing-state-remaining-seconds"
@@ -111,15 +247,55 @@ fn view_remaining_time(props: &GameComponentProps, game: &Rc<Game>) -> Html {
     }
 }
 
-fn view_question_or_solution(props: &GameComponentProps, game: &Rc<Game>) -> Html {
-    let this_player_is_watcher = game.get_this_player().unwrap().is_watcher();
-    if let GameState::Playing { playing_state, .. } = &game.game_state {
+fn view_question_skip_vote(
+    props: &GameComponentProps,
+    game: &Rc<Game>,
+    skip_request: &HashSet<PlayerId>,
+) -> Html {
+    let count_of_skip_votes = skip_request.len();
+    if skip_request.contains(&game.this_player_id) {
+        html! {
+            <>
+                {" - "}
+                <LocaleComponent keyid="game-view-question-playing-state-voted-to-skip"
+                    args={locale_args([("votes", count_of_skip_votes.into())])} />
+            </>
+        }
+    } else {
+        let cloned_on_request_skip = props.on_request_skip.clone();
+        let onclick_skip_button = Callback::from(move |_| cloned_on_request_skip.emit(()));
+        html! {
+            <button type="button" class={classes!("button", "solution-skip-button")} onclick={onclick_skip_button}>
+                <LocaleComponent keyid="game-view-question-playing-state-vote-to-skip"
+                    args={locale_args([("votes", count_of_skip_votes.into())])} />
+            </button>
+        }
+    }
+}
+
+fn view_question_or_solution(
+    props: &GameComponentProps,
+    game: &Rc<Game>,
+    server_clock: &ServerClock,
+    stake_node_ref: &NodeRef,
+) -> Html {
+    // Players, who aren't contenders of the tiebreaker, only watch it
+    let this_player_is_watcher = game.get_this_player().unwrap().is_watcher()
+        || matches!(&game.game_state, GameState::Tiebreaker { contenders, .. } if !contenders.contains(&game.this_player_id));
+    let is_tiebreaker = matches!(game.game_state, GameState::Tiebreaker { .. });
+    if let Some(playing_state) = game.game_state.playing_state() {
         match playing_state {
             PlayingState::Question {
                 current_question,
+                time_until,
                 own_answer,
+                own_stake,
                 ..
             } => {
+                // Answers after the end of the countdown would be rejected by the server anyway
+                let is_too_late = time_until.map_or(false, |time_until| {
+                    time_until <= game.paused_at.unwrap_or_else(|| server_clock.now())
+                });
                 let this_player_answer_is_the_onion = own_answer
                     .map(|answer| answer == Answer::TheOnion)
                     .unwrap_or(false)
@@ -129,26 +305,48 @@ fn view_question_or_solution(props: &GameComponentProps, game: &Rc<Game>) -> Htm
                     .unwrap_or(false)
                     .then_some("question-button--chosen");
 
+                // With a captain answering for the team, the other team members only watch
+                let optional_answering_captain_name = game
+                    .configuration
+                    .team_setup
+                    .filter(|team_setup| team_setup.team_answer_mode == TeamAnswerMode::Captain)
+                    .and(game.get_this_player().unwrap().team)
+                    .and_then(|team| game.get_team_captain(team))
+                    .filter(|captain| captain.id != game.this_player_id)
+                    .map(|captain| captain.name.to_string());
+
                 html! {
                     <>
                         <h1 class={classes!("question-headline")}>
                             {current_question.title.clone()}
                         </h1>
                         {
-                            if !this_player_is_watcher {
+                            if let Some(captain_name) = optional_answering_captain_name {
+                                html! {
+                                    <p class={classes!("question-captain-answers")}>
+                                        <LocaleComponent keyid="game-view-question-playing-state-captain-answers"
+                                            args={locale_args([("captain", captain_name.into())])} />
+                                    </p>
+                                }
+                            } else if !this_player_is_watcher {
                                 let cloned_on_choose_answer = props.on_choose_answer.clone();
                                 let onclick_the_onion = Callback::from(move |_| cloned_on_choose_answer.emit(Answer::TheOnion));
                                 let cloned_on_choose_answer = props.on_choose_answer.clone();
                                 let onclick_not_the_onion = Callback::from(move |_| cloned_on_choose_answer.emit(Answer::NotTheOnion));
                                 html! {
+                                <>
                                     <section class={classes!("question-buttons-container")}>
-                                        <button type="button" class={classes!("button", "question-button--the-onion", this_player_answer_is_the_onion)} onclick={onclick_the_onion}>
+                                        <button type="button" class={classes!("button", "question-button--the-onion", this_player_answer_is_the_onion)} disabled={is_too_late} onclick={onclick_the_onion}>
                                             <LocaleComponent keyid="game-view-question-playing-state-selection-button-the-onion" />
                                         </button>
-                                        <button type="button" class={classes!("button", "question-button--not-the-onion", this_player_answer_is_not_the_onion)} onclick={onclick_not_the_onion}>
+                                        <button type="button" class={classes!("button", "question-button--not-the-onion", this_player_answer_is_not_the_onion)} disabled={is_too_late} onclick={onclick_not_the_onion}>
                                             <LocaleComponent keyid="game-view-question-playing-state-selection-button-not-the-onion" />
                                         </button>
                                     </section>
+                                    if game.configuration.scoring_mode == ScoringMode::ConfidenceBetting && !is_tiebreaker {
+                                        { view_stake_form(props, game, stake_node_ref, *own_stake, own_answer.is_some() || is_too_late) }
+                                    }
+                                </>
                                 }
                             } else {
                                 html! {}
@@ -160,6 +358,10 @@ fn view_question_or_solution(props: &GameComponentProps, game: &Rc<Game>) -> Htm
             PlayingState::Solution {
                 current_question,
                 answers,
+                fastest_correct_answer,
+                drinks,
+                stakes,
+                own_report,
                 ..
             } => {
                 let this_player_answer = answers.get(&game.this_player_id);
@@ -179,6 +381,27 @@ fn view_question_or_solution(props: &GameComponentProps, game: &Rc<Game>) -> Htm
                     }
                 };
 
+                // A stake without an answer is lost as well
+                let this_player_stake_result = stakes.get(&game.this_player_id).map(|stake| {
+                    let stake_result_keyid = if this_player_answer_correct == Some(true) {
+                        "game-view-solution-playing-state-sub-headline-player-stake-won"
+                    } else {
+                        "game-view-solution-playing-state-sub-headline-player-stake-lost"
+                    };
+                    (stake_result_keyid, *stake)
+                });
+
+                let this_player_streak =
+                    game.get_this_player()
+                        .map(|player| player.streak)
+                        .filter(|streak| {
+                            *streak > 0 && game.configuration.scoring_rules.is_on_fire(*streak)
+                        });
+                let this_player_streak_broken =
+                    drinks.get(&game.this_player_id).map_or(false, |drinks| {
+                        drinks.reasons.contains(&DrinkReason::StreakBroken)
+                    });
+
                 let sub_headline_locale = match current_question.answer {
                     Answer::TheOnion => "game-view-solution-playing-state-sub-headline-the-onion",
                     Answer::NotTheOnion => {
@@ -203,6 +426,21 @@ fn view_question_or_solution(props: &GameComponentProps, game: &Rc<Game>) -> Htm
                                         <>
                                             <br/>
                                             <LocaleComponent keyid={this_player_answer_locale} />
+                                            if let Some((stake_result_keyid, stake)) = this_player_stake_result {
+                                                <br/>
+                                                <LocaleComponent keyid={stake_result_keyid}
+                                                    args={locale_args([("points", stake.into())])} />
+                                            }
+                                            if let Some(streak) = this_player_streak {
+                                                <br/>
+                                                <LocaleComponent keyid="game-view-solution-playing-state-sub-headline-player-on-fire"
+                                                    args={locale_args([("streak", streak.into())])} />
+                                            }
+                                            if this_player_streak_broken {
+                                                <br/>
+                                                <LocaleComponent keyid="game-view-solution-playing-state-sub-headline-player-streak-broken" />
+                                            }
+                                            { view_this_player_drinks(drinks.get(&game.this_player_id), game.configuration.penalty_mode) }
                                         </>
                                     }
                                 }
@@ -216,7 +454,17 @@ fn view_question_or_solution(props: &GameComponentProps, game: &Rc<Game>) -> Htm
                                 <LocaleComponent keyid="game-view-solution-playing-state-link-to-newspaper-posting-anchor-text"/>
                             </a>
                         </section>
+                        if let Some((_, player_name, response_time)) = fastest_correct_answer {
+                            <p class={classes!("fastest-correct-answer")}>
+                                <LocaleComponent keyid="game-view-solution-playing-state-fastest-correct-answer"
+                                    args={locale_args([
+                                        ("player", player_name.to_string().into()),
+                                        ("seconds", format_milliseconds_as_seconds(*response_time).into()),
+                                    ])} />
+                            </p>
+                        }
                         <img class={classes!("question-picture")} src={current_question.preview_image_url.clone().unwrap_or_default()}/>
+                        { view_report_question(props, *own_report) }
                     </>
                 }
             }
@@ -226,9 +474,139 @@ fn view_question_or_solution(props: &GameComponentProps, game: &Rc<Game>) -> Htm
     }
 }
 
+fn view_report_question(props: &GameComponentProps, own_report: Option<ReportReason>) -> Html {
+    if own_report.is_some() {
+        return html! {
+            <p class={classes!("report-question")}>
+                <LocaleComponent keyid="game-view-solution-playing-state-report-thanks" />
+            </p>
+        };
+    }
+
+    let report_buttons_html = [
+        (ReportReason::DeletedPost, "game-view-solution-playing-state-report-deleted-post"),
+        (ReportReason::Duplicate, "game-view-solution-playing-state-report-duplicate"),
+        (ReportReason::NeedsImage, "game-view-solution-playing-state-report-needs-image"),
+        (ReportReason::Misleading, "game-view-solution-playing-state-report-misleading"),
+    ]
+    .into_iter()
+    .map(|(reason, keyid)| {
+        let cloned_on_report_question = props.on_report_question.clone();
+        let onclick_report_button = Callback::from(move |_| cloned_on_report_question.emit(reason));
+        html! {
+            <button type="button" class={classes!("button", "report-question__button")} onclick={onclick_report_button}>
+                <LocaleComponent {keyid} />
+            </button>
+        }
+    })
+    .collect::<Html>();
+
+    html! {
+        <section class={classes!("report-question")}>
+            <LocaleComponent keyid="game-view-solution-playing-state-report-label" />
+            { report_buttons_html }
+        </section>
+    }
+}
+
+fn view_stake_form(
+    props: &GameComponentProps,
+    game: &Rc<Game>,
+    stake_node_ref: &NodeRef,
+    own_stake: Option<u16>,
+    is_stake_locked: bool,
+) -> Html {
+    let this_player_points = match game.get_this_player().unwrap().play_type {
+        PlayType::Player { points, .. } => points,
+        PlayType::Watcher => 0,
+    };
+
+    let cloned_on_place_stake = props.on_place_stake.clone();
+    let cloned_stake_node_ref = stake_node_ref.clone();
+    let onclick_place_stake = Callback::from(move |_| {
+        let stake_str = cloned_stake_node_ref
+            .cast::<HtmlInputElement>()
+            .unwrap()
+            .value();
+        match stake_str.trim().parse::<u16>() {
+            Ok(stake) => cloned_on_place_stake.emit(stake),
+            Err(error) => log::warn!("Could not parse stake \"{stake_str}\" ({error})."),
+        }
+    });
+
+    html! {
+        <section class={classes!("stake-form")}>
+            <label for="stake">
+                <LocaleComponent keyid="game-view-question-playing-state-stake-label"
+                    args={locale_args([("points", this_player_points.into())])} />
+            </label>
+            <input autocomplete="off"
+                class={classes!("input-field")}
+                id="stake"
+                max={this_player_points.to_string()}
+                min="0"
+                ref={stake_node_ref.clone()}
+                type="number"
+                disabled={is_stake_locked}
+                value={own_stake.unwrap_or(0).to_string()} />
+            <button type="button" class={classes!("button", "stake-form__button")} disabled={is_stake_locked} onclick={onclick_place_stake}>
+                <LocaleComponent keyid="game-view-question-playing-state-stake-button" />
+            </button>
+            if let Some(stake) = own_stake {
+                <p class={classes!("stake-form__current-stake")}>
+                    <LocaleComponent keyid="game-view-question-playing-state-stake-placed"
+                        args={locale_args([("points", stake.into())])} />
+                </p>
+            }
+        </section>
+    }
+}
+
+fn view_this_player_drinks(optional_drinks: Option<&Drinks>, penalty_mode: PenaltyMode) -> Html {
+    match optional_drinks {
+        Some(drinks) => {
+            let sips_to_hand_out_keyid = match penalty_mode {
+                PenaltyMode::Alcohol => {
+                    "game-view-solution-playing-state-sub-headline-player-drinks-sips-to-hand-out"
+                }
+                PenaltyMode::SoftDrink | PenaltyMode::Dare => {
+                    "game-view-solution-playing-state-sub-headline-player-drinks-penalties-to-hand-out"
+                }
+            };
+            html! {
+                <>
+                    if drinks.sips > 0 {
+                        <br/>
+                        if let Some(penalty_card) = drinks.penalty_card {
+                            <PenaltyCardComponent {penalty_card} sips={drinks.sips} />
+                        } else {
+                            <LocaleComponent keyid="game-view-solution-playing-state-sub-headline-player-drinks-sips"
+                                args={locale_args([("sips", drinks.sips.into())])} />
+                        }
+                    }
+                    if drinks.sips_to_hand_out > 0 {
+                        <br/>
+                        <LocaleComponent keyid={sips_to_hand_out_keyid}
+                            args={locale_args([("sips", drinks.sips_to_hand_out.into())])} />
+                    }
+                </>
+            }
+        }
+        None => Html::default(),
+    }
+}
+
 #[derive(yew::Properties, PartialEq)]
 pub struct GameComponentProps {
     pub on_exit_game_wish: Callback<()>,
     pub on_choose_answer: Callback<Answer>,
+    pub on_place_stake: Callback<u16>,
     pub on_request_skip: Callback<()>,
+    pub on_end_game: Callback<()>,
+    pub on_pause_game: Callback<()>,
+    pub on_resume_game: Callback<()>,
+    pub on_vote_kick: Callback<PlayerId>,
+    pub on_report_question: Callback<ReportReason>,
+    pub on_send_chat_message: Callback<String>,
+    pub on_react: Callback<Emoji>,
 }
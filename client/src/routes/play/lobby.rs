@@ -1,24 +1,46 @@
 use std::rc::Rc;
 
-use onion_or_not_the_onion_drinking_game_2_shared_library::model::game::{Game, PlayType};
+use fluent_templates::LanguageIdentifier;
 
-use yew::{classes, function_component, html, use_context, Callback, Html};
+use onion_or_not_the_onion_drinking_game_2_shared_library::model::game::{
+    Emoji, Game, GameConfiguration, GameMode, PenaltyMode, PlayerId, ScoringMode, ScoringRules,
+    TeamAnswerMode, TeamId, TeamSetup,
+};
 
+use web_sys::{HtmlInputElement, HtmlSelectElement, SubmitEvent};
+
+use yew::{
+    classes, function_component, html, use_context, use_state_eq, Callback, Html, NodeRef,
+    UseStateHandle,
+};
+
+use crate::components::chat::ChatComponent;
 use crate::components::join_game::JoinGameComponent;
-use crate::components::locale::LocaleComponent;
+use crate::components::locale::{locale, LocaleComponent};
 use crate::components::player_name_type_exit_headline::PlayerNameTypeExitHeadlineComponent;
 use crate::components::playerlist::PlayerListComponent;
+use crate::routes::index::{
+    PENALTY_MODE_VALUE_ALCOHOL, PENALTY_MODE_VALUE_DARE, PENALTY_MODE_VALUE_SOFT_DRINK,
+    SCORING_MODE_VALUE_CLASSIC, SCORING_MODE_VALUE_CONFIDENCE_BETTING,
+    SCORING_MODE_VALUE_SPEED_BONUS,
+};
+
+const TEAM_ANSWER_MODE_VALUE_MAJORITY_VOTE: &str = "majority_vote";
+const TEAM_ANSWER_MODE_VALUE_CAPTAIN: &str = "captain";
+const GAME_MODE_VALUE_CLASSIC: &str = "classic";
+const GAME_MODE_VALUE_ELIMINATION: &str = "elimination";
+const GAME_MODE_VALUE_LIVES: &str = "lives";
+const DEFAULT_COUNT_OF_LIVES: u8 = 3;
 
 #[function_component(LobbyComponent)]
 pub fn lobby_component(props: &LobbyComponentProps) -> Html {
+    let langid = use_context::<LanguageIdentifier>().expect("Missing LanguageIdentifier context.");
     let game: Rc<Game> = use_context().expect("Missing Game context.");
 
-    let this_player = game
-        .players
-        .iter()
-        .find(|player| player.id == game.this_player_id)
-        .unwrap();
-    let is_watcher = matches!(this_player.play_type, PlayType::Watcher);
+    let node_refs = use_state_eq(ConfigurationNodeRefs::default);
+    let error_message = use_state_eq::<Option<&'static str>, _>(|| None);
+
+    let is_host = game.is_this_player_host();
 
     let invite_code = game.invite_code.to_string();
 
@@ -28,27 +50,487 @@ pub fn lobby_component(props: &LobbyComponentProps) -> Html {
     let cloned_on_start_game = props.on_start_game.clone();
     let onclick_start_game = Callback::from(move |_| cloned_on_start_game.emit(()));
 
+    let cloned_on_balance_teams = props.on_balance_teams.clone();
+    let onclick_balance_teams = Callback::from(move |_| cloned_on_balance_teams.emit(()));
+
     html! {
         <main class={classes!("main")}>
-            <JoinGameComponent {invite_code} />
+            <JoinGameComponent {invite_code} with_qr_code={true} />
             <section class={classes!("centered-primary-content", "play-primary-content")}>
                 <PlayerNameTypeExitHeadlineComponent {on_exit_game_wished} />
                 <h1 class={classes!("welcome-headline")}>
                     <LocaleComponent keyid="lobby-view-welcome-headline"/>
                 </h1>
-                if !is_watcher {
+                if is_host {
                     <button class={classes!("button", "button--width-full")} onclick={onclick_start_game} type="button">
                         <LocaleComponent keyid="lobby-view-start-game-button"/>
                     </button>
+                } else {
+                    <p class={classes!("lobby-view__waiting-for-host")}>
+                        <LocaleComponent keyid="lobby-view-waiting-for-host"/>
+                    </p>
                 }
-                <PlayerListComponent class={classes!("play-primary-content__player-list")} />
+                <PlayerListComponent class={classes!("play-primary-content__player-list")}
+                    with_host_controls={true}
+                    on_kick_player={props.on_kick_player.clone()}
+                    on_switch_play_type={props.on_switch_play_type.clone()}
+                    on_transfer_host={props.on_transfer_host.clone()}
+                    on_assign_team={props.on_assign_team.clone()}
+                    on_vote_kick={props.on_vote_kick.clone()} />
+                <ChatComponent class={classes!("play-primary-content__chat")}
+                    on_send_chat_message={props.on_send_chat_message.clone()}
+                    on_react={props.on_react.clone()} />
+                if is_host && game.configuration.team_setup.is_some() {
+                    <button class={classes!("button", "button--width-full")} onclick={onclick_balance_teams} type="button">
+                        <LocaleComponent keyid="lobby-view-balance-teams-button"/>
+                    </button>
+                }
+                { view_configuration_form(props, &game, &langid, &node_refs, &error_message) }
             </section>
         </main>
     }
 }
 
+fn view_configuration_form(
+    props: &LobbyComponentProps,
+    game: &Rc<Game>,
+    langid: &LanguageIdentifier,
+    node_refs: &UseStateHandle<ConfigurationNodeRefs>,
+    error_message: &UseStateHandle<Option<&'static str>>,
+) -> Html {
+    let is_host = game.is_this_player_host();
+    let configuration = game.configuration;
+
+    let cloned_on_update_configuration = props.on_update_configuration.clone();
+    let cloned_node_refs = node_refs.clone();
+    let cloned_error_message = error_message.clone();
+    let onsubmit = Callback::from(move |event: SubmitEvent| {
+        event.prevent_default();
+        event.stop_propagation();
+        match parse_configuration(&cloned_node_refs, configuration) {
+            Ok(new_configuration) => {
+                cloned_error_message.set(None);
+                cloned_on_update_configuration.emit(new_configuration);
+            }
+            Err(lang_key_id) => cloned_error_message.set(Some(lang_key_id)),
+        }
+    });
+
+    let scoring_rules = configuration.scoring_rules;
+    let team_answer_mode = configuration
+        .team_setup
+        .map_or(TeamAnswerMode::MajorityVote, |team_setup| {
+            team_setup.team_answer_mode
+        });
+    let count_of_lives = match configuration.game_mode {
+        GameMode::Lives { count_of_lives } => count_of_lives,
+        GameMode::Classic | GameMode::Elimination => DEFAULT_COUNT_OF_LIVES,
+    };
+
+    html! {
+        <form class={classes!("lobby-configuration-form")} {onsubmit}>
+            <h3 class={classes!("lobby-configuration-form__headline")}>
+                <LocaleComponent keyid="lobby-view-configuration-headline"/>
+            </h3>
+
+            { view_number_input("lobby_question_count", "game-creation-form-max-questions-label",
+                &node_refs.question_count_node_ref, configuration.count_of_questions.to_string(), is_host, langid) }
+            { view_number_input("lobby_minimum_score", "game-creation-form-minimum-score-label",
+                &node_refs.minimum_score_node_ref, optional_to_string(configuration.minimum_score_per_question), is_host, langid) }
+            { view_number_input("lobby_timer", "game-creation-form-timer-wanted-label",
+                &node_refs.timer_node_ref, optional_to_string(configuration.maximum_answer_time_per_question), is_host, langid) }
+            { view_number_input("lobby_solution_time", "game-creation-form-solution-time-label",
+                &node_refs.solution_time_node_ref, configuration.solution_time_in_seconds.to_string(), is_host, langid) }
+            <label for="lobby_scoring_mode">
+                <span class={classes!("form-input-label")}>
+                    <LocaleComponent keyid="game-creation-form-scoring-mode-label"/>
+                    {":"}
+                </span>
+            </label>
+            <select class={classes!("input-field")}
+                disabled={!is_host}
+                id="lobby_scoring_mode"
+                ref={node_refs.scoring_mode_node_ref.clone()}>
+                <option value={SCORING_MODE_VALUE_CLASSIC}
+                    selected={configuration.scoring_mode == ScoringMode::Classic}>
+                    {locale("game-creation-form-scoring-mode-classic", langid)}
+                </option>
+                <option value={SCORING_MODE_VALUE_SPEED_BONUS}
+                    selected={configuration.scoring_mode == ScoringMode::SpeedBonus}>
+                    {locale("game-creation-form-scoring-mode-speed-bonus", langid)}
+                </option>
+                <option value={SCORING_MODE_VALUE_CONFIDENCE_BETTING}
+                    selected={configuration.scoring_mode == ScoringMode::ConfidenceBetting}>
+                    {locale("game-creation-form-scoring-mode-confidence-betting", langid)}
+                </option>
+            </select>
+            { view_number_input("lobby_points_for_correct_answer", "game-creation-form-points-for-correct-answer-label",
+                &node_refs.points_for_correct_answer_node_ref, scoring_rules.points_for_correct_answer.to_string(), is_host, langid) }
+            { view_number_input("lobby_points_for_minority_bonus", "game-creation-form-points-for-minority-bonus-label",
+                &node_refs.points_for_minority_bonus_node_ref, scoring_rules.points_for_minority_bonus.to_string(), is_host, langid) }
+            { view_number_input("lobby_minority_threshold", "game-creation-form-minority-threshold-label",
+                &node_refs.minority_threshold_node_ref, scoring_rules.minority_threshold_in_percent.to_string(), is_host, langid) }
+            { view_number_input("lobby_points_penalty_for_wrong_answer", "game-creation-form-points-penalty-for-wrong-answer-label",
+                &node_refs.points_penalty_for_wrong_answer_node_ref, optional_to_string(scoring_rules.points_penalty_for_wrong_answer), is_host, langid) }
+            { view_number_input("lobby_streak_length_for_bonus", "lobby-view-configuration-streak-length-for-bonus-label",
+                &node_refs.streak_length_for_bonus_node_ref, scoring_rules.streak_length_for_bonus.to_string(), is_host, langid) }
+            { view_number_input("lobby_points_for_streak_bonus", "lobby-view-configuration-points-for-streak-bonus-label",
+                &node_refs.points_for_streak_bonus_node_ref, scoring_rules.points_for_streak_bonus.to_string(), is_host, langid) }
+            <p class={classes!("form-description-paragraph", "lobby-configuration-form__full-width")}>
+                <LocaleComponent keyid="lobby-view-configuration-streak-explanation"/>
+            </p>
+
+            <label for="lobby_penalty_mode">
+                <span class={classes!("form-input-label")}>
+                    <LocaleComponent keyid="game-creation-form-penalty-mode-label"/>
+                    {":"}
+                </span>
+            </label>
+            <select class={classes!("input-field")}
+                disabled={!is_host}
+                id="lobby_penalty_mode"
+                ref={node_refs.penalty_mode_node_ref.clone()}>
+                <option value={PENALTY_MODE_VALUE_ALCOHOL}
+                    selected={configuration.penalty_mode == PenaltyMode::Alcohol}>
+                    {locale("game-creation-form-penalty-mode-alcohol", langid)}
+                </option>
+                <option value={PENALTY_MODE_VALUE_SOFT_DRINK}
+                    selected={configuration.penalty_mode == PenaltyMode::SoftDrink}>
+                    {locale("game-creation-form-penalty-mode-soft-drink", langid)}
+                </option>
+                <option value={PENALTY_MODE_VALUE_DARE}
+                    selected={configuration.penalty_mode == PenaltyMode::Dare}>
+                    {locale("game-creation-form-penalty-mode-dare", langid)}
+                </option>
+            </select>
+
+            { view_number_input("lobby_count_of_teams", "lobby-view-configuration-count-of-teams-label",
+                &node_refs.count_of_teams_node_ref, optional_to_string(configuration.team_setup.map(|team_setup| team_setup.count_of_teams)), is_host, langid) }
+            <label for="lobby_team_answer_mode">
+                <span class={classes!("form-input-label")}>
+                    <LocaleComponent keyid="lobby-view-configuration-team-answer-mode-label"/>
+                    {":"}
+                </span>
+            </label>
+            <select class={classes!("input-field")}
+                disabled={!is_host}
+                id="lobby_team_answer_mode"
+                ref={node_refs.team_answer_mode_node_ref.clone()}>
+                <option value={TEAM_ANSWER_MODE_VALUE_MAJORITY_VOTE}
+                    selected={team_answer_mode == TeamAnswerMode::MajorityVote}>
+                    {locale("lobby-view-configuration-team-answer-mode-majority-vote", langid)}
+                </option>
+                <option value={TEAM_ANSWER_MODE_VALUE_CAPTAIN}
+                    selected={team_answer_mode == TeamAnswerMode::Captain}>
+                    {locale("lobby-view-configuration-team-answer-mode-captain", langid)}
+                </option>
+            </select>
+            <p class={classes!("form-description-paragraph", "lobby-configuration-form__full-width")}>
+                <LocaleComponent keyid="lobby-view-configuration-teams-explanation"/>
+            </p>
+
+            <label for="lobby_game_mode">
+                <span class={classes!("form-input-label")}>
+                    <LocaleComponent keyid="lobby-view-configuration-game-mode-label"/>
+                    {":"}
+                </span>
+            </label>
+            <select class={classes!("input-field")}
+                disabled={!is_host}
+                id="lobby_game_mode"
+                ref={node_refs.game_mode_node_ref.clone()}>
+                <option value={GAME_MODE_VALUE_CLASSIC}
+                    selected={configuration.game_mode == GameMode::Classic}>
+                    {locale("lobby-view-configuration-game-mode-classic", langid)}
+                </option>
+                <option value={GAME_MODE_VALUE_ELIMINATION}
+                    selected={configuration.game_mode == GameMode::Elimination}>
+                    {locale("lobby-view-configuration-game-mode-elimination", langid)}
+                </option>
+                <option value={GAME_MODE_VALUE_LIVES}
+                    selected={matches!(configuration.game_mode, GameMode::Lives { .. })}>
+                    {locale("lobby-view-configuration-game-mode-lives", langid)}
+                </option>
+            </select>
+            { view_number_input("lobby_count_of_lives", "lobby-view-configuration-count-of-lives-label",
+                &node_refs.count_of_lives_node_ref, count_of_lives.to_string(), is_host, langid) }
+            <p class={classes!("form-description-paragraph", "lobby-configuration-form__full-width")}>
+                <LocaleComponent keyid="lobby-view-configuration-game-mode-explanation"/>
+            </p>
+
+            <label class={classes!("form-just-watch-label", "lobby-configuration-form__full-width")}>
+                <input type="checkbox"
+                    checked={configuration.sudden_death_tiebreaker}
+                    disabled={!is_host}
+                    ref={node_refs.sudden_death_tiebreaker_node_ref.clone()} />
+                {" "}
+                <LocaleComponent keyid="lobby-view-configuration-sudden-death-tiebreaker-label"/>
+            </label>
+
+            <label class={classes!("form-just-watch-label", "lobby-configuration-form__full-width")}>
+                <input type="checkbox"
+                    checked={configuration.marathon}
+                    disabled={!is_host}
+                    ref={node_refs.marathon_node_ref.clone()} />
+                {" "}
+                <LocaleComponent keyid="lobby-view-configuration-marathon-label"/>
+            </label>
+
+            if let Some(lang_key_id) = **error_message {
+                <p class={classes!("form-error-paragraph", "lobby-configuration-form__full-width")}>
+                    <LocaleComponent keyid={lang_key_id}/>
+                </p>
+            }
+            if is_host {
+                <input class={classes!("button", "lobby-configuration-form__full-width")}
+                    value={locale("lobby-view-configuration-submit-value", langid)}
+                    type="submit" />
+            }
+        </form>
+    }
+}
+
+fn view_number_input(
+    id: &'static str,
+    label_keyid: &'static str,
+    node_ref: &NodeRef,
+    value: String,
+    is_host: bool,
+    langid: &LanguageIdentifier,
+) -> Html {
+    html! {
+        <>
+            <label for={id}>
+                <span class={classes!("form-input-label")}>
+                    <LocaleComponent keyid={label_keyid}/>
+                    {":"}
+                </span>
+            </label>
+            <input autocomplete="off"
+                class={classes!("input-field")}
+                disabled={!is_host}
+                {id}
+                placeholder={locale(label_keyid, langid)}
+                ref={node_ref.clone()}
+                type="number"
+                {value} />
+        </>
+    }
+}
+
+fn optional_to_string<T: ToString>(optional_value: Option<T>) -> String {
+    optional_value
+        .map(|value| value.to_string())
+        .unwrap_or_default()
+}
+
+/// Parses the configuration form on top of the current configuration,
+/// so that settings without an input field are kept.
+fn parse_configuration(
+    node_refs: &ConfigurationNodeRefs,
+    configuration: GameConfiguration,
+) -> Result<GameConfiguration, &'static str> {
+    fn parse_trimmed_optional_input<T>(
+        element_node_ref: &NodeRef,
+        error_message_lang_id: &'static str,
+    ) -> Result<Option<T>, &'static str>
+    where
+        T: std::str::FromStr,
+        <T as std::str::FromStr>::Err: std::fmt::Display,
+    {
+        let element_str: String = element_node_ref.cast::<HtmlInputElement>().unwrap().value();
+        let element_str = element_str.trim();
+        if element_str.is_empty() {
+            Ok(None)
+        } else {
+            element_str.parse().map(Some).map_err(|error| {
+                log::error!("Could not parse configuration input ({error})");
+                error_message_lang_id
+            })
+        }
+    }
+
+    let count_of_questions = parse_trimmed_optional_input(
+        &node_refs.question_count_node_ref,
+        "game-creation-form-error-message-max-questions-invalid",
+    )?
+    .ok_or("game-creation-form-error-message-max-questions-invalid")?;
+    let minimum_score_per_question = parse_trimmed_optional_input(
+        &node_refs.minimum_score_node_ref,
+        "game-creation-form-error-message-minimum-score-invalid",
+    )?;
+    let maximum_answer_time_per_question = parse_trimmed_optional_input(
+        &node_refs.timer_node_ref,
+        "game-creation-form-error-message-timer-wanted-invalid",
+    )?;
+    let solution_time_in_seconds = parse_trimmed_optional_input(
+        &node_refs.solution_time_node_ref,
+        "game-creation-form-error-message-scoring-invalid",
+    )?
+    .ok_or("game-creation-form-error-message-scoring-invalid")?;
+    let points_for_correct_answer = parse_trimmed_optional_input(
+        &node_refs.points_for_correct_answer_node_ref,
+        "game-creation-form-error-message-scoring-invalid",
+    )?
+    .ok_or("game-creation-form-error-message-scoring-invalid")?;
+    let points_for_minority_bonus = parse_trimmed_optional_input(
+        &node_refs.points_for_minority_bonus_node_ref,
+        "game-creation-form-error-message-scoring-invalid",
+    )?
+    .ok_or("game-creation-form-error-message-scoring-invalid")?;
+    let minority_threshold_in_percent = parse_trimmed_optional_input(
+        &node_refs.minority_threshold_node_ref,
+        "game-creation-form-error-message-scoring-invalid",
+    )?
+    .ok_or("game-creation-form-error-message-scoring-invalid")?;
+    let points_penalty_for_wrong_answer = parse_trimmed_optional_input(
+        &node_refs.points_penalty_for_wrong_answer_node_ref,
+        "game-creation-form-error-message-scoring-invalid",
+    )?;
+    let streak_length_for_bonus = parse_trimmed_optional_input(
+        &node_refs.streak_length_for_bonus_node_ref,
+        "game-creation-form-error-message-scoring-invalid",
+    )?
+    .filter(|streak_length_for_bonus| *streak_length_for_bonus > 0)
+    .ok_or("game-creation-form-error-message-scoring-invalid")?;
+    let points_for_streak_bonus = parse_trimmed_optional_input(
+        &node_refs.points_for_streak_bonus_node_ref,
+        "game-creation-form-error-message-scoring-invalid",
+    )?
+    .ok_or("game-creation-form-error-message-scoring-invalid")?;
+    let scoring_mode = match node_refs
+        .scoring_mode_node_ref
+        .cast::<HtmlSelectElement>()
+        .unwrap()
+        .value()
+        .as_str()
+    {
+        SCORING_MODE_VALUE_SPEED_BONUS => ScoringMode::SpeedBonus,
+        SCORING_MODE_VALUE_CONFIDENCE_BETTING => ScoringMode::ConfidenceBetting,
+        _ => ScoringMode::Classic,
+    };
+    let penalty_mode = match node_refs
+        .penalty_mode_node_ref
+        .cast::<HtmlSelectElement>()
+        .unwrap()
+        .value()
+        .as_str()
+    {
+        PENALTY_MODE_VALUE_SOFT_DRINK => PenaltyMode::SoftDrink,
+        PENALTY_MODE_VALUE_DARE => PenaltyMode::Dare,
+        _ => PenaltyMode::Alcohol,
+    };
+
+    let count_of_teams: Option<u8> = parse_trimmed_optional_input(
+        &node_refs.count_of_teams_node_ref,
+        "lobby-view-configuration-error-message-teams-invalid",
+    )?;
+    let team_answer_mode = match node_refs
+        .team_answer_mode_node_ref
+        .cast::<HtmlSelectElement>()
+        .unwrap()
+        .value()
+        .as_str()
+    {
+        TEAM_ANSWER_MODE_VALUE_CAPTAIN => TeamAnswerMode::Captain,
+        _ => TeamAnswerMode::MajorityVote,
+    };
+    let team_setup = match count_of_teams {
+        Some(count_of_teams) if count_of_teams < 2 => {
+            return Err("lobby-view-configuration-error-message-teams-invalid");
+        }
+        Some(count_of_teams) => Some(TeamSetup {
+            count_of_teams,
+            team_answer_mode,
+        }),
+        None => None,
+    };
+    let game_mode = match node_refs
+        .game_mode_node_ref
+        .cast::<HtmlSelectElement>()
+        .unwrap()
+        .value()
+        .as_str()
+    {
+        GAME_MODE_VALUE_ELIMINATION => GameMode::Elimination,
+        GAME_MODE_VALUE_LIVES => {
+            let count_of_lives: u8 = parse_trimmed_optional_input(
+                &node_refs.count_of_lives_node_ref,
+                "lobby-view-configuration-error-message-lives-invalid",
+            )?
+            .filter(|count_of_lives| *count_of_lives > 0)
+            .ok_or("lobby-view-configuration-error-message-lives-invalid")?;
+            GameMode::Lives { count_of_lives }
+        }
+        _ => GameMode::Classic,
+    };
+    let sudden_death_tiebreaker = node_refs
+        .sudden_death_tiebreaker_node_ref
+        .cast::<HtmlInputElement>()
+        .unwrap()
+        .checked();
+    let marathon = node_refs
+        .marathon_node_ref
+        .cast::<HtmlInputElement>()
+        .unwrap()
+        .checked();
+
+    Ok(GameConfiguration {
+        count_of_questions,
+        minimum_score_per_question,
+        maximum_answer_time_per_question,
+        solution_time_in_seconds,
+        scoring_mode,
+        scoring_rules: ScoringRules {
+            points_for_correct_answer,
+            points_for_minority_bonus,
+            minority_threshold_in_percent,
+            points_penalty_for_wrong_answer,
+            streak_length_for_bonus,
+            points_for_streak_bonus,
+        },
+        penalty_mode,
+        team_setup,
+        sudden_death_tiebreaker,
+        game_mode,
+        marathon,
+        ..configuration
+    })
+}
+
+#[derive(Default, PartialEq)]
+struct ConfigurationNodeRefs {
+    question_count_node_ref: NodeRef,
+    minimum_score_node_ref: NodeRef,
+    timer_node_ref: NodeRef,
+    solution_time_node_ref: NodeRef,
+    scoring_mode_node_ref: NodeRef,
+    points_for_correct_answer_node_ref: NodeRef,
+    points_for_minority_bonus_node_ref: NodeRef,
+    minority_threshold_node_ref: NodeRef,
+    points_penalty_for_wrong_answer_node_ref: NodeRef,
+    streak_length_for_bonus_node_ref: NodeRef,
+    points_for_streak_bonus_node_ref: NodeRef,
+    penalty_mode_node_ref: NodeRef,
+    count_of_teams_node_ref: NodeRef,
+    team_answer_mode_node_ref: NodeRef,
+    sudden_death_tiebreaker_node_ref: NodeRef,
+    marathon_node_ref: NodeRef,
+    game_mode_node_ref: NodeRef,
+    count_of_lives_node_ref: NodeRef,
+}
+
 #[derive(yew::Properties, PartialEq)]
 pub struct LobbyComponentProps {
     pub on_exit_game_wish: Callback<()>,
     pub on_start_game: Callback<()>,
+    pub on_kick_player: Callback<PlayerId>,
+    pub on_switch_play_type: Callback<PlayerId>,
+    pub on_transfer_host: Callback<PlayerId>,
+    pub on_update_configuration: Callback<GameConfiguration>,
+    pub on_assign_team: Callback<(PlayerId, TeamId)>,
+    pub on_balance_teams: Callback<()>,
+    pub on_vote_kick: Callback<PlayerId>,
+    pub on_send_chat_message: Callback<String>,
+    pub on_react: Callback<Emoji>,
 }
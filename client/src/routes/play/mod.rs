@@ -1,31 +1,45 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
 
 use fluent_templates::LanguageIdentifier;
 
-use gloo_net::websocket::{Message, WebSocketError};
+use gloo_net::websocket::Message;
 
 use onion_or_not_the_onion_drinking_game_2_shared_library::model::game::{Game, GameState};
-use onion_or_not_the_onion_drinking_game_2_shared_library::model::network::ServerMessage;
+use onion_or_not_the_onion_drinking_game_2_shared_library::model::network::TimestampedServerMessage;
+
+use wasm_bindgen_futures::spawn_local;
 
 use yew::{
     function_component, html, use_context, use_effect_with_deps, use_force_update, use_state,
-    Callback, ContextProvider, Html,
+    Callback, ContextProvider, Html, UseForceUpdateHandle,
 };
 
-use crate::routes::index::{CreateLobby, JoinLobby};
+use crate::routes::index::{CreateLobby, DisplayLobby, JoinLobby};
 use crate::routes::play::aftermath::AftermathComponent;
 use crate::routes::play::connecting::{ConnectingComponent, ConnectingComponentState};
 use crate::routes::play::game::GameComponent;
 use crate::routes::play::lobby::LobbyComponent;
 use crate::routes::play::play_state::{ConnectingErrorLocaleKeyId, PlayState};
-use crate::utils::{retrieve_browser_location, REPLACE_PROTOCOL_WEBSOCKET};
+use crate::routes::play::server_clock::ServerClock;
+use crate::routes::play::session::{
+    load_session_from_persistent_storage_and_log_warnings, RejoinLobby,
+};
+use crate::routes::Route;
+use crate::utils::{replace_browser_route, retrieve_browser_location, REPLACE_PROTOCOL_WEBSOCKET};
 
 pub mod aftermath;
 pub mod connecting;
 pub mod game;
 pub mod lobby;
 pub mod play_state;
+pub mod server_clock;
+pub mod session;
+
+const MAXIMUM_RECONNECT_ATTEMPTS: u8 = 5;
+const RECONNECT_DELAY_IN_SECONDS: u64 = 2;
 
 #[function_component(PlayComponent)]
 pub fn play_component(props: &PlayComponentProps) -> Html {
@@ -35,63 +49,23 @@ pub fn play_component(props: &PlayComponentProps) -> Html {
 
     let play_state = Rc::clone(&*use_state(|| Rc::new(RefCell::new(PlayState::None))));
 
+    let reconnect_attempts = Rc::clone(&*use_state(|| Rc::new(Cell::new(0u8))));
+
+    let server_clock = Rc::clone(&*use_state(|| Rc::new(Cell::new(ServerClock::default()))));
+
     let cloned_create_join_lobby = props.create_join_lobby.clone();
     let cloned_play_state = Rc::clone(&play_state);
+    let cloned_reconnect_attempts = Rc::clone(&reconnect_attempts);
+    let cloned_server_clock = Rc::clone(&server_clock);
     use_effect_with_deps(
         move |_| {
-            let web_socket_address_root =
-                retrieve_browser_location(Some(REPLACE_PROTOCOL_WEBSOCKET), Some("/api"));
-            log::debug!("Retrieved web_socket_address_root as {web_socket_address_root}");
-
-            let cloned_force_update = force_update.clone();
-            let cloned_cloned_play_state = Rc::clone(&cloned_play_state);
-            let on_message_received = Callback::from(move |msg| match msg {
-                Ok(Message::Bytes(bytes)) => {
-                    let server_message = ServerMessage::try_from(&bytes[..]).unwrap();
-                    let optional_new_play_state = {
-                        RefCell::borrow(&cloned_cloned_play_state)
-                            .handle_server_message(server_message)
-                    };
-                    if let Some(new_play_state) = optional_new_play_state {
-                        *RefCell::borrow_mut(&cloned_cloned_play_state) = new_play_state;
-                        cloned_force_update.force_update();
-                    }
-                }
-                Ok(Message::Text(text)) => {
-                    log::warn!("Received text from WebSocket \"{text}\"; ignoring it...");
-                }
-                Err(error) => {
-                    log::warn!("An error occurred: {error} ({error:?})");
-                    let new_play_state = PlayState::ConnectingError {
-                        locale_keyid: match &error {
-                            WebSocketError::ConnectionError => {
-                                ConnectingErrorLocaleKeyId::MessageReceiveConnectionError
-                            }
-                            WebSocketError::ConnectionClose(_) => {
-                                ConnectingErrorLocaleKeyId::MessageReceiveConnectionClose
-                            }
-                            WebSocketError::MessageSendError(_) => {
-                                ConnectingErrorLocaleKeyId::MessageReceiveMessageSendError
-                            }
-                            _ => unimplemented!(),
-                        },
-                        error: Some(error.into()),
-                    };
-                    *RefCell::borrow_mut(&cloned_cloned_play_state) = new_play_state;
-                    cloned_force_update.force_update();
-                }
-            });
-
-            let cloned_force_update = force_update.clone();
-            let on_connection_closed = Callback::from(move |_| cloned_force_update.force_update());
-
-            *RefCell::borrow_mut(&cloned_play_state) = PlayState::connect(
-                &web_socket_address_root,
-                on_message_received,
-                on_connection_closed,
+            connect(
+                &cloned_play_state,
+                &cloned_reconnect_attempts,
+                &cloned_server_clock,
+                &force_update,
                 &cloned_create_join_lobby,
             );
-            force_update.force_update();
         },
         (),
     );
@@ -105,7 +79,13 @@ pub fn play_component(props: &PlayComponentProps) -> Html {
                 RefCell::borrow(&cloned_play_state).exit(cloned_on_go_back_to_index.clone())
             });
 
-            html! { <ConnectingComponent state={ConnectingComponentState::Connecting} {on_cancel} /> }
+            let state = if reconnect_attempts.get() > 0 {
+                ConnectingComponentState::Reconnecting
+            } else {
+                ConnectingComponentState::Connecting
+            };
+
+            html! { <ConnectingComponent {state} {on_cancel} /> }
         }
         PlayState::Playing { game, .. } => {
             let cloned_play_state = Rc::clone(&play_state);
@@ -114,6 +94,20 @@ pub fn play_component(props: &PlayComponentProps) -> Html {
                 RefCell::borrow(&cloned_play_state).exit(cloned_on_go_back_to_index.clone())
             });
 
+            let cloned_play_state = Rc::clone(&play_state);
+            let on_vote_kick = Callback::from(move |player_id| {
+                RefCell::borrow(&cloned_play_state).vote_kick(player_id)
+            });
+
+            let cloned_play_state = Rc::clone(&play_state);
+            let on_send_chat_message = Callback::from(move |text| {
+                RefCell::borrow(&cloned_play_state).send_chat_message(text)
+            });
+
+            let cloned_play_state = Rc::clone(&play_state);
+            let on_react =
+                Callback::from(move |emoji| RefCell::borrow(&cloned_play_state).react(emoji));
+
             match game.game_state {
                 GameState::InLobby => {
                     let cloned_play_state = Rc::clone(&play_state);
@@ -121,28 +115,89 @@ pub fn play_component(props: &PlayComponentProps) -> Html {
                         RefCell::borrow(&cloned_play_state).wish_for_game_start()
                     });
 
+                    let cloned_play_state = Rc::clone(&play_state);
+                    let on_kick_player = Callback::from(move |player_id| {
+                        RefCell::borrow(&cloned_play_state).kick_player(player_id)
+                    });
+
+                    let cloned_play_state = Rc::clone(&play_state);
+                    let on_switch_play_type = Callback::from(move |player_id| {
+                        RefCell::borrow(&cloned_play_state).switch_play_type(player_id)
+                    });
+
+                    let cloned_play_state = Rc::clone(&play_state);
+                    let on_transfer_host = Callback::from(move |player_id| {
+                        RefCell::borrow(&cloned_play_state).transfer_host(player_id)
+                    });
+
+                    let cloned_play_state = Rc::clone(&play_state);
+                    let on_update_configuration = Callback::from(move |configuration| {
+                        RefCell::borrow(&cloned_play_state).update_configuration(configuration)
+                    });
+
+                    let cloned_play_state = Rc::clone(&play_state);
+                    let on_assign_team = Callback::from(move |(player_id, team)| {
+                        RefCell::borrow(&cloned_play_state).assign_team(player_id, team)
+                    });
+
+                    let cloned_play_state = Rc::clone(&play_state);
+                    let on_balance_teams = Callback::from(move |_| {
+                        RefCell::borrow(&cloned_play_state).balance_teams()
+                    });
+
                     let game_rc = Rc::new(AsRef::as_ref(game).clone());
                     html! {
                         <ContextProvider<Rc<Game>> context={game_rc}>
-                            <LobbyComponent {on_exit_game_wish} {on_start_game} />
+                            <LobbyComponent {on_exit_game_wish} {on_start_game} {on_kick_player}
+                                {on_switch_play_type} {on_transfer_host} {on_update_configuration}
+                                {on_assign_team} {on_balance_teams} {on_vote_kick}
+                                {on_send_chat_message} {on_react} />
                         </ContextProvider<Rc<Game>>>
                     }
                 }
-                GameState::Playing { .. } => {
+                GameState::Playing { .. } | GameState::Tiebreaker { .. } => {
                     let game_rc = Rc::new(AsRef::as_ref(game).clone());
 
                     let cloned_play_state = Rc::clone(&play_state);
+                    let cloned_server_clock = Rc::clone(&server_clock);
                     let on_choose_answer = Callback::from(move |answer| {
-                        RefCell::borrow(&cloned_play_state).choose_answer(answer)
+                        RefCell::borrow(&cloned_play_state)
+                            .choose_answer(answer, cloned_server_clock.get().now())
+                    });
+
+                    let cloned_play_state = Rc::clone(&play_state);
+                    let on_place_stake = Callback::from(move |stake| {
+                        RefCell::borrow(&cloned_play_state).place_stake(stake)
                     });
 
                     let cloned_play_state = Rc::clone(&play_state);
                     let on_request_skip =
                         Callback::from(move |_| RefCell::borrow(&cloned_play_state).request_skip());
 
+                    let cloned_play_state = Rc::clone(&play_state);
+                    let on_end_game =
+                        Callback::from(move |_| RefCell::borrow(&cloned_play_state).end_game());
+
+                    let cloned_play_state = Rc::clone(&play_state);
+                    let on_pause_game =
+                        Callback::from(move |_| RefCell::borrow(&cloned_play_state).pause_game());
+
+                    let cloned_play_state = Rc::clone(&play_state);
+                    let on_resume_game =
+                        Callback::from(move |_| RefCell::borrow(&cloned_play_state).resume_game());
+
+                    let cloned_play_state = Rc::clone(&play_state);
+                    let on_report_question = Callback::from(move |reason| {
+                        RefCell::borrow(&cloned_play_state).report_question(reason)
+                    });
+
                     html! {
                         <ContextProvider<Rc<Game>> context={game_rc}>
-                            <GameComponent {on_exit_game_wish} {on_choose_answer} {on_request_skip} />
+                            <ContextProvider<ServerClock> context={server_clock.get()}>
+                                <GameComponent {on_exit_game_wish} {on_choose_answer} {on_place_stake} {on_request_skip}
+                                    {on_end_game} {on_pause_game} {on_resume_game} {on_vote_kick}
+                                    {on_report_question} {on_send_chat_message} {on_react} />
+                            </ContextProvider<ServerClock>>
                         </ContextProvider<Rc<Game>>>
                     }
                 }
@@ -156,7 +211,7 @@ pub fn play_component(props: &PlayComponentProps) -> Html {
 
                     html! {
                         <ContextProvider<Rc<Game>> context={game_rc}>
-                            <AftermathComponent {on_exit_game_wish} {on_play_again_wish}/>
+                            <AftermathComponent {on_exit_game_wish} {on_play_again_wish} {on_vote_kick} />
                         </ContextProvider<Rc<Game>>>
                     }
                 }
@@ -190,4 +245,138 @@ pub struct PlayComponentProps {
 pub enum CreateJoinLobby {
     Create(CreateLobby),
     Join(JoinLobby),
+    Rejoin(RejoinLobby),
+    Display(DisplayLobby),
+}
+
+fn connect(
+    play_state: &Rc<RefCell<PlayState>>,
+    reconnect_attempts: &Rc<Cell<u8>>,
+    server_clock: &Rc<Cell<ServerClock>>,
+    force_update: &UseForceUpdateHandle,
+    create_join_lobby: &CreateJoinLobby,
+) {
+    let web_socket_address_root =
+        retrieve_browser_location(Some(REPLACE_PROTOCOL_WEBSOCKET), Some("/api"));
+    log::debug!("Retrieved web_socket_address_root as {web_socket_address_root}");
+
+    // Displays reconnect as displays, only players rejoin with their stored session
+    let optional_display_lobby = match create_join_lobby {
+        CreateJoinLobby::Display(display_lobby) => Some(display_lobby.clone()),
+        CreateJoinLobby::Create(_) | CreateJoinLobby::Join(_) | CreateJoinLobby::Rejoin(_) => None,
+    };
+
+    let cloned_force_update = force_update.clone();
+    let cloned_play_state = Rc::clone(play_state);
+    let cloned_reconnect_attempts = Rc::clone(reconnect_attempts);
+    let cloned_server_clock = Rc::clone(server_clock);
+    let on_message_received = Callback::from(move |msg| match msg {
+        Ok(Message::Bytes(bytes)) => {
+            let TimestampedServerMessage {
+                sent_at,
+                server_message,
+            } = TimestampedServerMessage::try_from(&bytes[..]).unwrap();
+            cloned_server_clock.set(cloned_server_clock.get().synchronized(sent_at));
+            let optional_new_play_state =
+                { RefCell::borrow(&cloned_play_state).handle_server_message(server_message) };
+            if let Some(new_play_state) = optional_new_play_state {
+                if let PlayState::Playing { game, .. } = &new_play_state {
+                    cloned_reconnect_attempts.set(0);
+
+                    // Show the link to the lobby, as soon as it's joined
+                    let was_playing = matches!(
+                        *RefCell::borrow(&cloned_play_state),
+                        PlayState::Playing { .. }
+                    );
+                    if !was_playing {
+                        replace_browser_route(&Route::Play {
+                            invite_code: game.invite_code.to_string(),
+                        });
+                    }
+                }
+                *RefCell::borrow_mut(&cloned_play_state) = new_play_state;
+                cloned_force_update.force_update();
+            }
+        }
+        Ok(Message::Text(text)) => {
+            log::warn!("Received text from WebSocket \"{text}\"; ignoring it...");
+        }
+        Err(error) => {
+            log::warn!("An error occurred: {error} ({error:?})");
+
+            /* A dropped connection of a running game (or of a reconnect attempt) is retried
+             * with the stored session, so that the player keeps their name and points.
+             * A display just connects as display again. */
+            let is_reconnectable = match &*RefCell::borrow(&cloned_play_state) {
+                PlayState::Playing { .. } => true,
+                PlayState::Connecting { .. } => cloned_reconnect_attempts.get() > 0,
+                PlayState::ConnectingError { .. } | PlayState::None => false,
+            };
+            if is_reconnectable
+                && cloned_reconnect_attempts.get() < MAXIMUM_RECONNECT_ATTEMPTS
+                && retrieve_reconnect_lobby(&optional_display_lobby).is_some()
+            {
+                cloned_reconnect_attempts.set(cloned_reconnect_attempts.get() + 1);
+                *RefCell::borrow_mut(&cloned_play_state) = PlayState::Connecting {
+                    web_socket_stream: Arc::new(tokio::sync::Mutex::new(None)),
+                    web_socket_sink: Arc::new(tokio::sync::Mutex::new(None)),
+                };
+                cloned_force_update.force_update();
+
+                let cloned_cloned_play_state = Rc::clone(&cloned_play_state);
+                let cloned_cloned_reconnect_attempts = Rc::clone(&cloned_reconnect_attempts);
+                let cloned_cloned_server_clock = Rc::clone(&cloned_server_clock);
+                let cloned_cloned_force_update = cloned_force_update.clone();
+                let cloned_optional_display_lobby = optional_display_lobby.clone();
+                spawn_local(async move {
+                    gloo_timers::future::sleep(Duration::from_secs(RECONNECT_DELAY_IN_SECONDS))
+                        .await;
+
+                    // The session is forgotten, if the player has cancelled in the meantime.
+                    if let Some(reconnect_lobby) =
+                        retrieve_reconnect_lobby(&cloned_optional_display_lobby)
+                    {
+                        connect(
+                            &cloned_cloned_play_state,
+                            &cloned_cloned_reconnect_attempts,
+                            &cloned_cloned_server_clock,
+                            &cloned_cloned_force_update,
+                            &reconnect_lobby,
+                        );
+                    }
+                });
+                return;
+            }
+
+            let new_play_state = PlayState::ConnectingError {
+                locale_keyid: ConnectingErrorLocaleKeyId::from(&error),
+                error: Some(error.into()),
+            };
+            *RefCell::borrow_mut(&cloned_play_state) = new_play_state;
+            cloned_force_update.force_update();
+        }
+    });
+
+    let cloned_force_update = force_update.clone();
+    let on_connection_closed = Callback::from(move |_| cloned_force_update.force_update());
+
+    *RefCell::borrow_mut(play_state) = PlayState::connect(
+        &web_socket_address_root,
+        on_message_received,
+        on_connection_closed,
+        create_join_lobby,
+    );
+    force_update.force_update();
+}
+
+/// What a dropped connection reconnects as, if anything.
+fn retrieve_reconnect_lobby(
+    optional_display_lobby: &Option<DisplayLobby>,
+) -> Option<CreateJoinLobby> {
+    match optional_display_lobby {
+        Some(display_lobby) => Some(CreateJoinLobby::Display(display_lobby.clone())),
+        None => {
+            load_session_from_persistent_storage_and_log_warnings().map(CreateJoinLobby::Rejoin)
+        }
+    }
 }
@@ -3,6 +3,8 @@ use std::sync::Arc;
 use std::task::Poll;
 use std::time::Duration;
 
+use chrono::{DateTime, Utc};
+
 use futures_util::stream::{SplitSink, SplitStream};
 use futures_util::{SinkExt, StreamExt};
 
@@ -10,7 +12,8 @@ use gloo_net::websocket::futures::WebSocket;
 use gloo_net::websocket::{Message, WebSocketError};
 
 use onion_or_not_the_onion_drinking_game_2_shared_library::model::game::{
-    Answer, Game, GameState, PlayingState,
+    Answer, Emoji, Game, GameConfiguration, GameState, PenaltyMode, PlayerId, PlayingState,
+    ReportReason, ScoringMode, TeamId,
 };
 use onion_or_not_the_onion_drinking_game_2_shared_library::model::network::{
     ClientMessage, ServerMessage,
@@ -20,7 +23,11 @@ use wasm_bindgen_futures::spawn_local;
 
 use yew::Callback;
 
-use crate::routes::index::{CreateLobby, JoinLobby};
+use crate::routes::index::{CreateLobby, DisplayLobby, JoinLobby};
+use crate::routes::play::session::{
+    forget_session_in_persistent_storage, store_session_to_persistent_storage_and_log_warnings,
+    RejoinLobby,
+};
 use crate::routes::play::CreateJoinLobby;
 
 pub enum PlayState {
@@ -54,6 +61,13 @@ impl PlayState {
                 count_of_questions,
                 minimum_score_per_question,
                 maximum_answer_seconds_per_question,
+                solution_seconds,
+                scoring_mode,
+                points_for_correct_answer,
+                points_for_minority_bonus,
+                minority_threshold_in_percent,
+                points_penalty_for_wrong_answer,
+                penalty_mode,
             }) => {
                 let player_name = urlencoding::encode(player_name);
                 let count_of_questions_str = count_of_questions
@@ -65,7 +79,29 @@ impl PlayState {
                 let maximum_answer_seconds_per_question_str = maximum_answer_seconds_per_question
                     .map(|v| format!("&maximum_answer_seconds_per_question={v}"))
                     .unwrap_or_default();
-                format!("{web_socket_address_root}/create?player_name={player_name}&just_watch={just_watch}{count_of_questions_str}{minimum_score_per_question_str}{maximum_answer_seconds_per_question_str}")
+                let scoring_str = [
+                    solution_seconds.map(|v| format!("&solution_seconds={v}")),
+                    points_for_correct_answer.map(|v| format!("&points_for_correct_answer={v}")),
+                    points_for_minority_bonus.map(|v| format!("&points_for_minority_bonus={v}")),
+                    minority_threshold_in_percent
+                        .map(|v| format!("&minority_threshold_in_percent={v}")),
+                    points_penalty_for_wrong_answer
+                        .map(|v| format!("&points_penalty_for_wrong_answer={v}")),
+                ]
+                .into_iter()
+                .flatten()
+                .collect::<String>();
+                let scoring_mode = match scoring_mode {
+                    ScoringMode::Classic => "classic",
+                    ScoringMode::SpeedBonus => "speed_bonus",
+                    ScoringMode::ConfidenceBetting => "confidence_betting",
+                };
+                let penalty_mode = match penalty_mode {
+                    PenaltyMode::Alcohol => "alcohol",
+                    PenaltyMode::SoftDrink => "soft_drink",
+                    PenaltyMode::Dare => "dare",
+                };
+                format!("{web_socket_address_root}/create?player_name={player_name}&just_watch={just_watch}{count_of_questions_str}{minimum_score_per_question_str}{maximum_answer_seconds_per_question_str}{scoring_str}&scoring_mode={scoring_mode}&penalty_mode={penalty_mode}")
             }
             CreateJoinLobby::Join(JoinLobby {
                 player_name,
@@ -76,6 +112,19 @@ impl PlayState {
                 let invite_code = urlencoding::encode(invite_code);
                 format!("{web_socket_address_root}/join/{invite_code}?player_name={player_name}&just_watch={just_watch}")
             }
+            CreateJoinLobby::Rejoin(RejoinLobby {
+                invite_code,
+                session_token,
+            }) => {
+                let invite_code = urlencoding::encode(&invite_code.0);
+                format!(
+                    "{web_socket_address_root}/rejoin/{invite_code}?session_token={session_token}"
+                )
+            }
+            CreateJoinLobby::Display(DisplayLobby { invite_code }) => {
+                let invite_code = urlencoding::encode(invite_code);
+                format!("{web_socket_address_root}/display/{invite_code}")
+            }
         };
 
         log::info!("Connecting to {web_socket_address}");
@@ -174,17 +223,24 @@ impl PlayState {
     #[must_use]
     pub fn handle_server_message(&self, server_message: ServerMessage) -> Option<Self> {
         match &server_message {
-            ServerMessage::LobbyCreated(game) | ServerMessage::LobbyJoined(game) => {
+            ServerMessage::LobbyCreated(game, session_token)
+            | ServerMessage::LobbyJoined(game, session_token) => {
                 match &self {
                     Self::Connecting {
                         web_socket_stream,
                         web_socket_sink,
                         ..
-                    } => Some(Self::Playing {
-                        web_socket_stream: Arc::clone(web_socket_stream),
-                        web_socket_sink: Arc::clone(web_socket_sink),
-                        game: Box::new(game.clone()),
-                    }),
+                    } => {
+                        store_session_to_persistent_storage_and_log_warnings(
+                            &game.invite_code,
+                            *session_token,
+                        );
+                        Some(Self::Playing {
+                            web_socket_stream: Arc::clone(web_socket_stream),
+                            web_socket_sink: Arc::clone(web_socket_sink),
+                            game: Box::new(game.clone()),
+                        })
+                    }
                     Self::ConnectingError { .. } | PlayState::Playing { .. } | PlayState::None => {
                         log::warn!(
                             "Received {server_message:?} but I am in {self:?}; so doing nothing."
@@ -194,6 +250,23 @@ impl PlayState {
                     }
                 }
             }
+            ServerMessage::DisplayConnected(game) => match &self {
+                Self::Connecting {
+                    web_socket_stream,
+                    web_socket_sink,
+                } => Some(Self::Playing {
+                    web_socket_stream: Arc::clone(web_socket_stream),
+                    web_socket_sink: Arc::clone(web_socket_sink),
+                    game: Box::new(game.clone()),
+                }),
+                Self::ConnectingError { .. } | PlayState::Playing { .. } | PlayState::None => {
+                    log::warn!(
+                        "Received {server_message:?} but I am in {self:?}; so doing nothing."
+                    );
+                    // No-Op
+                    None
+                }
+            },
             ServerMessage::GameFullUpdate(game_update) => {
                 match self {
                     Self::Playing {
@@ -219,11 +292,40 @@ impl PlayState {
                     }
                 }
             }
+            ServerMessage::ChatMessage(chat_message) => match self {
+                Self::Playing {
+                    web_socket_stream,
+                    web_socket_sink,
+                    game,
+                } => {
+                    let mut game = game.clone();
+                    game.push_chat_message(chat_message.clone());
+                    Some(Self::Playing {
+                        web_socket_stream: Arc::clone(web_socket_stream),
+                        web_socket_sink: Arc::clone(web_socket_sink),
+                        game,
+                    })
+                }
+                PlayState::Connecting { .. }
+                | PlayState::ConnectingError { .. }
+                | PlayState::None => {
+                    log::warn!(
+                        "Received {server_message:?} but I am in {self:?}; so doing nothing."
+                    );
+                    // No-Op
+                    None
+                }
+            },
             ServerMessage::AnswerNotInTimeLimit => {
                 log::error!("Sent answer not in time limit.");
                 // TODO: Maybe handle error better?
                 None
             }
+            ServerMessage::InvalidConfiguration => {
+                log::error!("Sent configuration was rejected by the server.");
+                // TODO: Maybe handle error better?
+                None
+            }
             ServerMessage::PlayerNameAlreadyInUse => {
                 match self {
                     PlayState::Connecting { .. } => {
@@ -245,6 +347,43 @@ impl PlayState {
                     }
                 }
             }
+            ServerMessage::Kicked => match self {
+                PlayState::Connecting { .. } | PlayState::Playing { .. } => {
+                    self.exit(Default::default());
+                    Some(Self::ConnectingError {
+                        locale_keyid: ConnectingErrorLocaleKeyId::HandleMessageKicked,
+                        error: None,
+                    })
+                }
+                PlayState::ConnectingError { .. } | PlayState::None => {
+                    log::warn!(
+                        "Received {server_message:?} but I am in {self:?}; so doing nothing."
+                    );
+                    // No-Op
+                    None
+                }
+            },
+            ServerMessage::RejoinFailed => {
+                forget_session_in_persistent_storage();
+                match self {
+                    PlayState::Connecting { .. } => {
+                        self.exit(Default::default());
+                        Some(Self::ConnectingError {
+                            locale_keyid: ConnectingErrorLocaleKeyId::HandleMessageRejoinFailed,
+                            error: None,
+                        })
+                    }
+                    PlayState::ConnectingError { .. }
+                    | PlayState::Playing { .. }
+                    | PlayState::None => {
+                        log::warn!(
+                            "Received {server_message:?} but I am in {self:?}; so doing nothing."
+                        );
+                        // No-Op
+                        None
+                    }
+                }
+            }
         }
     }
 
@@ -259,7 +398,9 @@ impl PlayState {
                 GameState::InLobby => {
                     send_to_server(Arc::clone(web_socket_sink), ClientMessage::StartGame);
                 }
-                GameState::Playing { .. } | GameState::Aftermath { .. } => {
+                GameState::Playing { .. }
+                | GameState::Tiebreaker { .. }
+                | GameState::Aftermath { .. } => {
                     log::error!("There is a wish for game start, but I am not in Playing GameState::InLobby; doing nothing.");
                 }
             }
@@ -268,7 +409,152 @@ impl PlayState {
         }
     }
 
-    pub fn choose_answer(&self, answer: Answer) {
+    pub fn kick_player(&self, player_id: PlayerId) {
+        self.send_host_message(ClientMessage::KickPlayer(player_id));
+    }
+
+    pub fn switch_play_type(&self, player_id: PlayerId) {
+        self.send_host_message(ClientMessage::SwitchPlayType(player_id));
+    }
+
+    pub fn transfer_host(&self, player_id: PlayerId) {
+        self.send_host_message(ClientMessage::TransferHost(player_id));
+    }
+
+    pub fn update_configuration(&self, configuration: GameConfiguration) {
+        self.send_host_message(ClientMessage::UpdateConfiguration(configuration));
+    }
+
+    pub fn assign_team(&self, player_id: PlayerId, team: TeamId) {
+        self.send_host_message(ClientMessage::AssignTeam(player_id, team));
+    }
+
+    pub fn balance_teams(&self) {
+        self.send_host_message(ClientMessage::BalanceTeams);
+    }
+
+    pub fn end_game(&self) {
+        self.send_host_message(ClientMessage::EndGame);
+    }
+
+    pub fn pause_game(&self) {
+        self.send_host_message(ClientMessage::Pause);
+    }
+
+    pub fn resume_game(&self) {
+        self.send_host_message(ClientMessage::Resume);
+    }
+
+    pub fn vote_kick(&self, player_id: PlayerId) {
+        if let PlayState::Playing {
+            web_socket_sink, ..
+        } = self
+        {
+            send_to_server(
+                Arc::clone(web_socket_sink),
+                ClientMessage::VoteKick(player_id),
+            );
+        } else {
+            log::error!(
+                "Client wants to vote to kick {player_id}, but I am in {self:?}; doing nothing."
+            );
+        }
+    }
+
+    pub fn send_chat_message(&self, text: String) {
+        if let PlayState::Playing {
+            web_socket_sink, ..
+        } = self
+        {
+            send_to_server(Arc::clone(web_socket_sink), ClientMessage::Chat(text));
+        } else {
+            log::error!(
+                "Client wants to send a chat message, but I am in {self:?}; doing nothing."
+            );
+        }
+    }
+
+    pub fn react(&self, emoji: Emoji) {
+        if let PlayState::Playing {
+            web_socket_sink, ..
+        } = self
+        {
+            send_to_server(Arc::clone(web_socket_sink), ClientMessage::React(emoji));
+        } else {
+            log::error!("Client wants to react with {emoji}, but I am in {self:?}; doing nothing.");
+        }
+    }
+
+    fn send_host_message(&self, client_message: ClientMessage) {
+        match &self {
+            PlayState::Playing {
+                web_socket_sink,
+                game,
+                ..
+            } if game.is_this_player_host() => {
+                send_to_server(Arc::clone(web_socket_sink), client_message);
+            }
+            PlayState::Playing { .. }
+            | PlayState::Connecting { .. }
+            | PlayState::ConnectingError { .. }
+            | PlayState::None => {
+                log::error!(
+                    "Client wants to send {client_message:?} as host, but I am in {self:?}; doing nothing."
+                );
+            }
+        }
+    }
+
+    pub fn choose_answer(&self, answer: Answer, server_now: DateTime<Utc>) {
+        match &self {
+            PlayState::Playing {
+                web_socket_sink,
+                game,
+                ..
+            } => {
+                match game.game_state {
+                    GameState::Playing {
+                        playing_state: PlayingState::Question { time_until, .. },
+                        ..
+                    }
+                    | GameState::Tiebreaker {
+                        playing_state: PlayingState::Question { time_until, .. },
+                        ..
+                    } => {
+                        if time_until.map_or(false, |time_until| {
+                            time_until <= game.paused_at.unwrap_or(server_now)
+                        }) {
+                            log::warn!("Client wants to choose {answer:?}, but the time is up; doing nothing.");
+                            return;
+                        }
+                        send_to_server(
+                            Arc::clone(web_socket_sink),
+                            ClientMessage::ChooseAnswer(answer),
+                        );
+                    }
+                    GameState::Playing {
+                        playing_state: PlayingState::Solution { .. },
+                        ..
+                    }
+                    | GameState::Tiebreaker {
+                        playing_state: PlayingState::Solution { .. },
+                        ..
+                    }
+                    | GameState::InLobby
+                    | GameState::Aftermath { .. } => {
+                        log::error!("Client wants to choose {answer:?}, but I am not in Playing GameState::Playing PlayingState::Question; doing nothing.");
+                    }
+                }
+            }
+            PlayState::Connecting { .. } | PlayState::ConnectingError { .. } | PlayState::None => {
+                log::error!(
+                    "Client wants to choose {answer:?}, but I am in {self:?}; doing nothing."
+                );
+            }
+        }
+    }
+
+    pub fn place_stake(&self, stake: u16) {
         match &self {
             PlayState::Playing {
                 web_socket_sink,
@@ -281,7 +567,7 @@ impl PlayState {
                 } => {
                     send_to_server(
                         Arc::clone(web_socket_sink),
-                        ClientMessage::ChooseAnswer(answer),
+                        ClientMessage::PlaceStake(stake),
                     );
                 }
                 GameState::Playing {
@@ -289,13 +575,39 @@ impl PlayState {
                     ..
                 }
                 | GameState::InLobby
+                | GameState::Tiebreaker { .. }
                 | GameState::Aftermath { .. } => {
-                    log::error!("Client wants to choose {answer:?}, but I am not in Playing GameState::Playing PlayingState::Question; doing nothing.");
+                    log::error!("Client wants to place a stake of {stake}, but I am not in GameState::Playing PlayingState::Question; doing nothing.");
                 }
             },
             PlayState::Connecting { .. } | PlayState::ConnectingError { .. } | PlayState::None => {
                 log::error!(
-                    "Client wants to choose {answer:?}, but I am in {self:?}; doing nothing."
+                    "Client wants to place a stake of {stake}, but I am in {self:?}; doing nothing."
+                );
+            }
+        }
+    }
+
+    pub fn report_question(&self, reason: ReportReason) {
+        match &self {
+            PlayState::Playing {
+                web_socket_sink,
+                game,
+                ..
+            } => match game.game_state.playing_state() {
+                Some(PlayingState::Solution { .. }) => {
+                    send_to_server(
+                        Arc::clone(web_socket_sink),
+                        ClientMessage::ReportQuestion { reason },
+                    );
+                }
+                Some(PlayingState::Question { .. }) | None => {
+                    log::error!("Client wants to report the question, but I am not in PlayingState::Solution; doing nothing.");
+                }
+            },
+            PlayState::Connecting { .. } | PlayState::ConnectingError { .. } | PlayState::None => {
+                log::error!(
+                    "Client wants to report the question, but I am in {self:?}; doing nothing."
                 );
             }
         }
@@ -308,19 +620,20 @@ impl PlayState {
                 game,
                 ..
             } => match game.game_state {
-                GameState::Playing {
+                GameState::Playing { .. }
+                | GameState::Tiebreaker {
                     playing_state: PlayingState::Solution { .. },
                     ..
                 } => {
                     send_to_server(Arc::clone(web_socket_sink), ClientMessage::RequestSkip);
                 }
-                GameState::Playing {
+                GameState::Tiebreaker {
                     playing_state: PlayingState::Question { .. },
                     ..
                 }
                 | GameState::InLobby
                 | GameState::Aftermath { .. } => {
-                    log::error!("Client wants to skip, but I am not in GameState::Playing PlayingState::Solution; doing nothing.");
+                    log::error!("Client wants to skip, but I am not in GameState::Playing or a solution of the tiebreaker; doing nothing.");
                 }
             },
             PlayState::Connecting { .. } | PlayState::ConnectingError { .. } | PlayState::None => {
@@ -339,7 +652,7 @@ impl PlayState {
                 GameState::Aftermath { .. } => {
                     send_to_server(Arc::clone(web_socket_sink), ClientMessage::RequestPlayAgain);
                 }
-                GameState::InLobby | GameState::Playing { .. } => {
+                GameState::InLobby | GameState::Playing { .. } | GameState::Tiebreaker { .. } => {
                     log::error!("Client wants to play again, but I am not in GameState::Aftermath; doing nothing.");
                 }
             },
@@ -350,6 +663,13 @@ impl PlayState {
     }
 
     pub fn exit(&self, on_closed: Callback<()>) {
+        forget_session_in_persistent_storage();
+
+        self.close(on_closed);
+    }
+
+    /// Closes the connection without forgetting the session, e.g. for displays.
+    pub fn close(&self, on_closed: Callback<()>) {
         match &self {
             PlayState::Connecting {
                 web_socket_stream,
@@ -415,6 +735,25 @@ pub enum ConnectingErrorLocaleKeyId {
     MessageReceiveConnectionClose,
     MessageReceiveMessageSendError,
     HandleMessagePlayerNameAlreadyInUse,
+    HandleMessageRejoinFailed,
+    HandleMessageKicked,
+}
+
+impl From<&WebSocketError> for ConnectingErrorLocaleKeyId {
+    fn from(error: &WebSocketError) -> Self {
+        match error {
+            WebSocketError::ConnectionError => {
+                ConnectingErrorLocaleKeyId::MessageReceiveConnectionError
+            }
+            WebSocketError::ConnectionClose(_) => {
+                ConnectingErrorLocaleKeyId::MessageReceiveConnectionClose
+            }
+            WebSocketError::MessageSendError(_) => {
+                ConnectingErrorLocaleKeyId::MessageReceiveMessageSendError
+            }
+            _ => unimplemented!(),
+        }
+    }
 }
 
 impl ConnectingErrorLocaleKeyId {
@@ -433,6 +772,12 @@ impl ConnectingErrorLocaleKeyId {
             ConnectingErrorLocaleKeyId::HandleMessagePlayerNameAlreadyInUse => {
                 "error-web-socket-handle-message-player-name-already-in-use"
             }
+            ConnectingErrorLocaleKeyId::HandleMessageRejoinFailed => {
+                "error-web-socket-handle-message-rejoin-failed"
+            }
+            ConnectingErrorLocaleKeyId::HandleMessageKicked => {
+                "error-web-socket-handle-message-kicked"
+            }
         }
     }
 }
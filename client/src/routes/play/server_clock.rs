@@ -0,0 +1,31 @@
+//! The server stamps every message with the time it was sent, but the message arrives only
+//! after its one-way network latency. So every measured offset between the clocks falls short
+//! of the real offset by that latency, and the countdowns run late by the latency at most.
+//! The largest measured offset belongs to the fastest message and is the best estimate.
+
+use chrono::{DateTime, Duration, Utc};
+
+/// The estimated difference between the server's clock and this device's clock.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct ServerClock {
+    optional_offset: Option<Duration>,
+}
+
+impl ServerClock {
+    /// Takes the server's timestamp of a just received message into the estimated offset,
+    /// which only grows, when the message was faster than all previous ones.
+    pub fn synchronized(self, server_sent_at: DateTime<Utc>) -> Self {
+        let measured_offset = server_sent_at - Utc::now();
+        let offset = self
+            .optional_offset
+            .map_or(measured_offset, |offset| offset.max(measured_offset));
+        Self {
+            optional_offset: Some(offset),
+        }
+    }
+
+    /// The current time by the server's clock.
+    pub fn now(&self) -> DateTime<Utc> {
+        Utc::now() + self.optional_offset.unwrap_or_else(Duration::zero)
+    }
+}
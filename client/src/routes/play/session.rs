@@ -0,0 +1,65 @@
+use gloo_storage::errors::StorageError;
+use gloo_storage::{LocalStorage, Storage};
+
+use onion_or_not_the_onion_drinking_game_2_shared_library::model::game::{
+    InviteCode, SessionToken,
+};
+
+const LOCAL_STORAGE_KEY_SESSION: &str = "session";
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RejoinLobby {
+    pub invite_code: InviteCode,
+    pub session_token: SessionToken,
+}
+
+pub fn load_session_from_persistent_storage_and_log_warnings() -> Option<RejoinLobby> {
+    load_session_from_persistent_storage()
+        .map_err(|inspect_error| {
+            log::warn!("While loading session: {inspect_error}");
+            inspect_error
+        })
+        .ok()
+        .flatten()
+}
+
+pub fn load_session_from_persistent_storage() -> anyhow::Result<Option<RejoinLobby>> {
+    use anyhow::Context;
+
+    match LocalStorage::get::<(InviteCode, SessionToken)>(LOCAL_STORAGE_KEY_SESSION) {
+        Ok((invite_code, session_token)) => Ok(Some(RejoinLobby {
+            invite_code,
+            session_token,
+        })),
+        Err(StorageError::KeyNotFound(_)) => Ok(None),
+        Err(error) => Err(error).with_context(|| {
+            format!(
+                "Could not get session from key \"{LOCAL_STORAGE_KEY_SESSION}\" from LocalStorage."
+            )
+        }),
+    }
+}
+
+pub fn store_session_to_persistent_storage_and_log_warnings(
+    invite_code: &InviteCode,
+    session_token: SessionToken,
+) {
+    if let Err(error) = store_session_to_persistent_storage(invite_code, session_token) {
+        log::warn!("While storing session for lobby \"{invite_code}\" ({error}).");
+    }
+}
+
+pub fn store_session_to_persistent_storage(
+    invite_code: &InviteCode,
+    session_token: SessionToken,
+) -> anyhow::Result<()> {
+    use anyhow::Context;
+
+    LocalStorage::set(LOCAL_STORAGE_KEY_SESSION, (invite_code, session_token)).with_context(|| {
+        format!("Could not store session to LocalStorage \"{LOCAL_STORAGE_KEY_SESSION}\".")
+    })
+}
+
+pub fn forget_session_in_persistent_storage() {
+    LocalStorage::delete(LOCAL_STORAGE_KEY_SESSION);
+}
@@ -1,3 +1,5 @@
+use crate::routes::Route;
+
 pub const REPLACE_PROTOCOL_WEBSOCKET: ReplaceProtocol = ReplaceProtocol {
     secure: "wss:",
     unsecure: "ws:",
@@ -14,13 +16,38 @@ pub fn retrieve_browser_location(
     };
     let host = location.host().unwrap();
     let pathname: String = location.pathname().unwrap();
-    let stripped_pathname = pathname.strip_suffix('/').unwrap_or(&pathname);
+    // The route isn't part of the location, under which the game is hosted
+    let (base_pathname, _) = Route::split_path(&pathname);
     format!(
-        "{protocol}//{host}{stripped_pathname}{}",
+        "{protocol}//{host}{base_pathname}{}",
         append_path.unwrap_or("")
     )
 }
 
+pub fn retrieve_browser_route() -> Route {
+    let pathname: String = web_sys::window().unwrap().location().pathname().unwrap();
+    Route::split_path(&pathname).1
+}
+
+/// Shows the route in the browser location without reloading the page.
+pub fn replace_browser_route(route: &Route) {
+    let window = web_sys::window().unwrap();
+    let pathname: String = window.location().pathname().unwrap();
+    let (base_pathname, _) = Route::split_path(&pathname);
+    let new_pathname = format!("{base_pathname}{}", route.to_path());
+    let history_result = window.history().and_then(|history| {
+        history.replace_state_with_url(&wasm_bindgen::JsValue::NULL, "", Some(&new_pathname))
+    });
+    if let Err(error) = history_result {
+        log::warn!("Failed replacing browser location with {new_pathname} ({error:?})");
+    }
+}
+
+/// Formats milliseconds as seconds with one decimal place, e.g. `1234` as `"1.2"`.
+pub fn format_milliseconds_as_seconds(milliseconds: u64) -> String {
+    format!("{}.{}", milliseconds / 1000, (milliseconds % 1000) / 100)
+}
+
 pub struct ReplaceProtocol<'a> {
     pub secure: &'a str,
     pub unsecure: &'a str,
@@ -1,8 +1,12 @@
 use std::fmt::{Debug, Display};
+use std::path::PathBuf;
+
+use secrecy::Secret;
 
 #[derive(serde::Deserialize)]
 pub struct Configuration {
     pub application: ApplicationConfiguration,
+    pub reports: ReportsConfiguration,
 }
 
 #[derive(serde::Deserialize)]
@@ -11,6 +15,14 @@ pub struct ApplicationConfiguration {
     pub host: String,
 }
 
+#[derive(serde::Deserialize)]
+pub struct ReportsConfiguration {
+    /// The JSON lines file, which the reported questions are persisted to.
+    pub file_path: PathBuf,
+    /// The bearer token for listing the reports; without a token the listing is disabled.
+    pub token: Option<Secret<String>>,
+}
+
 /// The possible runtime environment for the application.
 pub enum Environment {
     Local,
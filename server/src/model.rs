@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
@@ -94,6 +94,16 @@ pub struct Game {
     pub configuration: GameConfiguration,
     pub game_state: GameState,
     pub players: Vec<Player>,
+    pub host: Option<PlayerId>,
+    pub paused_at: Option<DateTime<Utc>>,
+    pub kick_votes: HashMap<PlayerId, HashSet<PlayerId>>,
+    /// Names of kicked players, which can't be used to join the lobby again.
+    pub blocked_names: HashSet<PlayerName>,
+    /// Questions discarded by a skip vote, which aren't drawn again in the current round.
+    pub skipped_questions: HashSet<QuestionId>,
+    pub chat_history: VecDeque<ChatMessage>,
+    /// Time of the latest chat message or reaction of every player for rate limiting.
+    pub last_chat_message_at: HashMap<PlayerId, DateTime<Utc>>,
 }
 
 impl Game {
@@ -113,6 +123,19 @@ impl Game {
                 .game_state
                 .into_shared_model_game_state(&this_player_id, f),
             players: self.players.into_iter().map(Into::into).collect(),
+            host: self.host.map(Into::into),
+            paused_at: self.paused_at,
+            chat_history: self.chat_history.into_iter().map(Into::into).collect(),
+            kick_votes: self
+                .kick_votes
+                .into_iter()
+                .map(|(player_id, voters)| {
+                    (
+                        player_id.into(),
+                        voters.into_iter().map(Into::into).collect(),
+                    )
+                })
+                .collect(),
             this_player_id: this_player_id.into(),
         }
     }
@@ -120,11 +143,23 @@ impl Game {
 
 /* GAME CONFIGURATION */
 
+pub const MAXIMUM_ANSWER_TIME_IN_SECONDS: u64 = 60 * 60;
+pub const MAXIMUM_SOLUTION_TIME_IN_SECONDS: u64 = 60 * 60;
+
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub struct GameConfiguration {
     pub count_of_questions: u64,
     pub minimum_score_per_question: Option<i64>,
     pub maximum_answer_time_per_question: Option<u64>,
+    pub solution_time_in_seconds: u64,
+    pub scoring_mode: ScoringMode,
+    pub scoring_rules: ScoringRules,
+    pub drink_rules: DrinkRules,
+    pub penalty_mode: PenaltyMode,
+    pub team_setup: Option<TeamSetup>,
+    pub sudden_death_tiebreaker: bool,
+    pub game_mode: GameMode,
+    pub marathon: bool,
 }
 
 // Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
@@ -135,22 +170,436 @@ impl Into<shared_model::game::GameConfiguration> for GameConfiguration {
             count_of_questions: self.count_of_questions,
             minimum_score_per_question: self.minimum_score_per_question,
             maximum_answer_time_per_question: self.maximum_answer_time_per_question,
+            solution_time_in_seconds: self.solution_time_in_seconds,
+            scoring_mode: self.scoring_mode.into(),
+            scoring_rules: self.scoring_rules.into(),
+            drink_rules: self.drink_rules.into(),
+            penalty_mode: self.penalty_mode.into(),
+            team_setup: self.team_setup.map(Into::into),
+            sudden_death_tiebreaker: self.sudden_death_tiebreaker,
+            game_mode: self.game_mode.into(),
+            marathon: self.marathon,
+        }
+    }
+}
+
+impl From<shared_model::game::GameConfiguration> for GameConfiguration {
+    fn from(value: shared_model::game::GameConfiguration) -> Self {
+        Self {
+            count_of_questions: value.count_of_questions,
+            minimum_score_per_question: value.minimum_score_per_question,
+            maximum_answer_time_per_question: value.maximum_answer_time_per_question,
+            solution_time_in_seconds: value.solution_time_in_seconds,
+            scoring_mode: value.scoring_mode.into(),
+            scoring_rules: value.scoring_rules.into(),
+            drink_rules: value.drink_rules.into(),
+            penalty_mode: value.penalty_mode.into(),
+            team_setup: value.team_setup.map(Into::into),
+            sudden_death_tiebreaker: value.sudden_death_tiebreaker,
+            game_mode: value.game_mode.into(),
+            marathon: value.marathon,
+        }
+    }
+}
+
+/* GAME MODE */
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum GameMode {
+    #[default]
+    Classic,
+    Elimination,
+    Lives {
+        count_of_lives: u8,
+    },
+}
+
+pub const MAXIMUM_COUNT_OF_LIVES: u8 = 10;
+
+impl GameMode {
+    /// The lives every player starts with, if wrong or missing answers eliminate players.
+    #[must_use]
+    pub fn lives_per_player(&self) -> Option<u8> {
+        match self {
+            GameMode::Classic => None,
+            GameMode::Elimination => Some(1),
+            GameMode::Lives { count_of_lives } => Some(*count_of_lives),
+        }
+    }
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::GameMode> for GameMode {
+    fn into(self) -> shared_model::game::GameMode {
+        match self {
+            GameMode::Classic => shared_model::game::GameMode::Classic,
+            GameMode::Elimination => shared_model::game::GameMode::Elimination,
+            GameMode::Lives { count_of_lives } => {
+                shared_model::game::GameMode::Lives { count_of_lives }
+            }
+        }
+    }
+}
+
+impl From<shared_model::game::GameMode> for GameMode {
+    fn from(value: shared_model::game::GameMode) -> Self {
+        match value {
+            shared_model::game::GameMode::Classic => Self::Classic,
+            shared_model::game::GameMode::Elimination => Self::Elimination,
+            shared_model::game::GameMode::Lives { count_of_lives } => {
+                Self::Lives { count_of_lives }
+            }
+        }
+    }
+}
+
+/* TEAM SETUP */
+
+pub const MAXIMUM_COUNT_OF_TEAMS: u8 = 10;
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct TeamSetup {
+    pub count_of_teams: u8,
+    pub team_answer_mode: TeamAnswerMode,
+}
+
+impl TeamSetup {
+    pub fn teams(&self) -> impl Iterator<Item = TeamId> + Clone {
+        (0..self.count_of_teams).map(TeamId)
+    }
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::TeamSetup> for TeamSetup {
+    fn into(self) -> shared_model::game::TeamSetup {
+        shared_model::game::TeamSetup {
+            count_of_teams: self.count_of_teams,
+            team_answer_mode: self.team_answer_mode.into(),
+        }
+    }
+}
+
+impl From<shared_model::game::TeamSetup> for TeamSetup {
+    fn from(value: shared_model::game::TeamSetup) -> Self {
+        Self {
+            count_of_teams: value.count_of_teams,
+            team_answer_mode: value.team_answer_mode.into(),
+        }
+    }
+}
+
+/* TEAM ANSWER MODE */
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum TeamAnswerMode {
+    MajorityVote,
+    Captain,
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::TeamAnswerMode> for TeamAnswerMode {
+    fn into(self) -> shared_model::game::TeamAnswerMode {
+        match self {
+            TeamAnswerMode::MajorityVote => shared_model::game::TeamAnswerMode::MajorityVote,
+            TeamAnswerMode::Captain => shared_model::game::TeamAnswerMode::Captain,
+        }
+    }
+}
+
+impl From<shared_model::game::TeamAnswerMode> for TeamAnswerMode {
+    fn from(value: shared_model::game::TeamAnswerMode) -> Self {
+        match value {
+            shared_model::game::TeamAnswerMode::MajorityVote => Self::MajorityVote,
+            shared_model::game::TeamAnswerMode::Captain => Self::Captain,
+        }
+    }
+}
+
+/* TEAM ID */
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct TeamId(pub u8);
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::TeamId> for TeamId {
+    fn into(self) -> shared_model::game::TeamId {
+        shared_model::game::TeamId(self.0)
+    }
+}
+
+impl From<shared_model::game::TeamId> for TeamId {
+    fn from(value: shared_model::game::TeamId) -> Self {
+        Self(value.0)
+    }
+}
+
+/* SCORING MODE */
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum ScoringMode {
+    #[default]
+    Classic,
+    SpeedBonus,
+    ConfidenceBetting,
+}
+
+impl FromStr for ScoringMode {
+    type Err = ScoringModeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "classic" => Ok(Self::Classic),
+            "speed_bonus" => Ok(Self::SpeedBonus),
+            "confidence_betting" => Ok(Self::ConfidenceBetting),
+            other => Err(ScoringModeFromStrError::Unknown(other.to_string())),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ScoringModeFromStrError {
+    #[error(
+        "Scoring mode \"{0}\" is unknown (allowed ones are classic, speed_bonus and confidence_betting)"
+    )]
+    Unknown(String),
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::ScoringMode> for ScoringMode {
+    fn into(self) -> shared_model::game::ScoringMode {
+        match self {
+            ScoringMode::Classic => shared_model::game::ScoringMode::Classic,
+            ScoringMode::SpeedBonus => shared_model::game::ScoringMode::SpeedBonus,
+            ScoringMode::ConfidenceBetting => shared_model::game::ScoringMode::ConfidenceBetting,
+        }
+    }
+}
+
+impl From<shared_model::game::ScoringMode> for ScoringMode {
+    fn from(value: shared_model::game::ScoringMode) -> Self {
+        match value {
+            shared_model::game::ScoringMode::Classic => Self::Classic,
+            shared_model::game::ScoringMode::SpeedBonus => Self::SpeedBonus,
+            shared_model::game::ScoringMode::ConfidenceBetting => Self::ConfidenceBetting,
+        }
+    }
+}
+
+/* SCORING RULES */
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ScoringRules {
+    pub points_for_correct_answer: u16,
+    pub points_for_minority_bonus: u16,
+    pub minority_threshold_in_percent: u8,
+    pub points_penalty_for_wrong_answer: Option<u16>,
+    pub streak_length_for_bonus: u8,
+    pub points_for_streak_bonus: u16,
+}
+
+impl ScoringRules {
+    /// Whether the correct players are few enough to be rewarded as a minority.
+    #[must_use]
+    pub fn is_minority(&self, count_of_correct_players: usize, count_of_players: usize) -> bool {
+        count_of_correct_players * 100
+            < usize::from(self.minority_threshold_in_percent) * count_of_players
+    }
+
+    /// Whether a streak is long enough for the streak bonus, so that players are on fire.
+    /// Without bonus points, streaks are turned off and nobody is on fire.
+    #[must_use]
+    pub fn is_on_fire(&self, streak: u16) -> bool {
+        self.points_for_streak_bonus > 0 && streak >= u16::from(self.streak_length_for_bonus)
+    }
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        Self {
+            points_for_correct_answer: 10,
+            points_for_minority_bonus: 5,
+            minority_threshold_in_percent: 50,
+            points_penalty_for_wrong_answer: None,
+            streak_length_for_bonus: 3,
+            points_for_streak_bonus: 0,
+        }
+    }
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::ScoringRules> for ScoringRules {
+    fn into(self) -> shared_model::game::ScoringRules {
+        shared_model::game::ScoringRules {
+            points_for_correct_answer: self.points_for_correct_answer,
+            points_for_minority_bonus: self.points_for_minority_bonus,
+            minority_threshold_in_percent: self.minority_threshold_in_percent,
+            points_penalty_for_wrong_answer: self.points_penalty_for_wrong_answer,
+            streak_length_for_bonus: self.streak_length_for_bonus,
+            points_for_streak_bonus: self.points_for_streak_bonus,
+        }
+    }
+}
+
+impl From<shared_model::game::ScoringRules> for ScoringRules {
+    fn from(value: shared_model::game::ScoringRules) -> Self {
+        Self {
+            points_for_correct_answer: value.points_for_correct_answer,
+            points_for_minority_bonus: value.points_for_minority_bonus,
+            minority_threshold_in_percent: value.minority_threshold_in_percent,
+            points_penalty_for_wrong_answer: value.points_penalty_for_wrong_answer,
+            streak_length_for_bonus: value.streak_length_for_bonus,
+            points_for_streak_bonus: value.points_for_streak_bonus,
+        }
+    }
+}
+
+/* DRINK RULES */
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct DrinkRules {
+    pub sips_for_wrong_answer: u8,
+    pub sips_for_missing_answer: u8,
+    pub sips_for_everyone_wrong: u8,
+    pub sips_to_hand_out_for_minority_correct: u8,
+    pub sips_for_broken_streak: u8,
+}
+
+impl Default for DrinkRules {
+    fn default() -> Self {
+        Self {
+            sips_for_wrong_answer: 1,
+            sips_for_missing_answer: 2,
+            sips_for_everyone_wrong: 1,
+            sips_to_hand_out_for_minority_correct: 2,
+            sips_for_broken_streak: 0,
+        }
+    }
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::DrinkRules> for DrinkRules {
+    fn into(self) -> shared_model::game::DrinkRules {
+        shared_model::game::DrinkRules {
+            sips_for_wrong_answer: self.sips_for_wrong_answer,
+            sips_for_missing_answer: self.sips_for_missing_answer,
+            sips_for_everyone_wrong: self.sips_for_everyone_wrong,
+            sips_to_hand_out_for_minority_correct: self.sips_to_hand_out_for_minority_correct,
+            sips_for_broken_streak: self.sips_for_broken_streak,
+        }
+    }
+}
+
+impl From<shared_model::game::DrinkRules> for DrinkRules {
+    fn from(value: shared_model::game::DrinkRules) -> Self {
+        Self {
+            sips_for_wrong_answer: value.sips_for_wrong_answer,
+            sips_for_missing_answer: value.sips_for_missing_answer,
+            sips_for_everyone_wrong: value.sips_for_everyone_wrong,
+            sips_to_hand_out_for_minority_correct: value.sips_to_hand_out_for_minority_correct,
+            sips_for_broken_streak: value.sips_for_broken_streak,
+        }
+    }
+}
+
+/* PENALTY MODE */
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub enum PenaltyMode {
+    #[default]
+    Alcohol,
+    SoftDrink,
+    Dare,
+}
+
+impl PenaltyMode {
+    /// Count of penalty cards in the client's localization catalog for this mode.
+    #[must_use]
+    pub fn count_of_penalty_cards(&self) -> u8 {
+        match self {
+            PenaltyMode::Alcohol => 0,
+            PenaltyMode::SoftDrink => 5,
+            PenaltyMode::Dare => 8,
+        }
+    }
+}
+
+impl FromStr for PenaltyMode {
+    type Err = PenaltyModeFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "alcohol" => Ok(Self::Alcohol),
+            "soft_drink" => Ok(Self::SoftDrink),
+            "dare" => Ok(Self::Dare),
+            other => Err(PenaltyModeFromStrError::Unknown(other.to_string())),
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum PenaltyModeFromStrError {
+    #[error("Penalty mode \"{0}\" is unknown (allowed ones are alcohol, soft_drink and dare)")]
+    Unknown(String),
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::PenaltyMode> for PenaltyMode {
+    fn into(self) -> shared_model::game::PenaltyMode {
+        match self {
+            PenaltyMode::Alcohol => shared_model::game::PenaltyMode::Alcohol,
+            PenaltyMode::SoftDrink => shared_model::game::PenaltyMode::SoftDrink,
+            PenaltyMode::Dare => shared_model::game::PenaltyMode::Dare,
+        }
+    }
+}
+
+impl From<shared_model::game::PenaltyMode> for PenaltyMode {
+    fn from(value: shared_model::game::PenaltyMode) -> Self {
+        match value {
+            shared_model::game::PenaltyMode::Alcohol => Self::Alcohol,
+            shared_model::game::PenaltyMode::SoftDrink => Self::SoftDrink,
+            shared_model::game::PenaltyMode::Dare => Self::Dare,
         }
     }
 }
 
 /* GAME STATE */
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub enum GameState {
     InLobby,
     Playing {
-        previous_questions: Vec<(AnsweredQuestion, HashMap<PlayerId, Answer>)>,
+        previous_questions: Vec<PlayedQuestion>,
         current_question: AnsweredQuestion,
         playing_state: PlayingState,
+        /// Players, who have lost all their lives, in order of their elimination.
+        eliminated_players: Vec<EliminatedPlayer>,
+    },
+    /// Sudden-death questions for the players tied on the first place,
+    /// where the contenders are eliminated by their first wrong or missing answer.
+    Tiebreaker {
+        previous_questions: Vec<PlayedQuestion>,
+        current_question: AnsweredQuestion,
+        playing_state: PlayingState,
+        contenders: Vec<PlayerId>,
+        eliminated: Vec<PlayerId>,
     },
     Aftermath {
         ranked_players: Vec<(PlayerId, PlayerName, u16)>,
+        drink_leaderboard: Vec<(PlayerId, PlayerName, u16, u16)>,
+        average_response_times: Vec<(PlayerId, PlayerName, chrono::Duration)>,
+        tiebreaker_winner: Option<PlayerId>,
+        eliminated_players: Vec<(PlayerId, usize)>,
+        count_of_played_questions: usize,
         restart_requests: Vec<PlayerId>,
     },
 }
@@ -170,6 +619,7 @@ impl GameState {
                 previous_questions,
                 current_question,
                 playing_state,
+                eliminated_players,
             } => shared_model::game::GameState::Playing {
                 index_of_current_question: previous_questions.len(),
                 playing_state: playing_state.into_shared_model_playing_state(
@@ -177,9 +627,34 @@ impl GameState {
                     &current_question,
                     f,
                 ),
+                eliminated_players: eliminated_players
+                    .into_iter()
+                    .map(|eliminated_player| eliminated_player.player_id.into())
+                    .collect(),
+            },
+            GameState::Tiebreaker {
+                previous_questions,
+                current_question,
+                playing_state,
+                contenders,
+                eliminated,
+            } => shared_model::game::GameState::Tiebreaker {
+                index_of_current_question: previous_questions.len(),
+                playing_state: playing_state.into_shared_model_playing_state(
+                    own_id,
+                    &current_question,
+                    f,
+                ),
+                contenders: contenders.into_iter().map(Into::into).collect(),
+                eliminated: eliminated.into_iter().map(Into::into).collect(),
             },
             GameState::Aftermath {
                 ranked_players,
+                drink_leaderboard,
+                average_response_times,
+                tiebreaker_winner,
+                eliminated_players,
+                count_of_played_questions,
                 restart_requests: restart_request,
             } => shared_model::game::GameState::Aftermath {
                 ranked_players: ranked_players
@@ -188,24 +663,70 @@ impl GameState {
                         (player_id.into(), player_name.into(), points)
                     })
                     .collect(),
+                drink_leaderboard: drink_leaderboard
+                    .into_iter()
+                    .map(|(player_id, player_name, sips_taken, sips_handed_out)| {
+                        (
+                            player_id.into(),
+                            player_name.into(),
+                            sips_taken,
+                            sips_handed_out,
+                        )
+                    })
+                    .collect(),
+                average_response_times: average_response_times
+                    .into_iter()
+                    .map(|(player_id, player_name, average_response_time)| {
+                        (
+                            player_id.into(),
+                            player_name.into(),
+                            duration_to_milliseconds(average_response_time),
+                        )
+                    })
+                    .collect(),
+                tiebreaker_winner: tiebreaker_winner.map(Into::into),
+                eliminated_players: eliminated_players
+                    .into_iter()
+                    .map(|(player_id, index_of_question)| (player_id.into(), index_of_question))
+                    .collect(),
+                count_of_played_questions,
                 restart_requests: restart_request.into_iter().map(Into::into).collect(),
             },
         }
     }
 }
 
+/* ELIMINATED PLAYER */
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct EliminatedPlayer {
+    pub player_id: PlayerId,
+    /// The play type before the elimination, which the player gets back in the aftermath.
+    pub play_type: PlayType,
+    pub index_of_question: usize,
+}
+
 /* PLAYING STATE */
 
 #[derive(Clone, Eq, PartialEq, Debug)]
 pub enum PlayingState {
     Question {
+        shown_at: DateTime<Utc>,
         time_until: Option<DateTime<Utc>>,
         answers: HashMap<PlayerId, Answer>,
+        response_times: HashMap<PlayerId, chrono::Duration>,
+        stakes: HashMap<PlayerId, u16>,
+        skip_request: HashSet<PlayerId>,
     },
     Solution {
         time_until: DateTime<Utc>,
         answers: HashMap<PlayerId, Answer>,
+        response_times: HashMap<PlayerId, chrono::Duration>,
+        fastest_correct_answer: Option<(PlayerId, PlayerName, chrono::Duration)>,
         skip_request: HashSet<PlayerId>,
+        drinks: HashMap<PlayerId, Drinks>,
+        stakes: HashMap<PlayerId, u16>,
+        reports: HashMap<PlayerId, ReportReason>,
     },
 }
 
@@ -223,9 +744,13 @@ impl PlayingState {
             PlayingState::Question {
                 time_until,
                 answers,
+                stakes,
+                skip_request,
+                ..
             } => {
                 let reddit_submission_data = f(&answered_question.question_id).unwrap();
                 let own_answer = answers.get(own_id).copied();
+                let own_stake = stakes.get(own_id).copied();
                 shared_model::game::PlayingState::Question {
                     current_question: shared_model::game::Question {
                         title: reddit_submission_data.title.clone(),
@@ -233,12 +758,19 @@ impl PlayingState {
                     time_until,
                     answers: answers.into_keys().map(Into::into).collect(),
                     own_answer: own_answer.map(Into::into),
+                    own_stake,
+                    skip_request: skip_request.into_iter().map(Into::into).collect(),
                 }
             }
             PlayingState::Solution {
                 time_until,
                 answers,
+                fastest_correct_answer,
                 skip_request,
+                drinks,
+                stakes,
+                reports,
+                ..
             } => shared_model::game::PlayingState::Solution {
                 current_question: answered_question.into_shared_model_answered_question(&f),
                 time_until,
@@ -246,12 +778,45 @@ impl PlayingState {
                     .into_iter()
                     .map(|(id, answer)| (id.into(), answer.into()))
                     .collect(),
+                fastest_correct_answer: fastest_correct_answer.map(
+                    |(player_id, player_name, response_time)| {
+                        (
+                            player_id.into(),
+                            player_name.into(),
+                            duration_to_milliseconds(response_time),
+                        )
+                    },
+                ),
                 skip_request: skip_request.into_iter().map(Into::into).collect(),
+                drinks: drinks
+                    .into_iter()
+                    .map(|(id, drinks)| (id.into(), drinks.into()))
+                    .collect(),
+                stakes: stakes
+                    .into_iter()
+                    .map(|(id, stake)| (id.into(), stake))
+                    .collect(),
+                own_report: reports.get(own_id).copied().map(Into::into),
             },
         }
     }
 }
 
+/// Converts a duration into milliseconds for the shared model, where negative durations become zero.
+fn duration_to_milliseconds(duration: chrono::Duration) -> u64 {
+    u64::try_from(duration.num_milliseconds()).unwrap_or(0)
+}
+
+/* PLAYED QUESTION */
+
+/// A question of the current round, which has already been answered and resolved.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct PlayedQuestion {
+    pub answered_question: AnsweredQuestion,
+    pub answers: HashMap<PlayerId, Answer>,
+    pub response_times: HashMap<PlayerId, chrono::Duration>,
+}
+
 /* QUESTION ID */
 
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -326,6 +891,130 @@ impl Into<shared_model::game::Answer> for Answer {
     }
 }
 
+/* DRINKS */
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Drinks {
+    pub sips: u16,
+    pub sips_to_hand_out: u16,
+    pub reasons: Vec<DrinkReason>,
+    pub penalty_card: Option<PenaltyCard>,
+}
+
+impl Drinks {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.sips == 0 && self.sips_to_hand_out == 0
+    }
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::Drinks> for Drinks {
+    fn into(self) -> shared_model::game::Drinks {
+        shared_model::game::Drinks {
+            sips: self.sips,
+            sips_to_hand_out: self.sips_to_hand_out,
+            reasons: self.reasons.into_iter().map(Into::into).collect(),
+            penalty_card: self.penalty_card.map(Into::into),
+        }
+    }
+}
+
+/* PENALTY CARD */
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct PenaltyCard {
+    pub penalty_mode: PenaltyMode,
+    pub number: u8,
+}
+
+impl PenaltyCard {
+    #[must_use]
+    pub fn choose_random(penalty_mode: PenaltyMode) -> Option<Self> {
+        use rand::Rng;
+        match penalty_mode.count_of_penalty_cards() {
+            0 => None,
+            count_of_penalty_cards => Some(Self {
+                penalty_mode,
+                number: rand::thread_rng().gen_range(1..=count_of_penalty_cards),
+            }),
+        }
+    }
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::PenaltyCard> for PenaltyCard {
+    fn into(self) -> shared_model::game::PenaltyCard {
+        shared_model::game::PenaltyCard {
+            penalty_mode: self.penalty_mode.into(),
+            number: self.number,
+        }
+    }
+}
+
+/* DRINK REASON */
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum DrinkReason {
+    WrongAnswer,
+    MissingAnswer,
+    EveryoneWrong,
+    MinorityCorrect,
+    StreakBroken,
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::DrinkReason> for DrinkReason {
+    fn into(self) -> shared_model::game::DrinkReason {
+        match self {
+            DrinkReason::WrongAnswer => shared_model::game::DrinkReason::WrongAnswer,
+            DrinkReason::MissingAnswer => shared_model::game::DrinkReason::MissingAnswer,
+            DrinkReason::EveryoneWrong => shared_model::game::DrinkReason::EveryoneWrong,
+            DrinkReason::MinorityCorrect => shared_model::game::DrinkReason::MinorityCorrect,
+            DrinkReason::StreakBroken => shared_model::game::DrinkReason::StreakBroken,
+        }
+    }
+}
+
+/* REPORT REASON */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub enum ReportReason {
+    DeletedPost,
+    Duplicate,
+    NeedsImage,
+    Misleading,
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::ReportReason> for ReportReason {
+    fn into(self) -> shared_model::game::ReportReason {
+        match self {
+            ReportReason::DeletedPost => shared_model::game::ReportReason::DeletedPost,
+            ReportReason::Duplicate => shared_model::game::ReportReason::Duplicate,
+            ReportReason::NeedsImage => shared_model::game::ReportReason::NeedsImage,
+            ReportReason::Misleading => shared_model::game::ReportReason::Misleading,
+        }
+    }
+}
+
+impl From<shared_model::game::ReportReason> for ReportReason {
+    fn from(value: shared_model::game::ReportReason) -> Self {
+        match value {
+            shared_model::game::ReportReason::DeletedPost => ReportReason::DeletedPost,
+            shared_model::game::ReportReason::Duplicate => ReportReason::Duplicate,
+            shared_model::game::ReportReason::NeedsImage => ReportReason::NeedsImage,
+            shared_model::game::ReportReason::Misleading => ReportReason::Misleading,
+        }
+    }
+}
+
 /* PLAYER */
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -333,6 +1022,13 @@ pub struct Player {
     pub id: PlayerId,
     pub name: PlayerName,
     pub play_type: PlayType,
+    pub session_token: SessionToken,
+    /// The player's current connection; a rejoin replaces the previous one.
+    pub connection_id: ConnectionId,
+    pub disconnected_since: Option<DateTime<Utc>>,
+    pub team: Option<TeamId>,
+    pub lives: Option<u8>,
+    pub streak: u16,
 }
 
 impl Player {
@@ -353,6 +1049,10 @@ impl Into<shared_model::game::Player> for Player {
             id: self.id.into(),
             name: self.name.into(),
             play_type: self.play_type.into(),
+            team: self.team.map(Into::into),
+            lives: self.lives,
+            streak: self.streak,
+            disconnected: self.disconnected_since.is_some(),
         }
     }
 }
@@ -383,6 +1083,59 @@ impl Into<shared_model::game::PlayerId> for PlayerId {
     }
 }
 
+impl From<shared_model::game::PlayerId> for PlayerId {
+    fn from(value: shared_model::game::PlayerId) -> Self {
+        Self(value.0)
+    }
+}
+
+/* SESSION TOKEN */
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct SessionToken(Uuid);
+
+impl SessionToken {
+    #[must_use]
+    pub fn generate() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Display for SessionToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for SessionToken {
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::from_str(s.trim()).map(Self)
+    }
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::SessionToken> for SessionToken {
+    fn into(self) -> shared_model::game::SessionToken {
+        shared_model::game::SessionToken(self.0)
+    }
+}
+
+/* CONNECTION ID */
+
+/// Identifies a single WebSocket connection, because a rejoining player keeps their `PlayerId`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ConnectionId(Uuid);
+
+impl ConnectionId {
+    #[must_use]
+    pub fn generate() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
 /* PLAYER NAME */
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
@@ -428,20 +1181,150 @@ impl Into<shared_model::game::PlayerName> for PlayerName {
     }
 }
 
+/* CHAT MESSAGE */
+
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ChatMessage {
+    pub player_id: PlayerId,
+    pub player_name: PlayerName,
+    pub content: ChatContent,
+    pub sent_at: DateTime<Utc>,
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::ChatMessage> for ChatMessage {
+    fn into(self) -> shared_model::game::ChatMessage {
+        shared_model::game::ChatMessage {
+            player_id: self.player_id.into(),
+            player_name: self.player_name.into(),
+            content: self.content.into(),
+            sent_at: self.sent_at,
+        }
+    }
+}
+
+/* CHAT CONTENT */
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum ChatContent {
+    Text(ChatText),
+    Reaction(Emoji),
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::ChatContent> for ChatContent {
+    fn into(self) -> shared_model::game::ChatContent {
+        match self {
+            ChatContent::Text(chat_text) => shared_model::game::ChatContent::Text(chat_text.0),
+            ChatContent::Reaction(emoji) => shared_model::game::ChatContent::Reaction(emoji.into()),
+        }
+    }
+}
+
+/* CHAT TEXT */
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct ChatText(String);
+
+impl FromStr for ChatText {
+    type Err = ChatTextFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            Err(ChatTextFromStrError::EmptyAfterBeingTrimmed)
+        } else if trimmed.chars().count() > shared_model::game::MAXIMUM_CHAT_MESSAGE_LENGTH {
+            Err(ChatTextFromStrError::TooLong)
+        } else {
+            Ok(Self(trimmed.to_string()))
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ChatTextFromStrError {
+    #[error("Chat text is empty after being trimmed")]
+    EmptyAfterBeingTrimmed,
+    #[error("Chat text is too long")]
+    TooLong,
+}
+
+/* EMOJI */
+
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum Emoji {
+    Laughing,
+    Surprised,
+    Facepalm,
+    ThumbsUp,
+    Cheers,
+}
+
+// Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
+#[allow(clippy::from_over_into)]
+impl Into<shared_model::game::Emoji> for Emoji {
+    fn into(self) -> shared_model::game::Emoji {
+        match self {
+            Emoji::Laughing => shared_model::game::Emoji::Laughing,
+            Emoji::Surprised => shared_model::game::Emoji::Surprised,
+            Emoji::Facepalm => shared_model::game::Emoji::Facepalm,
+            Emoji::ThumbsUp => shared_model::game::Emoji::ThumbsUp,
+            Emoji::Cheers => shared_model::game::Emoji::Cheers,
+        }
+    }
+}
+
+impl From<shared_model::game::Emoji> for Emoji {
+    fn from(value: shared_model::game::Emoji) -> Self {
+        match value {
+            shared_model::game::Emoji::Laughing => Emoji::Laughing,
+            shared_model::game::Emoji::Surprised => Emoji::Surprised,
+            shared_model::game::Emoji::Facepalm => Emoji::Facepalm,
+            shared_model::game::Emoji::ThumbsUp => Emoji::ThumbsUp,
+            shared_model::game::Emoji::Cheers => Emoji::Cheers,
+        }
+    }
+}
+
 /* PLAY TYPE */
 
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum PlayType {
-    Player { points: u16 },
+    Player {
+        points: u16,
+        sips_taken: u16,
+        sips_handed_out: u16,
+    },
     Watcher,
 }
 
+impl PlayType {
+    #[must_use]
+    pub fn new_player() -> Self {
+        Self::Player {
+            points: 0,
+            sips_taken: 0,
+            sips_handed_out: 0,
+        }
+    }
+}
+
 // Allowing clippy::from_over_into, because don't want to and can't implement From<_> for shared_model.
 #[allow(clippy::from_over_into)]
 impl Into<shared_model::game::PlayType> for PlayType {
     fn into(self) -> shared_model::game::PlayType {
         match self {
-            PlayType::Player { points } => shared_model::game::PlayType::Player { points },
+            PlayType::Player {
+                points,
+                sips_taken,
+                sips_handed_out,
+            } => shared_model::game::PlayType::Player {
+                points,
+                sips_taken,
+                sips_handed_out,
+            },
             PlayType::Watcher => shared_model::game::PlayType::Watcher,
         }
     }
@@ -1,4 +1,4 @@
-use actix_ws::{Message, MessageStream, Session};
+use actix_ws::{CloseCode, CloseReason, Message, MessageStream, Session};
 
 use futures_util::StreamExt;
 
@@ -9,29 +9,71 @@ use crate::routes::game::lobbies_storage::LobbiesStorage;
 use crate::routes::game::to_lobby_message::{ClientInfo, RegisterType, ToLobbyMessage};
 
 pub async fn start_client_network_task(
-    player_name: crate::model::PlayerName,
     invite_code: crate::model::InviteCode,
-    just_watch: bool,
     lobbies: LobbiesStorage,
     mut session: Session,
     mut msg_stream: MessageStream,
     client_type: ClientType,
 ) {
-    let (unbounded_sender_to_lobby, mut broadcast_receiver_from_lobby) =
-        lobbies.retrieve(&invite_code).await.unwrap();
+    let Some((unbounded_sender_to_lobby, mut broadcast_receiver_from_lobby)) =
+        lobbies.retrieve(&invite_code).await
+    else {
+        tracing::warn!("Lobby \"{invite_code}\" does not exist");
+        if let ClientType::LobbyRejoiner { .. } = client_type {
+            reject_rejoin(session).await;
+        } else if let Err(error) = session.close(None).await {
+            tracing::info!("Connection closed while closing it ({error})");
+        }
+        return;
+    };
     let (unbounded_sender_from_lobby, mut unbounded_receiver_from_lobby) =
         tokio::sync::mpsc::unbounded_channel::<FromLobbyMessage>();
 
-    let this_player_id = crate::model::PlayerId::generate();
+    // Displays only watch the game, so they neither act in it nor leave it
+    let is_display = client_type == ClientType::Display;
+
+    let this_player_id = match &client_type {
+        ClientType::LobbyCreator { .. } | ClientType::LobbyJoiner { .. } | ClientType::Display => {
+            crate::model::PlayerId::generate()
+        }
+        ClientType::LobbyRejoiner { session_token } => {
+            let (oneshot_sender, oneshot_receiver) = tokio::sync::oneshot::channel();
+            unbounded_sender_to_lobby
+                .send(ToLobbyMessage::ResolveSessionToken {
+                    session_token: *session_token,
+                    callback: oneshot_sender,
+                })
+                .unwrap();
+
+            match oneshot_receiver.await {
+                Ok(Some(player_id)) => player_id,
+                Ok(None) | Err(_) => {
+                    reject_rejoin(session).await;
+                    return;
+                }
+            }
+        }
+    };
+
+    // Tells this connection apart from the previous ones of a rejoining player
+    let this_connection_id = crate::model::ConnectionId::generate();
 
     let mut cloned_session = session.clone();
     let cloned_invite_code = invite_code.clone();
     tokio::spawn(async move {
         while let Ok(from_lobby_message) = broadcast_receiver_from_lobby.recv().await {
-            let server_message =
-                from_lobby_message.into_server_message(cloned_invite_code.clone(), this_player_id);
-            let server_message: Vec<u8> = server_message.try_into().unwrap();
+            let is_kicked = from_lobby_message.is_kick_of(this_player_id);
+            let Some(server_message) =
+                from_lobby_message.into_server_message(cloned_invite_code.clone(), this_player_id)
+            else {
+                continue;
+            };
+            let server_message = into_timestamped_bytes(server_message);
             cloned_session.binary(server_message).await.unwrap();
+            if is_kicked {
+                close_kicked(cloned_session).await;
+                break;
+            }
         }
     });
 
@@ -39,36 +81,77 @@ pub async fn start_client_network_task(
     let cloned_invite_code = invite_code.clone();
     tokio::spawn(async move {
         while let Some(from_lobby_message) = unbounded_receiver_from_lobby.recv().await {
-            let server_message =
-                from_lobby_message.into_server_message(cloned_invite_code.clone(), this_player_id);
-            let server_message: Vec<u8> = server_message.try_into().unwrap();
+            let is_kicked = from_lobby_message.is_kick_of(this_player_id);
+            let Some(server_message) =
+                from_lobby_message.into_server_message(cloned_invite_code.clone(), this_player_id)
+            else {
+                continue;
+            };
+            let server_message = into_timestamped_bytes(server_message);
             cloned_session.binary(server_message).await.unwrap();
+            if is_kicked {
+                close_kicked(cloned_session).await;
+                break;
+            }
         }
     });
 
     tokio::task::spawn_local(async move {
-        unbounded_sender_to_lobby
-            .send(ToLobbyMessage::Register {
-                client_info: ClientInfo {
-                    callback: unbounded_sender_from_lobby.clone(),
-                    player_id: this_player_id,
-                },
+        let client_info = ClientInfo {
+            callback: unbounded_sender_from_lobby.clone(),
+            player_id: this_player_id,
+            connection_id: this_connection_id,
+        };
+        let first_message = match client_type {
+            ClientType::LobbyCreator {
+                player_name,
+                just_watch,
+            } => ToLobbyMessage::Register {
+                client_info,
                 name: player_name,
                 just_watch,
-                register_type: client_type.into(),
-            })
-            .unwrap();
+                register_type: RegisterType::Creator,
+            },
+            ClientType::LobbyJoiner {
+                player_name,
+                just_watch,
+            } => ToLobbyMessage::Register {
+                client_info,
+                name: player_name,
+                just_watch,
+                register_type: RegisterType::Joiner,
+            },
+            ClientType::LobbyRejoiner { session_token } => ToLobbyMessage::Rejoin {
+                client_info,
+                session_token,
+            },
+            ClientType::Display => ToLobbyMessage::RegisterDisplay { client_info },
+        };
+        unbounded_sender_to_lobby.send(first_message).unwrap();
 
+        // Only a normal close by the client means leaving the lobby for good,
+        // every other end of the connection keeps the player around for a possible rejoin.
+        let mut is_leaving = false;
         while let Some(Ok(msg)) = msg_stream.next().await {
             match msg {
                 Message::Binary(bytes) => {
                     match shared_model::network::ClientMessage::try_from(&*bytes) {
+                        Ok(client_message)
+                            if is_display
+                                && client_message
+                                    != shared_model::network::ClientMessage::RequestFullUpdate =>
+                        {
+                            tracing::warn!(
+                                "Not forwarding {client_message:?}, because it was sent by a display"
+                            );
+                        }
                         Ok(client_message) => {
                             unbounded_sender_to_lobby
                                 .send(ToLobbyMessage::ClientMessage {
                                     client_info: ClientInfo {
                                         callback: unbounded_sender_from_lobby.clone(),
                                         player_id: this_player_id,
+                                        connection_id: this_connection_id,
                                     },
                                     client_message,
                                 })
@@ -85,14 +168,13 @@ pub async fn start_client_network_task(
                     tracing::info!(
                         "WebSocket connection closed by client ({optional_close_reason:?})"
                     );
-                    unbounded_sender_to_lobby
-                        .send(ToLobbyMessage::Disconnect {
-                            client_info: ClientInfo {
-                                callback: unbounded_sender_from_lobby.clone(),
-                                player_id: this_player_id,
-                            },
+                    is_leaving = matches!(
+                        optional_close_reason,
+                        Some(CloseReason {
+                            code: CloseCode::Normal,
+                            ..
                         })
-                        .unwrap();
+                    );
                     break;
                 }
                 Message::Ping(bytes) => {
@@ -111,21 +193,71 @@ pub async fn start_client_network_task(
                 }
             }
         }
+
+        if is_display {
+            return;
+        }
+
+        let client_info = ClientInfo {
+            callback: unbounded_sender_from_lobby,
+            player_id: this_player_id,
+            connection_id: this_connection_id,
+        };
+        let to_lobby_message = if is_leaving {
+            ToLobbyMessage::Leave { client_info }
+        } else {
+            ToLobbyMessage::Disconnect { client_info }
+        };
+        if let Err(error) = unbounded_sender_to_lobby.send(to_lobby_message) {
+            tracing::warn!("Failed notifying lobby about closed connection ({error})");
+        }
     });
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-pub enum ClientType {
-    LobbyCreator,
-    LobbyJoiner,
+/// Stamps the message with the server's clock, so that clients can correct their countdowns.
+fn into_timestamped_bytes(server_message: shared_model::network::ServerMessage) -> Vec<u8> {
+    shared_model::network::TimestampedServerMessage {
+        sent_at: chrono::Utc::now(),
+        server_message,
+    }
+    .try_into()
+    .unwrap()
 }
 
-#[allow(clippy::from_over_into)]
-impl Into<RegisterType> for ClientType {
-    fn into(self) -> RegisterType {
-        match self {
-            ClientType::LobbyCreator => RegisterType::Creator,
-            ClientType::LobbyJoiner => RegisterType::Joiner,
-        }
+async fn reject_rejoin(mut session: Session) {
+    let server_message = into_timestamped_bytes(shared_model::network::ServerMessage::RejoinFailed);
+    if let Err(error) = session.binary(server_message).await {
+        tracing::info!("Connection closed while rejecting rejoin ({error})");
+        return;
     }
+    if let Err(error) = session.close(None).await {
+        tracing::info!("Connection closed while rejecting rejoin ({error})");
+    }
+}
+
+/// Ends the connection of a kicked player, so that the kicked player's client stops acting in the lobby.
+async fn close_kicked(session: Session) {
+    let close_reason = CloseReason {
+        code: CloseCode::Policy,
+        description: Some("Kicked".to_string()),
+    };
+    if let Err(error) = session.close(Some(close_reason)).await {
+        tracing::info!("Connection closed while closing it of a kicked player ({error})");
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum ClientType {
+    LobbyCreator {
+        player_name: crate::model::PlayerName,
+        just_watch: bool,
+    },
+    LobbyJoiner {
+        player_name: crate::model::PlayerName,
+        just_watch: bool,
+    },
+    LobbyRejoiner {
+        session_token: crate::model::SessionToken,
+    },
+    Display,
 }
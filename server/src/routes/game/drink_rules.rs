@@ -0,0 +1,360 @@
+use std::collections::{HashMap, HashSet};
+
+/// Evaluates the lobby's drink rules for the question which has just been answered.
+///
+/// Only players with `PlayType::Player` are taken into account and players without any sips
+/// to take or to hand out are left out of the returned map.
+/// Outside of `PenaltyMode::Alcohol` every player with sips to take draws a penalty card instead.
+/// Whether the correct players are a minority is decided by the lobby's scoring rules.
+#[must_use]
+pub fn evaluate_drink_rules(
+    drink_rules: &crate::model::DrinkRules,
+    scoring_rules: &crate::model::ScoringRules,
+    penalty_mode: crate::model::PenaltyMode,
+    players: &[crate::model::Player],
+    answers: &HashMap<crate::model::PlayerId, crate::model::Answer>,
+    broken_streaks: &HashSet<crate::model::PlayerId>,
+    correct_answer: crate::model::Answer,
+) -> HashMap<crate::model::PlayerId, crate::model::Drinks> {
+    let participating_players: Vec<&crate::model::Player> =
+        players.iter().filter(|player| player.is_player()).collect();
+
+    let count_of_correct_players = participating_players
+        .iter()
+        .filter(|player| answers.get(&player.id) == Some(&correct_answer))
+        .count();
+
+    let is_everyone_wrong = !participating_players.is_empty() && count_of_correct_players == 0;
+    let are_correct_players_a_minority =
+        scoring_rules.is_minority(count_of_correct_players, participating_players.len());
+
+    participating_players
+        .into_iter()
+        .map(|player| {
+            let mut drinks = crate::model::Drinks::default();
+
+            match answers.get(&player.id) {
+                Some(answer) if *answer == correct_answer => {
+                    if are_correct_players_a_minority {
+                        add_sips_to_hand_out(
+                            &mut drinks,
+                            drink_rules.sips_to_hand_out_for_minority_correct,
+                            crate::model::DrinkReason::MinorityCorrect,
+                        );
+                    }
+                }
+                Some(_) => add_sips(
+                    &mut drinks,
+                    drink_rules.sips_for_wrong_answer,
+                    crate::model::DrinkReason::WrongAnswer,
+                ),
+                None => add_sips(
+                    &mut drinks,
+                    drink_rules.sips_for_missing_answer,
+                    crate::model::DrinkReason::MissingAnswer,
+                ),
+            }
+
+            if is_everyone_wrong {
+                add_sips(
+                    &mut drinks,
+                    drink_rules.sips_for_everyone_wrong,
+                    crate::model::DrinkReason::EveryoneWrong,
+                );
+            }
+
+            if broken_streaks.contains(&player.id) {
+                add_sips(
+                    &mut drinks,
+                    drink_rules.sips_for_broken_streak,
+                    crate::model::DrinkReason::StreakBroken,
+                );
+            }
+
+            if drinks.sips > 0 {
+                drinks.penalty_card = crate::model::PenaltyCard::choose_random(penalty_mode);
+            }
+
+            (player.id, drinks)
+        })
+        .filter(|(_, drinks)| !drinks.is_empty())
+        .collect()
+}
+
+/// Lets every player start the new game without any sips taken or handed out.
+pub fn reset_sips(players: &mut [crate::model::Player]) {
+    players.iter_mut().for_each(|player| {
+        if let crate::model::PlayType::Player {
+            sips_taken,
+            sips_handed_out,
+            ..
+        } = &mut player.play_type
+        {
+            *sips_taken = 0;
+            *sips_handed_out = 0;
+        }
+    });
+}
+
+fn add_sips(drinks: &mut crate::model::Drinks, sips: u8, reason: crate::model::DrinkReason) {
+    if sips > 0 {
+        drinks.sips += u16::from(sips);
+        drinks.reasons.push(reason);
+    }
+}
+
+fn add_sips_to_hand_out(
+    drinks: &mut crate::model::Drinks,
+    sips: u8,
+    reason: crate::model::DrinkReason,
+) {
+    if sips > 0 {
+        drinks.sips_to_hand_out += u16::from(sips);
+        drinks.reasons.push(reason);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        Answer, DrinkReason, DrinkRules, Drinks, PenaltyMode, PlayType, Player, ScoringRules,
+    };
+    use crate::routes::game::test_utils::create_player;
+
+    use super::*;
+
+    /// Different sips for every rule, so that each rule can be told apart in the result.
+    fn create_drink_rules() -> DrinkRules {
+        DrinkRules {
+            sips_for_wrong_answer: 1,
+            sips_for_missing_answer: 2,
+            sips_for_everyone_wrong: 3,
+            sips_to_hand_out_for_minority_correct: 4,
+            sips_for_broken_streak: 5,
+        }
+    }
+
+    fn create_players(count_of_players: usize) -> Vec<Player> {
+        (0..count_of_players)
+            .map(|index| create_player(&format!("Player {index}"), 0))
+            .collect()
+    }
+
+    fn evaluate(
+        penalty_mode: PenaltyMode,
+        players: &[Player],
+        answers: &[(usize, Answer)],
+        broken_streaks: &[usize],
+    ) -> Vec<Option<Drinks>> {
+        let answers = answers
+            .iter()
+            .map(|(player_index, answer)| (players[*player_index].id, *answer))
+            .collect();
+        let broken_streaks = broken_streaks
+            .iter()
+            .map(|player_index| players[*player_index].id)
+            .collect();
+        let drinks = evaluate_drink_rules(
+            &create_drink_rules(),
+            &ScoringRules::default(),
+            penalty_mode,
+            players,
+            &answers,
+            &broken_streaks,
+            Answer::TheOnion,
+        );
+        players
+            .iter()
+            .map(|player| drinks.get(&player.id).cloned())
+            .collect()
+    }
+
+    fn create_drinks(
+        sips: u16,
+        sips_to_hand_out: u16,
+        reasons: Vec<DrinkReason>,
+    ) -> Option<Drinks> {
+        Some(Drinks {
+            sips,
+            sips_to_hand_out,
+            reasons,
+            penalty_card: None,
+        })
+    }
+
+    #[test]
+    fn wrong_and_missing_answers_cost_sips() {
+        let players = create_players(4);
+
+        let drinks = evaluate(
+            PenaltyMode::Alcohol,
+            &players,
+            &[
+                (0, Answer::TheOnion),
+                (1, Answer::NotTheOnion),
+                (3, Answer::TheOnion),
+            ],
+            &[],
+        );
+
+        // Half of the players being correct isn't a minority
+        assert_eq!(
+            drinks,
+            vec![
+                None,
+                create_drinks(1, 0, vec![DrinkReason::WrongAnswer]),
+                create_drinks(2, 0, vec![DrinkReason::MissingAnswer]),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn correct_minority_hands_out_sips() {
+        let players = create_players(3);
+
+        let drinks = evaluate(
+            PenaltyMode::Alcohol,
+            &players,
+            &[
+                (0, Answer::TheOnion),
+                (1, Answer::NotTheOnion),
+                (2, Answer::NotTheOnion),
+            ],
+            &[],
+        );
+
+        assert_eq!(
+            drinks,
+            vec![
+                create_drinks(0, 4, vec![DrinkReason::MinorityCorrect]),
+                create_drinks(1, 0, vec![DrinkReason::WrongAnswer]),
+                create_drinks(1, 0, vec![DrinkReason::WrongAnswer]),
+            ]
+        );
+    }
+
+    #[test]
+    fn everyone_wrong_adds_sips_for_every_player() {
+        let players = create_players(2);
+
+        let drinks = evaluate(
+            PenaltyMode::Alcohol,
+            &players,
+            &[(0, Answer::NotTheOnion)],
+            &[],
+        );
+
+        assert_eq!(
+            drinks,
+            vec![
+                create_drinks(
+                    4,
+                    0,
+                    vec![DrinkReason::WrongAnswer, DrinkReason::EveryoneWrong]
+                ),
+                create_drinks(
+                    5,
+                    0,
+                    vec![DrinkReason::MissingAnswer, DrinkReason::EveryoneWrong]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn broken_streak_adds_sips() {
+        let players = create_players(2);
+
+        let drinks = evaluate(
+            PenaltyMode::Alcohol,
+            &players,
+            &[(0, Answer::TheOnion), (1, Answer::NotTheOnion)],
+            &[1],
+        );
+
+        assert_eq!(
+            drinks,
+            vec![
+                None,
+                create_drinks(
+                    6,
+                    0,
+                    vec![DrinkReason::WrongAnswer, DrinkReason::StreakBroken]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn watchers_neither_drink_nor_count_for_the_minority() {
+        let mut players = create_players(3);
+        players[2].play_type = PlayType::Watcher;
+
+        let drinks = evaluate(
+            PenaltyMode::Alcohol,
+            &players,
+            &[(0, Answer::TheOnion), (1, Answer::TheOnion)],
+            &[],
+        );
+
+        assert_eq!(drinks, vec![None, None, None]);
+    }
+
+    #[test]
+    fn penalty_modes_draw_penalty_cards_for_the_same_sips() {
+        let players = create_players(3);
+        let answers = [
+            (0, Answer::TheOnion),
+            (1, Answer::NotTheOnion),
+            (2, Answer::NotTheOnion),
+        ];
+
+        for penalty_mode in [
+            PenaltyMode::Alcohol,
+            PenaltyMode::SoftDrink,
+            PenaltyMode::Dare,
+        ] {
+            let drinks = evaluate(penalty_mode, &players, &answers, &[]);
+
+            let minority_drinks = drinks[0].as_ref().unwrap();
+            assert_eq!(minority_drinks.sips_to_hand_out, 4);
+            assert_eq!(minority_drinks.penalty_card, None);
+            for wrong_drinks in drinks[1..].iter().map(|drinks| drinks.as_ref().unwrap()) {
+                assert_eq!(wrong_drinks.sips, 1);
+                match penalty_mode {
+                    PenaltyMode::Alcohol => assert_eq!(wrong_drinks.penalty_card, None),
+                    PenaltyMode::SoftDrink | PenaltyMode::Dare => {
+                        let penalty_card = wrong_drinks.penalty_card.unwrap();
+                        assert_eq!(penalty_card.penalty_mode, penalty_mode);
+                        assert!((1..=penalty_mode.count_of_penalty_cards())
+                            .contains(&penalty_card.number));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reset_sips_clears_taken_and_handed_out_sips_but_keeps_points() {
+        let mut players = create_players(2);
+        players[0].play_type = PlayType::Player {
+            points: 20,
+            sips_taken: 3,
+            sips_handed_out: 4,
+        };
+        players[1].play_type = PlayType::Watcher;
+
+        reset_sips(&mut players);
+
+        assert_eq!(
+            players[0].play_type,
+            PlayType::Player {
+                points: 20,
+                sips_taken: 0,
+                sips_handed_out: 0,
+            }
+        );
+        assert_eq!(players[1].play_type, PlayType::Watcher);
+    }
+}
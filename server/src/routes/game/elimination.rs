@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+/// Gives every player the lives, they start with in the game mode.
+pub fn reset_lives(players: &mut [crate::model::Player], game_mode: crate::model::GameMode) {
+    players
+        .iter_mut()
+        .for_each(|player| player.lives = game_mode.lives_per_player());
+}
+
+/// In game modes with lives every player with `PlayType::Player` without the correct answer
+/// loses a life. Players without any lives left are turned into watchers and appended
+/// to the eliminated players. If no player has answered correctly, nobody loses a life,
+/// so that a question, which everyone gets wrong, can't eliminate all remaining players
+/// at once and leave the game without a winner.
+pub fn eliminate_players(
+    game_mode: crate::model::GameMode,
+    players: &mut [crate::model::Player],
+    answers: &HashMap<crate::model::PlayerId, crate::model::Answer>,
+    correct_answer: crate::model::Answer,
+    index_of_question: usize,
+    eliminated_players: &mut Vec<crate::model::EliminatedPlayer>,
+) {
+    if game_mode.lives_per_player().is_none() {
+        return;
+    }
+
+    let has_anyone_answered_correctly = players
+        .iter()
+        .filter(|player| player.is_player())
+        .any(|player| answers.get(&player.id) == Some(&correct_answer));
+    if !has_anyone_answered_correctly {
+        return;
+    }
+
+    players
+        .iter_mut()
+        .filter(|player| player.is_player())
+        .filter(|player| answers.get(&player.id) != Some(&correct_answer))
+        .for_each(|player| {
+            let remaining_lives = player.lives.unwrap_or(0).saturating_sub(1);
+            player.lives = Some(remaining_lives);
+            if remaining_lives == 0 {
+                eliminated_players.push(crate::model::EliminatedPlayer {
+                    player_id: player.id,
+                    play_type: player.play_type,
+                    index_of_question,
+                });
+                player.play_type = crate::model::PlayType::Watcher;
+            }
+        });
+}
+
+/// Whether the game is decided early, because at most one player is left after eliminations.
+#[must_use]
+pub fn is_last_one_standing(
+    players: &[crate::model::Player],
+    eliminated_players: &[crate::model::EliminatedPlayer],
+) -> bool {
+    !eliminated_players.is_empty()
+        && players.iter().filter(|player| player.is_player()).count() <= 1
+}
+
+/// Gives the eliminated players, who are still in the game, their play type from before
+/// their elimination back and returns the order of elimination.
+pub fn restore_eliminated_players(
+    players: &mut [crate::model::Player],
+    eliminated_players: Vec<crate::model::EliminatedPlayer>,
+) -> Vec<(crate::model::PlayerId, usize)> {
+    eliminated_players
+        .into_iter()
+        .map(|eliminated_player| {
+            if let Some(player) = players
+                .iter_mut()
+                .find(|player| player.id == eliminated_player.player_id)
+            {
+                player.play_type = eliminated_player.play_type;
+            }
+            (
+                eliminated_player.player_id,
+                eliminated_player.index_of_question,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Answer, GameMode, PlayType, Player};
+    use crate::routes::game::test_utils::create_player;
+
+    use super::*;
+
+    fn create_players_with_lives(game_mode: GameMode) -> Vec<Player> {
+        let mut players = vec![
+            create_player("Alice", 0),
+            create_player("Bob", 0),
+            create_player("Carol", 0),
+        ];
+        reset_lives(&mut players, game_mode);
+        players
+    }
+
+    #[test]
+    fn wrong_and_missing_answers_eliminate_players() {
+        let mut players = create_players_with_lives(GameMode::Elimination);
+        let answers = HashMap::from([
+            (players[0].id, Answer::TheOnion),
+            (players[1].id, Answer::NotTheOnion),
+        ]);
+        let mut eliminated_players = Vec::new();
+
+        eliminate_players(
+            GameMode::Elimination,
+            &mut players,
+            &answers,
+            Answer::TheOnion,
+            4,
+            &mut eliminated_players,
+        );
+
+        assert!(players[0].is_player());
+        assert_eq!(players[1].play_type, PlayType::Watcher);
+        assert_eq!(players[2].play_type, PlayType::Watcher);
+        assert_eq!(
+            eliminated_players
+                .iter()
+                .map(|eliminated_player| (
+                    eliminated_player.player_id,
+                    eliminated_player.index_of_question
+                ))
+                .collect::<Vec<_>>(),
+            vec![(players[1].id, 4), (players[2].id, 4)]
+        );
+        assert!(is_last_one_standing(&players, &eliminated_players));
+    }
+
+    #[test]
+    fn wrong_answer_costs_one_of_the_lives() {
+        let game_mode = GameMode::Lives { count_of_lives: 2 };
+        let mut players = create_players_with_lives(game_mode);
+        let answers = HashMap::from([
+            (players[0].id, Answer::TheOnion),
+            (players[1].id, Answer::NotTheOnion),
+            (players[2].id, Answer::TheOnion),
+        ]);
+        let mut eliminated_players = Vec::new();
+
+        eliminate_players(
+            game_mode,
+            &mut players,
+            &answers,
+            Answer::TheOnion,
+            0,
+            &mut eliminated_players,
+        );
+
+        assert_eq!(
+            players
+                .iter()
+                .map(|player| player.lives)
+                .collect::<Vec<_>>(),
+            vec![Some(2), Some(1), Some(2)]
+        );
+        assert!(players.iter().all(Player::is_player));
+        assert!(eliminated_players.is_empty());
+    }
+
+    #[test]
+    fn nobody_is_eliminated_when_nobody_answers_correctly() {
+        let mut players = create_players_with_lives(GameMode::Elimination);
+        let answers = HashMap::from([
+            (players[0].id, Answer::NotTheOnion),
+            (players[1].id, Answer::NotTheOnion),
+        ]);
+        let mut eliminated_players = Vec::new();
+
+        eliminate_players(
+            GameMode::Elimination,
+            &mut players,
+            &answers,
+            Answer::TheOnion,
+            0,
+            &mut eliminated_players,
+        );
+
+        assert!(players.iter().all(Player::is_player));
+        assert!(players.iter().all(|player| player.lives == Some(1)));
+        assert!(eliminated_players.is_empty());
+    }
+}
@@ -2,31 +2,45 @@ use onion_or_not_the_onion_drinking_game_2_shared_library::model as shared_model
 
 #[derive(Clone, Debug)]
 pub enum FromLobbyMessage {
-    LobbyCreated(crate::model::Game),
-    LobbyJoined(crate::model::Game),
+    LobbyCreated(crate::model::Game, crate::model::SessionToken),
+    LobbyJoined(crate::model::Game, crate::model::SessionToken),
+    DisplayConnected(crate::model::Game),
 
     GameFullUpdate(crate::model::Game),
+    ChatMessage(crate::model::ChatMessage),
 
     AnswerNotInTimeLimit,
     PlayerNameAlreadyInUse,
+    InvalidConfiguration,
+    RejoinFailed,
+    Kicked(crate::model::PlayerId),
 }
 
 impl FromLobbyMessage {
+    pub fn is_kick_of(&self, this_player_id: crate::model::PlayerId) -> bool {
+        matches!(self, FromLobbyMessage::Kicked(player_id) if *player_id == this_player_id)
+    }
+
     pub fn into_server_message(
         self,
         invite_code: crate::model::InviteCode,
         this_player_id: crate::model::PlayerId,
-    ) -> shared_model::network::ServerMessage {
-        match self {
-            FromLobbyMessage::LobbyCreated(game) => {
-                shared_model::network::ServerMessage::LobbyCreated(game.into_shared_model_game(
-                    invite_code,
-                    this_player_id,
-                    crate::data::get,
-                ))
+    ) -> Option<shared_model::network::ServerMessage> {
+        Some(match self {
+            FromLobbyMessage::LobbyCreated(game, session_token) => {
+                shared_model::network::ServerMessage::LobbyCreated(
+                    game.into_shared_model_game(invite_code, this_player_id, crate::data::get),
+                    session_token.into(),
+                )
+            }
+            FromLobbyMessage::LobbyJoined(game, session_token) => {
+                shared_model::network::ServerMessage::LobbyJoined(
+                    game.into_shared_model_game(invite_code, this_player_id, crate::data::get),
+                    session_token.into(),
+                )
             }
-            FromLobbyMessage::LobbyJoined(game) => {
-                shared_model::network::ServerMessage::LobbyJoined(game.into_shared_model_game(
+            FromLobbyMessage::DisplayConnected(game) => {
+                shared_model::network::ServerMessage::DisplayConnected(game.into_shared_model_game(
                     invite_code,
                     this_player_id,
                     crate::data::get,
@@ -39,12 +53,26 @@ impl FromLobbyMessage {
                     crate::data::get,
                 ))
             }
+            FromLobbyMessage::ChatMessage(chat_message) => {
+                shared_model::network::ServerMessage::ChatMessage(chat_message.into())
+            }
             FromLobbyMessage::AnswerNotInTimeLimit => {
                 shared_model::network::ServerMessage::AnswerNotInTimeLimit
             }
             FromLobbyMessage::PlayerNameAlreadyInUse => {
                 shared_model::network::ServerMessage::PlayerNameAlreadyInUse
             }
-        }
+            FromLobbyMessage::InvalidConfiguration => {
+                shared_model::network::ServerMessage::InvalidConfiguration
+            }
+            FromLobbyMessage::RejoinFailed => shared_model::network::ServerMessage::RejoinFailed,
+            FromLobbyMessage::Kicked(player_id) => {
+                // Kicks are broadcast, but only concern the kicked player
+                if player_id != this_player_id {
+                    return None;
+                }
+                shared_model::network::ServerMessage::Kicked
+            }
+        })
     }
 }
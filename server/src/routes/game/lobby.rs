@@ -1,21 +1,36 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
 use std::time::Duration;
 
 use chrono::Utc;
 
 use onion_or_not_the_onion_drinking_game_2_shared_library::model as shared_model;
 
+use crate::routes::game::drink_rules::{evaluate_drink_rules, reset_sips};
+use crate::routes::game::elimination::{
+    eliminate_players, is_last_one_standing, reset_lives, restore_eliminated_players,
+};
 use crate::routes::game::from_lobby_message::FromLobbyMessage;
 use crate::routes::game::lobbies_storage::LobbiesStorage;
+use crate::routes::game::scoring::{apply_point_change, select_scoring_strategy, ScoringContext};
+use crate::routes::game::streaks::{
+    calculate_streaks, find_broken_streaks, reset_streaks, update_streaks,
+};
+use crate::routes::game::teams::{
+    assign_to_smallest_team, balance_teams, have_all_players_answered, resolve_team_answers,
+};
+use crate::routes::game::tiebreaker::{eliminate_contenders, find_tied_top_players};
 use crate::routes::game::to_lobby_message::{ClientInfo, RegisterType, ToLobbyMessage};
+use crate::routes::reports::{Report, ReportsStorage};
 
-const PLAYING_STATE_SOLUTION_TIME_IN_SECONDS: u64 = 30;
+const DISCONNECTED_PLAYER_GRACE_PERIOD_IN_SECONDS: u64 = 60;
+const MINIMUM_CHAT_MESSAGE_INTERVAL_IN_MILLISECONDS: i64 = 1000;
 
 pub async fn start_lobby_task(
-    count_of_questions: Option<u64>,
-    minimum_score_per_question: Option<i64>,
-    maximum_answer_time_per_question: Option<u64>,
+    configuration: crate::model::GameConfiguration,
     lobbies_storage: LobbiesStorage,
+    reports_storage: ReportsStorage,
 ) -> crate::model::InviteCode {
     let (invite_code, mut unbounded_receiver, broadcast_sender) = lobbies_storage.create().await;
 
@@ -25,18 +40,16 @@ pub async fn start_lobby_task(
 
     tokio::spawn(async move {
         let mut game = crate::model::Game {
-            configuration: crate::model::GameConfiguration {
-                count_of_questions: count_of_questions.unwrap_or_else(|| {
-                    u64::try_from(crate::data::calculate_count_of_questions(
-                        minimum_score_per_question,
-                    ))
-                    .unwrap()
-                }),
-                minimum_score_per_question,
-                maximum_answer_time_per_question,
-            },
+            configuration,
             game_state: crate::model::GameState::InLobby,
             players: Vec::new(),
+            host: None,
+            paused_at: None,
+            kick_votes: HashMap::new(),
+            blocked_names: HashSet::new(),
+            skipped_questions: HashSet::new(),
+            chat_history: VecDeque::new(),
+            last_chat_message_at: HashMap::new(),
         };
 
         while let Some(to_lobby_message) = unbounded_receiver.recv().await {
@@ -46,6 +59,7 @@ pub async fn start_lobby_task(
                 &mut game,
                 &broadcast_sender,
                 &lobbies_storage,
+                &reports_storage,
             )
             .await;
             if matches!(
@@ -75,6 +89,7 @@ async fn process_client_message(
     game: &mut crate::model::Game,
     broadcast_sender: &tokio::sync::broadcast::Sender<FromLobbyMessage>,
     lobbies_storage: &LobbiesStorage,
+    reports_storage: &ReportsStorage,
 ) -> ProcessClientMessageResult {
     let broadcast_game_update = |game: crate::model::Game| {
         broadcast_sender
@@ -97,7 +112,15 @@ async fn process_client_message(
             register_type,
         } => {
             // Process
-            if game.players.iter().any(|player| player.name == name) {
+            if game.blocked_names.contains(&name) {
+                // Respond
+                client_info
+                    .callback
+                    .send(FromLobbyMessage::Kicked(client_info.player_id))
+                    .unwrap();
+
+                ProcessClientMessageResult::Continue
+            } else if game.players.iter().any(|player| player.name == name) {
                 // Respond
                 client_info
                     .callback
@@ -108,20 +131,37 @@ async fn process_client_message(
             } else {
                 game.players
                     .retain(|player| player.id != client_info.player_id);
+                let session_token = crate::model::SessionToken::generate();
                 game.players.push(crate::model::Player {
                     id: client_info.player_id,
                     name,
                     play_type: if just_watch {
                         crate::model::PlayType::Watcher
                     } else {
-                        crate::model::PlayType::Player { points: 0 }
+                        crate::model::PlayType::new_player()
                     },
+                    session_token,
+                    connection_id: client_info.connection_id,
+                    disconnected_since: None,
+                    team: None,
+                    lives: game.configuration.game_mode.lives_per_player(),
+                    streak: 0,
                 });
+                assign_to_smallest_team(
+                    &mut game.players,
+                    client_info.player_id,
+                    game.configuration.team_setup,
+                );
+                hand_over_host_if_necessary(game);
 
                 // Respond
                 let create_or_join_response = match register_type {
-                    RegisterType::Creator => FromLobbyMessage::LobbyCreated(game.clone()),
-                    RegisterType::Joiner => FromLobbyMessage::LobbyJoined(game.clone()),
+                    RegisterType::Creator => {
+                        FromLobbyMessage::LobbyCreated(game.clone(), session_token)
+                    }
+                    RegisterType::Joiner => {
+                        FromLobbyMessage::LobbyJoined(game.clone(), session_token)
+                    }
                 };
                 client_info.callback.send(create_or_join_response).unwrap();
 
@@ -130,10 +170,107 @@ async fn process_client_message(
                 ProcessClientMessageResult::Continue
             }
         }
+        ToLobbyMessage::RegisterDisplay { client_info } => {
+            // Respond
+            client_info
+                .callback
+                .send(FromLobbyMessage::DisplayConnected(game.clone()))
+                .unwrap();
+
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::ResolveSessionToken {
+            session_token,
+            callback,
+        } => {
+            // Process
+            let optional_player_id = game
+                .players
+                .iter()
+                .find(|player| player.session_token == session_token)
+                .map(|player| player.id);
+
+            // Respond
+            if callback.send(optional_player_id).is_err() {
+                tracing::warn!("Rejoining client vanished before resolving its session token");
+            }
+
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::Rejoin {
+            client_info,
+            session_token,
+        } => {
+            // Process
+            let optional_player = game.players.iter_mut().find(|player| {
+                player.id == client_info.player_id && player.session_token == session_token
+            });
+            if let Some(player) = optional_player {
+                player.connection_id = client_info.connection_id;
+                player.disconnected_since = None;
+                hand_over_host_if_necessary(game);
+
+                // Respond
+                client_info
+                    .callback
+                    .send(FromLobbyMessage::LobbyJoined(game.clone(), session_token))
+                    .unwrap();
+
+                broadcast_game_update(game.clone());
+            } else {
+                // Respond
+                client_info
+                    .callback
+                    .send(FromLobbyMessage::RejoinFailed)
+                    .unwrap();
+            }
+
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::Disconnect { client_info } | ToLobbyMessage::Leave { client_info }
+            if !is_current_connection(game, &client_info) =>
+        {
+            tracing::info!("Ignoring the end of a connection, which was replaced by a rejoin");
+
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::ClientMessage { client_info, .. }
+            if !is_current_connection(game, &client_info) =>
+        {
+            tracing::info!(
+                "Ignoring a message of a connection, which was replaced by a rejoin or whose player has left"
+            );
+
+            ProcessClientMessageResult::Continue
+        }
         ToLobbyMessage::Disconnect { client_info } => {
+            // Process; the player is kept for the grace period to allow rejoining
+            if let Some(player) = game
+                .players
+                .iter_mut()
+                .find(|player| player.id == client_info.player_id)
+            {
+                player.disconnected_since = Some(Utc::now());
+            }
+            hand_over_host_if_necessary(game);
+
+            // Update; the team of a disconnected captain may have answered already
+            match process_playing_update(game) {
+                ProcessPlayingUpdateResult::Broadcast | ProcessPlayingUpdateResult::DoNothing => {
+                    // Do nothing; broadcasting anyway
+                }
+            }
+
+            // Respond
+            broadcast_game_update(game.clone());
+
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::Leave { client_info } => {
             // Process
             game.players
                 .retain(|player| player.id != client_info.player_id);
+            hand_over_host_if_necessary(game);
 
             if game.players.is_empty() {
                 lobbies_storage.remove(invite_code).await;
@@ -155,12 +292,52 @@ async fn process_client_message(
             }
         }
         ToLobbyMessage::IntervalUpdate => {
+            // Process
+            let count_of_players_before_purge = game.players.len();
+            let grace_period = chrono::Duration::seconds(
+                i64::try_from(DISCONNECTED_PLAYER_GRACE_PERIOD_IN_SECONDS).unwrap(),
+            );
+            let now = Utc::now();
+            game.players.retain(|player| {
+                player
+                    .disconnected_since
+                    .map_or(true, |disconnected_since| {
+                        now - disconnected_since < grace_period
+                    })
+            });
+
+            if count_of_players_before_purge != game.players.len() {
+                if game.players.is_empty() {
+                    lobbies_storage.remove(invite_code).await;
+
+                    return ProcessClientMessageResult::Exit;
+                }
+                hand_over_host_if_necessary(game);
+
+                // Update
+                match process_playing_update(game) {
+                    ProcessPlayingUpdateResult::Broadcast
+                    | ProcessPlayingUpdateResult::DoNothing => {
+                        // Do nothing; broadcasting anyway
+                    }
+                }
+
+                // Respond
+                broadcast_game_update(game.clone());
+            }
+
+            if game.paused_at.is_some() {
+                // Not updating the game, because it's paused
+                return ProcessClientMessageResult::Continue;
+            }
+
             match &mut game.game_state {
                 crate::model::GameState::InLobby | crate::model::GameState::Aftermath { .. } => {
                     // Do nothing
                     ProcessClientMessageResult::Continue
                 }
-                crate::model::GameState::Playing { playing_state, .. } => {
+                crate::model::GameState::Playing { playing_state, .. }
+                | crate::model::GameState::Tiebreaker { playing_state, .. } => {
                     let should_update = match playing_state {
                         crate::model::PlayingState::Question { time_until, .. } => {
                             let count_of_player_type_player = game
@@ -198,6 +375,14 @@ async fn process_client_message(
 
             ProcessClientMessageResult::Continue
         }
+        ToLobbyMessage::ClientMessage {
+            client_info,
+            client_message: shared_model::network::ClientMessage::StartGame,
+        } if game.host != Some(client_info.player_id) => {
+            tracing::warn!("Not starting game, because it was requested by a non-host");
+
+            ProcessClientMessageResult::Continue
+        }
         ToLobbyMessage::ClientMessage {
             client_message: shared_model::network::ClientMessage::StartGame,
             ..
@@ -213,6 +398,7 @@ async fn process_client_message(
                     ProcessClientMessageResult::Continue
                 }
                 crate::model::GameState::Playing { .. }
+                | crate::model::GameState::Tiebreaker { .. }
                 | crate::model::GameState::Aftermath { .. } => {
                     // Not starting game, because it's already running
 
@@ -222,53 +408,76 @@ async fn process_client_message(
         }
         ToLobbyMessage::ClientMessage {
             client_info,
-            client_message: shared_model::network::ClientMessage::ChooseAnswer(answer),
+            client_message: shared_model::network::ClientMessage::EndGame,
+        } if game.host != Some(client_info.player_id) => {
+            tracing::warn!("Not ending game, because it was requested by a non-host");
+
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::ClientMessage {
+            client_message: shared_model::network::ClientMessage::EndGame,
+            ..
         } => {
             match &mut game.game_state {
                 crate::model::GameState::Playing {
-                    playing_state:
-                        crate::model::PlayingState::Question {
-                            time_until,
-                            answers,
-                        },
-                    ..
+                    previous_questions,
+                    current_question,
+                    playing_state,
+                    eliminated_players,
                 } => {
                     // Process
-                    let is_within_time_limit = time_until
-                        .as_ref()
-                        .map_or(true, |time_until| *time_until >= Utc::now());
-                    if is_within_time_limit {
-                        answers.insert(client_info.player_id, answer.into());
-
-                        // Update
-                        match process_playing_update(game) {
-                            ProcessPlayingUpdateResult::Broadcast
-                            | ProcessPlayingUpdateResult::DoNothing => {
-                                // Do nothing; broadcasting anyway
-                            }
-                        }
+                    store_resolved_question(previous_questions, *current_question, playing_state);
+                    let elimination_order = restore_eliminated_players(
+                        &mut game.players,
+                        std::mem::take(eliminated_players),
+                    );
+                    let game_state_aftermath = create_game_state_aftermath(
+                        &game.players,
+                        previous_questions,
+                        None,
+                        elimination_order,
+                    );
 
-                        // Respond
-                        broadcast_game_update(game.clone());
+                    // Update
+                    game.game_state = game_state_aftermath;
+                    game.paused_at = None;
 
-                        ProcessClientMessageResult::Continue
-                    } else {
-                        // Respond
-                        client_info
-                            .callback
-                            .send(FromLobbyMessage::AnswerNotInTimeLimit)
-                            .unwrap();
+                    // Respond
+                    broadcast_game_update(game.clone());
 
-                        ProcessClientMessageResult::Continue
-                    }
+                    ProcessClientMessageResult::Continue
                 }
-                crate::model::GameState::InLobby
-                | crate::model::GameState::Playing {
-                    playing_state: crate::model::PlayingState::Solution { .. },
+                crate::model::GameState::Tiebreaker {
+                    previous_questions,
+                    current_question,
+                    playing_state,
+                    contenders,
                     ..
+                } => {
+                    // Process
+                    store_resolved_question(previous_questions, *current_question, playing_state);
+                    let tiebreaker_winner = match contenders.as_slice() {
+                        [winner] => Some(*winner),
+                        _ => None,
+                    };
+                    let game_state_aftermath = create_game_state_aftermath(
+                        &game.players,
+                        previous_questions,
+                        tiebreaker_winner,
+                        Vec::new(),
+                    );
+
+                    // Update
+                    game.game_state = game_state_aftermath;
+                    game.paused_at = None;
+
+                    // Respond
+                    broadcast_game_update(game.clone());
+
+                    ProcessClientMessageResult::Continue
                 }
-                | crate::model::GameState::Aftermath { .. } => {
-                    // Not processing skip request, because not in GameState::Playing PlayingState::Question
+                crate::model::GameState::InLobby | crate::model::GameState::Aftermath { .. } => {
+                    // Not ending game, because it isn't running
 
                     ProcessClientMessageResult::Continue
                 }
@@ -276,173 +485,979 @@ async fn process_client_message(
         }
         ToLobbyMessage::ClientMessage {
             client_info,
-            client_message: shared_model::network::ClientMessage::RequestSkip,
-        } => {
-            match &mut game.game_state {
-                crate::model::GameState::Playing {
-                    playing_state: crate::model::PlayingState::Solution { skip_request, .. },
-                    ..
-                } => {
-                    // Process
-                    if !skip_request.contains(&client_info.player_id) {
-                        skip_request.insert(client_info.player_id);
-                    }
+            client_message:
+                shared_model::network::ClientMessage::Pause
+                | shared_model::network::ClientMessage::Resume,
+        } if game.host != Some(client_info.player_id) => {
+            tracing::warn!("Not pausing or resuming game, because it was requested by a non-host");
 
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::ClientMessage {
+            client_message: shared_model::network::ClientMessage::Pause,
+            ..
+        } => {
+            match game.game_state {
+                crate::model::GameState::Playing { .. }
+                | crate::model::GameState::Tiebreaker { .. }
+                    if game.paused_at.is_none() =>
+                {
                     // Update
-                    match process_playing_update(game) {
-                        ProcessPlayingUpdateResult::Broadcast
-                        | ProcessPlayingUpdateResult::DoNothing => {
-                            // Do nothing; broadcasting anyway
-                        }
-                    }
+                    game.paused_at = Some(Utc::now());
 
                     // Respond
                     broadcast_game_update(game.clone());
-
-                    ProcessClientMessageResult::Continue
                 }
                 crate::model::GameState::InLobby
-                | crate::model::GameState::Playing {
-                    playing_state: crate::model::PlayingState::Question { .. },
-                    ..
-                }
+                | crate::model::GameState::Playing { .. }
+                | crate::model::GameState::Tiebreaker { .. }
                 | crate::model::GameState::Aftermath { .. } => {
-                    // Not processing skip request, because not in GameState::Playing PlayingState::Solution
-
-                    ProcessClientMessageResult::Continue
+                    // Not pausing game, because it isn't running or already paused
                 }
             }
+
+            ProcessClientMessageResult::Continue
         }
         ToLobbyMessage::ClientMessage {
-            client_info,
-            client_message: shared_model::network::ClientMessage::RequestPlayAgain,
+            client_message: shared_model::network::ClientMessage::Resume,
+            ..
         } => {
-            match &mut game.game_state {
-                crate::model::GameState::Aftermath {
-                    restart_requests, ..
-                } => {
-                    restart_requests.push(client_info.player_id);
+            match (&mut game.game_state, game.paused_at) {
+                (
+                    crate::model::GameState::Playing { playing_state, .. }
+                    | crate::model::GameState::Tiebreaker { playing_state, .. },
+                    Some(paused_at),
+                ) => {
+                    // Process
+                    postpone_playing_state(playing_state, Utc::now() - paused_at);
 
                     // Update
-                    match process_playing_update(game) {
-                        ProcessPlayingUpdateResult::Broadcast
-                        | ProcessPlayingUpdateResult::DoNothing => {
-                            // Do nothing; broadcasting anyway
-                        }
-                    }
+                    game.paused_at = None;
 
                     // Respond
                     broadcast_game_update(game.clone());
-
-                    ProcessClientMessageResult::Continue
                 }
-                crate::model::GameState::InLobby | crate::model::GameState::Playing { .. } => {
-                    // Not processing play again request, because not in GameState::Aftermath
-
-                    ProcessClientMessageResult::Continue
+                _ => {
+                    // Not resuming game, because it isn't paused
                 }
             }
+
+            ProcessClientMessageResult::Continue
         }
-    }
-}
+        ToLobbyMessage::ClientMessage {
+            client_message:
+                shared_model::network::ClientMessage::ChooseAnswer(_)
+                | shared_model::network::ClientMessage::PlaceStake(_)
+                | shared_model::network::ClientMessage::RequestSkip,
+            ..
+        } if game.paused_at.is_some() => {
+            tracing::warn!("Ignoring answer, stake or skip request, because the game is paused");
 
-#[must_use]
-fn process_playing_update(game: &mut crate::model::Game) -> ProcessPlayingUpdateResult {
-    match &mut game.game_state {
-        crate::model::GameState::InLobby => ProcessPlayingUpdateResult::DoNothing,
-        crate::model::GameState::Aftermath {
-            restart_requests, ..
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::ClientMessage {
+            client_info,
+            client_message:
+                shared_model::network::ClientMessage::KickPlayer(_)
+                | shared_model::network::ClientMessage::SwitchPlayType(_)
+                | shared_model::network::ClientMessage::TransferHost(_)
+                | shared_model::network::ClientMessage::UpdateConfiguration(_)
+                | shared_model::network::ClientMessage::AssignTeam(..)
+                | shared_model::network::ClientMessage::BalanceTeams,
+        } if game.host != Some(client_info.player_id) => {
+            tracing::warn!("Ignoring host-only message, because it was sent by a non-host");
+
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::ClientMessage {
+            client_info,
+            client_message: shared_model::network::ClientMessage::KickPlayer(player_id),
         } => {
-            let count_of_players_wanting_restart = game
-                .players
-                .iter()
-                .filter(|player| player.is_player())
-                .filter(|player| restart_requests.contains(&player.id))
-                .count();
-            let count_of_player_type_player = game
-                .players
-                .iter()
-                .filter(|player| player.is_player())
-                .count();
-            if count_of_players_wanting_restart * 2 >= count_of_player_type_player {
-                game.game_state = create_new_game_state_playing(game);
-                ProcessPlayingUpdateResult::Broadcast
+            let player_id = crate::model::PlayerId::from(player_id);
+            if player_id == client_info.player_id
+                || !game.players.iter().any(|player| player.id == player_id)
+            {
+                // Not kicking, because the host can't kick themself or the player is unknown
+
+                ProcessClientMessageResult::Continue
             } else {
-                ProcessPlayingUpdateResult::DoNothing
+                // Process
+                kick_player(game, player_id);
+
+                // Update
+                match process_playing_update(game) {
+                    ProcessPlayingUpdateResult::Broadcast
+                    | ProcessPlayingUpdateResult::DoNothing => {
+                        // Do nothing; broadcasting anyway
+                    }
+                }
+
+                // Respond
+                broadcast_sender
+                    .send(FromLobbyMessage::Kicked(player_id))
+                    .unwrap();
+                broadcast_game_update(game.clone());
+
+                ProcessClientMessageResult::Continue
             }
         }
-        crate::model::GameState::Playing {
-            previous_questions,
-            current_question,
-            playing_state,
+        ToLobbyMessage::ClientMessage {
+            client_info,
+            client_message: shared_model::network::ClientMessage::VoteKick(player_id),
         } => {
-            match playing_state {
-                crate::model::PlayingState::Question {
-                    time_until,
-                    answers,
-                } => {
+            let player_id = crate::model::PlayerId::from(player_id);
+            if player_id == client_info.player_id
+                || !game.players.iter().any(|player| player.id == player_id)
+            {
+                // Not voting to kick, because players can't vote for themselves or the player is unknown
+
+                ProcessClientMessageResult::Continue
+            } else {
+                // Process
+                let voters = game.kick_votes.entry(player_id).or_default();
+                voters.insert(client_info.player_id);
+                let is_kicked = has_majority_for_kick(&game.players, player_id, voters);
+                if is_kicked {
+                    kick_player(game, player_id);
+                }
+
+                // Update
+                match process_playing_update(game) {
+                    ProcessPlayingUpdateResult::Broadcast
+                    | ProcessPlayingUpdateResult::DoNothing => {
+                        // Do nothing; broadcasting anyway
+                    }
+                }
+
+                // Respond
+                if is_kicked {
+                    broadcast_sender
+                        .send(FromLobbyMessage::Kicked(player_id))
+                        .unwrap();
+                }
+                broadcast_game_update(game.clone());
+
+                ProcessClientMessageResult::Continue
+            }
+        }
+        ToLobbyMessage::ClientMessage {
+            client_message: shared_model::network::ClientMessage::SwitchPlayType(player_id),
+            ..
+        } => {
+            let player_id = crate::model::PlayerId::from(player_id);
+            if let Some(player) = game
+                .players
+                .iter_mut()
+                .find(|player| player.id == player_id)
+            {
+                // Process
+                player.play_type = match player.play_type {
+                    crate::model::PlayType::Player { .. } => crate::model::PlayType::Watcher,
+                    crate::model::PlayType::Watcher => crate::model::PlayType::new_player(),
+                };
+                player.team = None;
+                assign_to_smallest_team(
+                    &mut game.players,
+                    player_id,
+                    game.configuration.team_setup,
+                );
+
+                // Update
+                match process_playing_update(game) {
+                    ProcessPlayingUpdateResult::Broadcast
+                    | ProcessPlayingUpdateResult::DoNothing => {
+                        // Do nothing; broadcasting anyway
+                    }
+                }
+
+                // Respond
+                broadcast_game_update(game.clone());
+            }
+
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::ClientMessage {
+            client_message: shared_model::network::ClientMessage::TransferHost(player_id),
+            ..
+        } => {
+            let player_id = crate::model::PlayerId::from(player_id);
+            if game.players.iter().any(|player| player.id == player_id) {
+                // Process
+                game.host = Some(player_id);
+
+                // Respond
+                broadcast_game_update(game.clone());
+            }
+
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::ClientMessage {
+            client_info,
+            client_message: shared_model::network::ClientMessage::UpdateConfiguration(configuration),
+        } => {
+            match game.game_state {
+                crate::model::GameState::InLobby => {
+                    let configuration = crate::model::GameConfiguration::from(configuration);
+                    if is_valid_configuration(&configuration) {
+                        // Process
+                        let count_of_teams = |configuration: &crate::model::GameConfiguration| {
+                            configuration
+                                .team_setup
+                                .map(|team_setup| team_setup.count_of_teams)
+                        };
+                        let has_team_count_changed =
+                            count_of_teams(&game.configuration) != count_of_teams(&configuration);
+                        game.configuration = configuration;
+                        if has_team_count_changed {
+                            balance_teams(&mut game.players, game.configuration.team_setup);
+                        }
+
+                        // Respond
+                        broadcast_game_update(game.clone());
+                    } else {
+                        // Respond
+                        client_info
+                            .callback
+                            .send(FromLobbyMessage::InvalidConfiguration)
+                            .unwrap();
+                    }
+
+                    ProcessClientMessageResult::Continue
+                }
+                crate::model::GameState::Playing { .. }
+                | crate::model::GameState::Tiebreaker { .. }
+                | crate::model::GameState::Aftermath { .. } => {
+                    // Not updating configuration, because the game has already been started
+
+                    ProcessClientMessageResult::Continue
+                }
+            }
+        }
+        ToLobbyMessage::ClientMessage {
+            client_message: shared_model::network::ClientMessage::AssignTeam(player_id, team),
+            ..
+        } => {
+            let player_id = crate::model::PlayerId::from(player_id);
+            let team = crate::model::TeamId::from(team);
+            let is_known_team = game.configuration.team_setup.map_or(false, |team_setup| {
+                team_setup.teams().any(|known| known == team)
+            });
+            match (
+                &game.game_state,
+                game.players
+                    .iter_mut()
+                    .find(|player| player.id == player_id),
+            ) {
+                (crate::model::GameState::InLobby, Some(player))
+                    if is_known_team && player.is_player() =>
+                {
+                    // Process
+                    player.team = Some(team);
+
+                    // Respond
+                    broadcast_game_update(game.clone());
+                }
+                _ => {
+                    // Not assigning team, because not in the lobby or not a player of a known team
+                }
+            }
+
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::ClientMessage {
+            client_message: shared_model::network::ClientMessage::BalanceTeams,
+            ..
+        } => {
+            match game.game_state {
+                crate::model::GameState::InLobby => {
+                    // Process
+                    balance_teams(&mut game.players, game.configuration.team_setup);
+
+                    // Respond
+                    broadcast_game_update(game.clone());
+                }
+                crate::model::GameState::Playing { .. }
+                | crate::model::GameState::Tiebreaker { .. }
+                | crate::model::GameState::Aftermath { .. } => {
+                    // Not balancing teams, because the game has already been started
+                }
+            }
+
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::ClientMessage {
+            client_info,
+            client_message: shared_model::network::ClientMessage::ChooseAnswer(answer),
+        } => {
+            match &mut game.game_state {
+                crate::model::GameState::Tiebreaker { contenders, .. }
+                    if !contenders.contains(&client_info.player_id) =>
+                {
+                    // Not processing answer, because not a contender of the tiebreaker
+
+                    ProcessClientMessageResult::Continue
+                }
+                crate::model::GameState::Playing {
+                    playing_state:
+                        crate::model::PlayingState::Question {
+                            shown_at,
+                            time_until,
+                            answers,
+                            response_times,
+                            ..
+                        },
+                    ..
+                }
+                | crate::model::GameState::Tiebreaker {
+                    playing_state:
+                        crate::model::PlayingState::Question {
+                            shown_at,
+                            time_until,
+                            answers,
+                            response_times,
+                            ..
+                        },
+                    ..
+                } => {
+                    // Process
+                    let now = Utc::now();
+                    let is_within_time_limit = time_until
+                        .as_ref()
+                        .map_or(true, |time_until| *time_until >= now);
+                    if is_within_time_limit {
+                        answers.insert(client_info.player_id, answer.into());
+                        response_times.insert(client_info.player_id, now - *shown_at);
+
+                        // Update
+                        match process_playing_update(game) {
+                            ProcessPlayingUpdateResult::Broadcast
+                            | ProcessPlayingUpdateResult::DoNothing => {
+                                // Do nothing; broadcasting anyway
+                            }
+                        }
+
+                        // Respond
+                        broadcast_game_update(game.clone());
+
+                        ProcessClientMessageResult::Continue
+                    } else {
+                        // Respond
+                        client_info
+                            .callback
+                            .send(FromLobbyMessage::AnswerNotInTimeLimit)
+                            .unwrap();
+
+                        ProcessClientMessageResult::Continue
+                    }
+                }
+                crate::model::GameState::InLobby
+                | crate::model::GameState::Playing {
+                    playing_state: crate::model::PlayingState::Solution { .. },
+                    ..
+                }
+                | crate::model::GameState::Tiebreaker {
+                    playing_state: crate::model::PlayingState::Solution { .. },
+                    ..
+                }
+                | crate::model::GameState::Aftermath { .. } => {
+                    // Not processing skip request, because not in GameState::Playing PlayingState::Question
+
+                    ProcessClientMessageResult::Continue
+                }
+            }
+        }
+        ToLobbyMessage::ClientMessage {
+            client_info,
+            client_message: shared_model::network::ClientMessage::PlaceStake(stake),
+        } => {
+            let current_points = game
+                .players
+                .iter()
+                .find(|player| player.id == client_info.player_id)
+                .and_then(|player| match player.play_type {
+                    crate::model::PlayType::Player { points, .. } => Some(points),
+                    crate::model::PlayType::Watcher => None,
+                });
+            let is_betting = matches!(
+                game.configuration.scoring_mode,
+                crate::model::ScoringMode::ConfidenceBetting
+            );
+            match (&mut game.game_state, current_points) {
+                (
+                    crate::model::GameState::Playing {
+                        playing_state:
+                            crate::model::PlayingState::Question {
+                                time_until,
+                                answers,
+                                stakes,
+                                ..
+                            },
+                        ..
+                    },
+                    Some(current_points),
+                ) if is_betting => {
+                    let is_within_time_limit = time_until
+                        .as_ref()
+                        .map_or(true, |time_until| *time_until >= Utc::now());
+                    if !is_within_time_limit {
+                        // Respond
+                        client_info
+                            .callback
+                            .send(FromLobbyMessage::AnswerNotInTimeLimit)
+                            .unwrap();
+
+                        ProcessClientMessageResult::Continue
+                    } else if answers.contains_key(&client_info.player_id) {
+                        // Not processing stake, because it is locked with the player's answer
+
+                        ProcessClientMessageResult::Continue
+                    } else {
+                        // Process; players can't stake more points than they have
+                        stakes.insert(client_info.player_id, stake.min(current_points));
+
+                        // Respond
+                        broadcast_game_update(game.clone());
+
+                        ProcessClientMessageResult::Continue
+                    }
+                }
+                _ => {
+                    // Not processing stake, because not betting in PlayingState::Question as player
+
+                    ProcessClientMessageResult::Continue
+                }
+            }
+        }
+        ToLobbyMessage::ClientMessage {
+            client_info,
+            client_message: shared_model::network::ClientMessage::ReportQuestion { reason },
+        } => {
+            match &mut game.game_state {
+                crate::model::GameState::Playing {
+                    current_question,
+                    playing_state: crate::model::PlayingState::Solution { reports, .. },
+                    ..
+                }
+                | crate::model::GameState::Tiebreaker {
+                    current_question,
+                    playing_state: crate::model::PlayingState::Solution { reports, .. },
+                    ..
+                } => {
+                    let optional_reddit_submission_data =
+                        crate::data::get(&current_question.question_id);
+                    match (
+                        reports.entry(client_info.player_id),
+                        optional_reddit_submission_data,
+                    ) {
+                        (Entry::Vacant(vacant_entry), Some(reddit_submission_data)) => {
+                            // Process
+                            let reason = crate::model::ReportReason::from(reason);
+                            vacant_entry.insert(reason);
+                            reports_storage
+                                .record(Report::new(reddit_submission_data, reason))
+                                .await;
+
+                            // Respond
+                            client_game_update(game.clone(), &client_info);
+                        }
+                        (Entry::Occupied(_), _) | (Entry::Vacant(_), None) => {
+                            // Not reporting question, because the player has already reported it
+                            // or it's missing in the dataset
+                        }
+                    }
+
+                    ProcessClientMessageResult::Continue
+                }
+                crate::model::GameState::InLobby
+                | crate::model::GameState::Playing {
+                    playing_state: crate::model::PlayingState::Question { .. },
+                    ..
+                }
+                | crate::model::GameState::Tiebreaker {
+                    playing_state: crate::model::PlayingState::Question { .. },
+                    ..
+                }
+                | crate::model::GameState::Aftermath { .. } => {
+                    // Not reporting question, because not in PlayingState::Solution
+
+                    ProcessClientMessageResult::Continue
+                }
+            }
+        }
+        ToLobbyMessage::ClientMessage {
+            client_info,
+            client_message: shared_model::network::ClientMessage::RequestSkip,
+        } => {
+            match &mut game.game_state {
+                crate::model::GameState::Playing {
+                    playing_state:
+                        crate::model::PlayingState::Question { skip_request, .. }
+                        | crate::model::PlayingState::Solution { skip_request, .. },
+                    ..
+                }
+                | crate::model::GameState::Tiebreaker {
+                    playing_state: crate::model::PlayingState::Solution { skip_request, .. },
+                    ..
+                } => {
+                    // Process
+                    if !skip_request.contains(&client_info.player_id) {
+                        skip_request.insert(client_info.player_id);
+                    }
+
+                    // Update
+                    match process_playing_update(game) {
+                        ProcessPlayingUpdateResult::Broadcast
+                        | ProcessPlayingUpdateResult::DoNothing => {
+                            // Do nothing; broadcasting anyway
+                        }
+                    }
+
+                    // Respond
+                    broadcast_game_update(game.clone());
+
+                    ProcessClientMessageResult::Continue
+                }
+                crate::model::GameState::InLobby
+                | crate::model::GameState::Tiebreaker {
+                    playing_state: crate::model::PlayingState::Question { .. },
+                    ..
+                }
+                | crate::model::GameState::Aftermath { .. } => {
+                    // Not processing skip request, because the tiebreaker questions can't be skipped
+                    // and there is nothing to skip outside of GameState::Playing
+
+                    ProcessClientMessageResult::Continue
+                }
+            }
+        }
+        ToLobbyMessage::ClientMessage {
+            client_info,
+            client_message: shared_model::network::ClientMessage::Chat(text),
+        } => {
+            match crate::model::ChatText::from_str(&text) {
+                Ok(chat_text) => {
+                    // Process
+                    let optional_chat_message = post_chat_message(
+                        game,
+                        client_info.player_id,
+                        crate::model::ChatContent::Text(chat_text),
+                    );
+
+                    // Respond
+                    if let Some(chat_message) = optional_chat_message {
+                        broadcast_sender
+                            .send(FromLobbyMessage::ChatMessage(chat_message))
+                            .unwrap();
+                    }
+                }
+                Err(error) => {
+                    tracing::warn!("Not posting chat message ({error})");
+                }
+            }
+
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::ClientMessage {
+            client_info,
+            client_message: shared_model::network::ClientMessage::React(emoji),
+        } => {
+            // Process
+            let optional_chat_message = post_chat_message(
+                game,
+                client_info.player_id,
+                crate::model::ChatContent::Reaction(emoji.into()),
+            );
+
+            // Respond
+            if let Some(chat_message) = optional_chat_message {
+                broadcast_sender
+                    .send(FromLobbyMessage::ChatMessage(chat_message))
+                    .unwrap();
+            }
+
+            ProcessClientMessageResult::Continue
+        }
+        ToLobbyMessage::ClientMessage {
+            client_info,
+            client_message: shared_model::network::ClientMessage::RequestPlayAgain,
+        } => {
+            match &mut game.game_state {
+                crate::model::GameState::Aftermath {
+                    restart_requests, ..
+                } => {
+                    restart_requests.push(client_info.player_id);
+
+                    // Update
+                    match process_playing_update(game) {
+                        ProcessPlayingUpdateResult::Broadcast
+                        | ProcessPlayingUpdateResult::DoNothing => {
+                            // Do nothing; broadcasting anyway
+                        }
+                    }
+
+                    // Respond
+                    broadcast_game_update(game.clone());
+
+                    ProcessClientMessageResult::Continue
+                }
+                crate::model::GameState::InLobby
+                | crate::model::GameState::Playing { .. }
+                | crate::model::GameState::Tiebreaker { .. } => {
+                    // Not processing play again request, because not in GameState::Aftermath
+
+                    ProcessClientMessageResult::Continue
+                }
+            }
+        }
+    }
+}
+
+#[must_use]
+pub(crate) fn process_playing_update(game: &mut crate::model::Game) -> ProcessPlayingUpdateResult {
+    if game.paused_at.is_some() {
+        // A paused game is frozen until it's resumed
+        return ProcessPlayingUpdateResult::DoNothing;
+    }
+
+    match &mut game.game_state {
+        crate::model::GameState::InLobby => ProcessPlayingUpdateResult::DoNothing,
+        crate::model::GameState::Aftermath {
+            restart_requests, ..
+        } => {
+            let count_of_players_wanting_restart = game
+                .players
+                .iter()
+                .filter(|player| player.is_player())
+                .filter(|player| restart_requests.contains(&player.id))
+                .count();
+            let count_of_player_type_player = game
+                .players
+                .iter()
+                .filter(|player| player.is_player())
+                .count();
+            if count_of_players_wanting_restart * 2 >= count_of_player_type_player {
+                game.game_state = create_new_game_state_playing(game);
+                ProcessPlayingUpdateResult::Broadcast
+            } else {
+                ProcessPlayingUpdateResult::DoNothing
+            }
+        }
+        crate::model::GameState::Playing {
+            previous_questions,
+            current_question,
+            playing_state,
+            eliminated_players,
+        } => {
+            match playing_state {
+                crate::model::PlayingState::Question {
+                    time_until,
+                    answers,
+                    response_times,
+                    stakes,
+                    skip_request,
+                    ..
+                } => {
+                    if has_majority_for_question_skip(&game.players, skip_request) {
+                        // Discard the question without points and drinks; it isn't drawn again
+                        game.skipped_questions.insert(current_question.question_id);
+                        match get_random_unplayed_question(
+                            &game.configuration,
+                            previous_questions,
+                            &game.skipped_questions,
+                        ) {
+                            Ok(Some(replacement_question)) => {
+                                *current_question = replacement_question;
+                                *playing_state =
+                                    create_new_playing_state_question(&game.configuration);
+
+                                return ProcessPlayingUpdateResult::Broadcast;
+                            }
+                            Ok(None) => {
+                                // Without any question left, the question has to be played
+                                game.skipped_questions.remove(&current_question.question_id);
+                                skip_request.clear();
+                            }
+                            Err(error) => {
+                                tracing::error!(
+                                    "Could not draw a replacement for the skipped question; playing it ({error:#})"
+                                );
+                                game.skipped_questions.remove(&current_question.question_id);
+                                skip_request.clear();
+                            }
+                        }
+                    }
+
                     let count_of_player_type_player = game
                         .players
                         .iter()
                         .filter(|player| player.is_player())
                         .count();
-                    let all_non_watchers_have_answered = game
+                    let all_non_watchers_have_answered = have_all_players_answered(
+                        game.configuration.team_setup,
+                        &game.players,
+                        answers,
+                    );
+                    if count_of_player_type_player == 0
+                        || all_non_watchers_have_answered
+                        || time_until
+                            .as_ref()
+                            .map_or(false, |time_until| *time_until < Utc::now())
+                    {
+                        // Settle answers per team
+                        let (answers, response_times) = resolve_team_answers(
+                            game.configuration.team_setup,
+                            &game.players,
+                            answers,
+                            response_times,
+                        );
+
+                        let streaks = calculate_streaks(&game.players, previous_questions);
+
+                        // Give out points
+                        let scoring_rules = game.configuration.scoring_rules;
+                        let point_changes = select_scoring_strategy(
+                            game.configuration.scoring_mode,
+                        )
+                        .score(&ScoringContext {
+                            scoring_rules: &scoring_rules,
+                            players: &game.players,
+                            answers: &answers,
+                            response_times: &response_times,
+                            stakes,
+                            streaks: &streaks,
+                            correct_answer: current_question.answer,
+                        });
+                        game.players.iter_mut().for_each(|player| {
+                            if let (
+                                crate::model::PlayType::Player { points, .. },
+                                Some(point_change),
+                            ) = (&mut player.play_type, point_changes.get(&player.id))
+                            {
+                                *points = apply_point_change(*points, *point_change);
+                            }
+                        });
+
+                        // Hand out drinks
+                        let broken_streaks = find_broken_streaks(
+                            &scoring_rules,
+                            &streaks,
+                            &answers,
+                            current_question.answer,
+                        );
+                        let drinks = evaluate_drink_rules(
+                            &game.configuration.drink_rules,
+                            &scoring_rules,
+                            game.configuration.penalty_mode,
+                            &game.players,
+                            &answers,
+                            &broken_streaks,
+                            current_question.answer,
+                        );
+                        game.players.iter_mut().for_each(|player| {
+                            if let (
+                                crate::model::PlayType::Player {
+                                    sips_taken,
+                                    sips_handed_out,
+                                    ..
+                                },
+                                Some(player_drinks),
+                            ) = (&mut player.play_type, drinks.get(&player.id))
+                            {
+                                *sips_taken = sips_taken.saturating_add(player_drinks.sips);
+                                *sips_handed_out =
+                                    sips_handed_out.saturating_add(player_drinks.sips_to_hand_out);
+                            }
+                        });
+
+                        update_streaks(
+                            &mut game.players,
+                            &streaks,
+                            &answers,
+                            current_question.answer,
+                        );
+
+                        let fastest_correct_answer = find_fastest_correct_answer(
+                            &game.players,
+                            &answers,
+                            &response_times,
+                            current_question.answer,
+                        );
+
+                        // Take lives and eliminate players
+                        eliminate_players(
+                            game.configuration.game_mode,
+                            &mut game.players,
+                            &answers,
+                            current_question.answer,
+                            previous_questions.len(),
+                            eliminated_players,
+                        );
+
+                        // Switch to Solution
+                        *playing_state = crate::model::PlayingState::Solution {
+                            time_until: calculate_solution_time_until(&game.configuration),
+                            answers,
+                            response_times,
+                            fastest_correct_answer,
+                            skip_request: HashSet::new(),
+                            drinks,
+                            stakes: stakes.clone(),
+                            reports: HashMap::new(),
+                        };
+
+                        ProcessPlayingUpdateResult::Broadcast
+                    } else {
+                        ProcessPlayingUpdateResult::DoNothing
+                    }
+                }
+                crate::model::PlayingState::Solution {
+                    time_until,
+                    answers,
+                    response_times,
+                    skip_request,
+                    ..
+                } => {
+                    let all_non_watchers_have_requested_skip =
+                        have_all_connected_players_requested_skip(&game.players, skip_request);
+                    if all_non_watchers_have_requested_skip || *time_until < Utc::now() {
+                        // STORE
+                        previous_questions.push(crate::model::PlayedQuestion {
+                            answered_question: *current_question,
+                            answers: answers.clone(),
+                            response_times: response_times.clone(),
+                        });
+
+                        // RENEW
+                        let maximum_questions =
+                            usize::try_from(game.configuration.count_of_questions).unwrap();
+                        let optional_next_question = if (game.configuration.marathon
+                            || previous_questions.len() < maximum_questions)
+                            && !is_last_one_standing(&game.players, eliminated_players)
+                        {
+                            // A marathon ends, when no question is left
+                            get_random_unplayed_question(
+                                &game.configuration,
+                                previous_questions,
+                                &game.skipped_questions,
+                            )
+                            .unwrap_or_else(|error| {
+                                tracing::error!(
+                                    "Could not draw the next question; ending the game ({error:#})"
+                                );
+                                None
+                            })
+                        } else {
+                            None
+                        };
+                        if let Some(next_question) = optional_next_question {
+                            *current_question = next_question;
+                            *playing_state = create_new_playing_state_question(&game.configuration);
+
+                            ProcessPlayingUpdateResult::Broadcast
+                        } else {
+                            let tied_top_players =
+                                find_tied_top_players(&game.configuration, &game.players);
+                            let optional_tiebreaker_question = if tied_top_players.is_empty() {
+                                None
+                            } else {
+                                // Without any question left, the tied players keep sharing their rank
+                                get_random_unplayed_question(
+                                    &game.configuration,
+                                    previous_questions,
+                                    &game.skipped_questions,
+                                )
+                                .ok()
+                                .flatten()
+                            };
+                            let elimination_order = restore_eliminated_players(
+                                &mut game.players,
+                                std::mem::take(eliminated_players),
+                            );
+
+                            game.game_state = match optional_tiebreaker_question {
+                                Some(tiebreaker_question) => crate::model::GameState::Tiebreaker {
+                                    previous_questions: std::mem::take(previous_questions),
+                                    current_question: tiebreaker_question,
+                                    playing_state: create_new_playing_state_question(
+                                        &game.configuration,
+                                    ),
+                                    contenders: tied_top_players,
+                                    eliminated: Vec::new(),
+                                },
+                                None => create_game_state_aftermath(
+                                    &game.players,
+                                    previous_questions,
+                                    None,
+                                    elimination_order,
+                                ),
+                            };
+
+                            ProcessPlayingUpdateResult::Broadcast
+                        }
+                    } else {
+                        ProcessPlayingUpdateResult::DoNothing
+                    }
+                }
+            }
+        }
+        crate::model::GameState::Tiebreaker {
+            previous_questions,
+            current_question,
+            playing_state,
+            contenders,
+            eliminated,
+        } => {
+            // Players, who have left or become watchers, can't win the tiebreaker anymore
+            contenders.retain(|contender| {
+                game.players
+                    .iter()
+                    .any(|player| player.id == *contender && player.is_player())
+            });
+
+            match playing_state {
+                crate::model::PlayingState::Question {
+                    time_until,
+                    answers,
+                    response_times,
+                    ..
+                } => {
+                    // Contenders, who have lost their connection, aren't waited for
+                    let mut connected_contenders = game
                         .players
                         .iter()
-                        .filter(|player| player.is_player())
-                        .all(|player| answers.contains_key(&player.id));
-                    if count_of_player_type_player == 0
-                        || all_non_watchers_have_answered
+                        .filter(|player| contenders.contains(&player.id))
+                        .filter(|player| player.disconnected_since.is_none())
+                        .peekable();
+                    let all_contenders_have_answered = connected_contenders.peek().is_some()
+                        && connected_contenders.all(|player| answers.contains_key(&player.id));
+                    if all_contenders_have_answered
                         || time_until
                             .as_ref()
                             .map_or(false, |time_until| *time_until < Utc::now())
                     {
-                        // Give out points
-                        let correct_players: Vec<crate::model::PlayerId> = game
-                            .players
-                            .iter_mut()
-                            .filter_map(|player| {
-                                let player_answer_is_correct = answers
-                                    .get(&player.id)
-                                    .map(|answer| *answer == current_question.answer);
-                                match player_answer_is_correct {
-                                    Some(true) => {
-                                        match &mut player.play_type {
-                                            crate::model::PlayType::Player { points } => {
-                                                *points += 10;
-                                            }
-                                            crate::model::PlayType::Watcher => {}
-                                        }
-                                        Some(player.id)
-                                    }
-                                    Some(false) | None => None,
-                                }
-                            })
-                            .collect();
-
-                        // Reward minority correct players extra points
-                        let are_correct_players_a_minority = (correct_players.len() as f64)
-                            .lt(&(count_of_player_type_player as f64 / 2.0));
-                        if are_correct_players_a_minority {
-                            game.players
-                                .iter_mut()
-                                .filter(|player| correct_players.contains(&player.id))
-                                .for_each(|correct_player| match &mut correct_player.play_type {
-                                    crate::model::PlayType::Player { points } => {
-                                        *points += 5;
-                                    }
-                                    crate::model::PlayType::Watcher => {}
-                                });
-                        }
+                        eliminate_contenders(
+                            contenders,
+                            eliminated,
+                            answers,
+                            current_question.answer,
+                        );
+                        let fastest_correct_answer = find_fastest_correct_answer(
+                            &game.players,
+                            answers,
+                            response_times,
+                            current_question.answer,
+                        );
 
-                        // Switch to Solution
+                        // Switch to Solution; no points and drinks are given out in the tiebreaker
                         *playing_state = crate::model::PlayingState::Solution {
-                            time_until: Utc::now()
-                                + chrono::Duration::seconds(
-                                    i64::try_from(PLAYING_STATE_SOLUTION_TIME_IN_SECONDS).unwrap(),
-                                ),
+                            time_until: calculate_solution_time_until(&game.configuration),
                             answers: answers.clone(),
+                            response_times: response_times.clone(),
+                            fastest_correct_answer,
                             skip_request: HashSet::new(),
+                            drinks: HashMap::new(),
+                            stakes: HashMap::new(),
+                            reports: HashMap::new(),
                         };
 
                         ProcessPlayingUpdateResult::Broadcast
@@ -453,54 +1468,60 @@ fn process_playing_update(game: &mut crate::model::Game) -> ProcessPlayingUpdate
                 crate::model::PlayingState::Solution {
                     time_until,
                     answers,
+                    response_times,
                     skip_request,
+                    ..
                 } => {
-                    let all_non_watchers_have_requested_skip = game
-                        .players
-                        .iter()
-                        .filter(|player| player.is_player())
-                        .all(|player| skip_request.contains(&player.id));
-                    if all_non_watchers_have_requested_skip || *time_until < Utc::now() {
+                    // Only the remaining contenders decide, whether the tiebreaker goes on early
+                    let all_contenders_have_requested_skip =
+                        have_all_connected_players_requested_skip(
+                            game.players
+                                .iter()
+                                .filter(|player| contenders.contains(&player.id)),
+                            skip_request,
+                        );
+                    if all_contenders_have_requested_skip || *time_until < Utc::now() {
                         // STORE
-                        previous_questions.push((*current_question, answers.clone()));
+                        previous_questions.push(crate::model::PlayedQuestion {
+                            answered_question: *current_question,
+                            answers: answers.clone(),
+                            response_times: response_times.clone(),
+                        });
 
                         // RENEW
-                        let maximum_questions =
-                            usize::try_from(game.configuration.count_of_questions).unwrap();
-                        if previous_questions.len() < maximum_questions {
-                            *current_question =
-                                crate::data_model_bridge::get_random_answered_question(
-                                    game.configuration.minimum_score_per_question,
-                                    Some(
-                                        &previous_questions
-                                            .iter()
-                                            .map(|k| k.0.question_id)
-                                            .collect::<HashSet<_>>(),
-                                    ),
-                                    None,
-                                )
-                                .unwrap()
-                                .unwrap();
-                            *playing_state = create_new_playing_state_question(&game.configuration);
-
-                            ProcessPlayingUpdateResult::Broadcast
+                        let optional_next_question = if contenders.len() > 1 {
+                            get_random_unplayed_question(
+                                &game.configuration,
+                                previous_questions,
+                                &game.skipped_questions,
+                            )
+                            .ok()
+                            .flatten()
                         } else {
-                            game.game_state = crate::model::GameState::Aftermath {
-                                ranked_players: game
-                                    .players
-                                    .iter()
-                                    .filter_map(|player| match player.play_type {
-                                        crate::model::PlayType::Player { points } => {
-                                            Some((player.id, player.name.clone(), points))
-                                        }
-                                        crate::model::PlayType::Watcher => None,
-                                    })
-                                    .collect(),
-                                restart_requests: Vec::new(),
-                            };
-
-                            ProcessPlayingUpdateResult::Broadcast
+                            None
+                        };
+                        match optional_next_question {
+                            Some(next_question) => {
+                                *current_question = next_question;
+                                *playing_state =
+                                    create_new_playing_state_question(&game.configuration);
+                            }
+                            None => {
+                                // Without any question left, the remaining contenders share their rank
+                                let tiebreaker_winner = match contenders.as_slice() {
+                                    [winner] => Some(*winner),
+                                    _ => None,
+                                };
+                                game.game_state = create_game_state_aftermath(
+                                    &game.players,
+                                    previous_questions,
+                                    tiebreaker_winner,
+                                    Vec::new(),
+                                );
+                            }
                         }
+
+                        ProcessPlayingUpdateResult::Broadcast
                     } else {
                         ProcessPlayingUpdateResult::DoNothing
                     }
@@ -510,7 +1531,348 @@ fn process_playing_update(game: &mut crate::model::Game) -> ProcessPlayingUpdate
     }
 }
 
-fn create_new_game_state_playing(game: &crate::model::Game) -> crate::model::GameState {
+fn find_fastest_correct_answer(
+    players: &[crate::model::Player],
+    answers: &HashMap<crate::model::PlayerId, crate::model::Answer>,
+    response_times: &HashMap<crate::model::PlayerId, chrono::Duration>,
+    correct_answer: crate::model::Answer,
+) -> Option<(
+    crate::model::PlayerId,
+    crate::model::PlayerName,
+    chrono::Duration,
+)> {
+    players
+        .iter()
+        .filter(|player| player.is_player())
+        .filter(|player| answers.get(&player.id) == Some(&correct_answer))
+        .filter_map(|player| {
+            response_times
+                .get(&player.id)
+                .map(|response_time| (player.id, player.name.clone(), *response_time))
+        })
+        .min_by_key(|(_, _, response_time)| *response_time)
+}
+
+fn calculate_solution_time_until(
+    configuration: &crate::model::GameConfiguration,
+) -> chrono::DateTime<Utc> {
+    // Only an invalid configuration could be out of range, so its solution is shown for good
+    calculate_time_until(configuration.solution_time_in_seconds)
+        .unwrap_or(chrono::DateTime::<Utc>::MAX_UTC)
+}
+
+/// The point in time the given seconds from now, unless it's out of range.
+fn calculate_time_until(seconds: u64) -> Option<chrono::DateTime<Utc>> {
+    i64::try_from(seconds)
+        .ok()
+        .and_then(chrono::Duration::try_seconds)
+        .and_then(|duration| Utc::now().checked_add_signed(duration))
+}
+
+fn get_random_unplayed_question(
+    configuration: &crate::model::GameConfiguration,
+    previous_questions: &[crate::model::PlayedQuestion],
+    skipped_questions: &HashSet<crate::model::QuestionId>,
+) -> anyhow::Result<Option<crate::model::AnsweredQuestion>> {
+    crate::data_model_bridge::get_random_answered_question(
+        configuration.minimum_score_per_question,
+        Some(
+            &previous_questions
+                .iter()
+                .map(|played_question| played_question.answered_question.question_id)
+                .chain(skipped_questions.iter().copied())
+                .collect::<HashSet<_>>(),
+        ),
+        None,
+    )
+}
+
+/// Whether every connected player with `PlayType::Player` has requested to skip the solution.
+/// Without any connected player the solution isn't skipped.
+fn have_all_connected_players_requested_skip<'a>(
+    players: impl IntoIterator<Item = &'a crate::model::Player>,
+    skip_request: &HashSet<crate::model::PlayerId>,
+) -> bool {
+    let mut connected_players = players
+        .into_iter()
+        .filter(|player| player.is_player() && player.disconnected_since.is_none())
+        .peekable();
+    connected_players.peek().is_some()
+        && connected_players.all(|player| skip_request.contains(&player.id))
+}
+
+/// Whether more than half of the players with `PlayType::Player` have voted
+/// to discard the current question.
+fn has_majority_for_question_skip(
+    players: &[crate::model::Player],
+    skip_request: &HashSet<crate::model::PlayerId>,
+) -> bool {
+    let count_of_player_type_player = players.iter().filter(|player| player.is_player()).count();
+    let count_of_skip_votes = players
+        .iter()
+        .filter(|player| player.is_player())
+        .filter(|player| skip_request.contains(&player.id))
+        .count();
+    count_of_skip_votes * 2 > count_of_player_type_player
+}
+
+fn create_game_state_aftermath(
+    players: &[crate::model::Player],
+    previous_questions: &[crate::model::PlayedQuestion],
+    tiebreaker_winner: Option<crate::model::PlayerId>,
+    eliminated_players: Vec<(crate::model::PlayerId, usize)>,
+) -> crate::model::GameState {
+    crate::model::GameState::Aftermath {
+        ranked_players: players
+            .iter()
+            .filter_map(|player| match player.play_type {
+                crate::model::PlayType::Player { points, .. } => {
+                    Some((player.id, player.name.clone(), points))
+                }
+                crate::model::PlayType::Watcher => None,
+            })
+            .collect(),
+        drink_leaderboard: players
+            .iter()
+            .filter_map(|player| match player.play_type {
+                crate::model::PlayType::Player {
+                    sips_taken,
+                    sips_handed_out,
+                    ..
+                } => Some((player.id, player.name.clone(), sips_taken, sips_handed_out)),
+                crate::model::PlayType::Watcher => None,
+            })
+            .collect(),
+        average_response_times: calculate_average_response_times(players, previous_questions),
+        tiebreaker_winner,
+        eliminated_players,
+        count_of_played_questions: previous_questions.len(),
+        restart_requests: Vec::new(),
+    }
+}
+
+/// Appends the chat message or reaction of the player to the bounded chat history,
+/// unless the player has already posted one within the minimum interval.
+fn post_chat_message(
+    game: &mut crate::model::Game,
+    player_id: crate::model::PlayerId,
+    content: crate::model::ChatContent,
+) -> Option<crate::model::ChatMessage> {
+    let player_name = game
+        .players
+        .iter()
+        .find(|player| player.id == player_id)?
+        .name
+        .clone();
+
+    let now = Utc::now();
+    let minimum_interval =
+        chrono::Duration::milliseconds(MINIMUM_CHAT_MESSAGE_INTERVAL_IN_MILLISECONDS);
+    if let Some(last_chat_message_at) = game.last_chat_message_at.get(&player_id) {
+        if now - *last_chat_message_at < minimum_interval {
+            tracing::warn!("Not posting chat message, because the player is rate limited");
+            return None;
+        }
+    }
+    game.last_chat_message_at.insert(player_id, now);
+
+    let chat_message = crate::model::ChatMessage {
+        player_id,
+        player_name,
+        content,
+        sent_at: now,
+    };
+    game.chat_history.push_back(chat_message.clone());
+    while game.chat_history.len() > shared_model::game::MAXIMUM_COUNT_OF_CHAT_MESSAGES {
+        game.chat_history.pop_front();
+    }
+
+    Some(chat_message)
+}
+
+/// Whether at least two and more than half of the other connected players in the lobby,
+/// watchers included, have voted to kick the player.
+/// Requiring two votes keeps a single player from kicking everyone in a small lobby.
+fn has_majority_for_kick(
+    players: &[crate::model::Player],
+    player_id: crate::model::PlayerId,
+    voters: &HashSet<crate::model::PlayerId>,
+) -> bool {
+    let other_connected_players = players
+        .iter()
+        .filter(|player| player.id != player_id && player.disconnected_since.is_none());
+    let count_of_other_players = other_connected_players.clone().count();
+    let count_of_votes = other_connected_players
+        .filter(|player| voters.contains(&player.id))
+        .count();
+    count_of_votes >= 2 && count_of_votes * 2 > count_of_other_players
+}
+
+/// Removes the player from the lobby and blocks their name from joining it again.
+/// A kicked host is replaced by the next connected player.
+fn kick_player(game: &mut crate::model::Game, player_id: crate::model::PlayerId) {
+    if let Some(player) = game.players.iter().find(|player| player.id == player_id) {
+        game.blocked_names.insert(player.name.clone());
+    }
+    game.players.retain(|player| player.id != player_id);
+    hand_over_host_if_necessary(game);
+    game.kick_votes.remove(&player_id);
+    for voters in game.kick_votes.values_mut() {
+        voters.remove(&player_id);
+    }
+}
+
+/// Moves the time limits of the current question or solution by the duration of a pause,
+/// so that the remaining time is the same as before the pause. The response times
+/// don't include the pause either.
+fn postpone_playing_state(
+    playing_state: &mut crate::model::PlayingState,
+    pause_duration: chrono::Duration,
+) {
+    match playing_state {
+        crate::model::PlayingState::Question {
+            shown_at,
+            time_until,
+            ..
+        } => {
+            *shown_at += pause_duration;
+            if let Some(time_until) = time_until {
+                *time_until += pause_duration;
+            }
+        }
+        crate::model::PlayingState::Solution { time_until, .. } => {
+            *time_until += pause_duration;
+        }
+    }
+}
+
+/// Stores the current question as played, if its solution is already shown,
+/// because its points and drinks have already been given out.
+fn store_resolved_question(
+    previous_questions: &mut Vec<crate::model::PlayedQuestion>,
+    current_question: crate::model::AnsweredQuestion,
+    playing_state: &crate::model::PlayingState,
+) {
+    match playing_state {
+        crate::model::PlayingState::Question { .. } => {}
+        crate::model::PlayingState::Solution {
+            answers,
+            response_times,
+            ..
+        } => previous_questions.push(crate::model::PlayedQuestion {
+            answered_question: current_question,
+            answers: answers.clone(),
+            response_times: response_times.clone(),
+        }),
+    }
+}
+
+/// Averages the response times of every player with `PlayType::Player` over all played questions,
+/// sorted from fastest to slowest. Players without any answer are left out.
+fn calculate_average_response_times(
+    players: &[crate::model::Player],
+    played_questions: &[crate::model::PlayedQuestion],
+) -> Vec<(
+    crate::model::PlayerId,
+    crate::model::PlayerName,
+    chrono::Duration,
+)> {
+    let mut average_response_times: Vec<_> = players
+        .iter()
+        .filter(|player| player.is_player())
+        .filter_map(|player| {
+            let response_times: Vec<chrono::Duration> = played_questions
+                .iter()
+                .filter_map(|played_question| played_question.response_times.get(&player.id))
+                .copied()
+                .collect();
+            let count_of_response_times = i32::try_from(response_times.len()).ok()?;
+            if count_of_response_times == 0 {
+                return None;
+            }
+            let sum_of_response_times = response_times
+                .into_iter()
+                .fold(chrono::Duration::zero(), |sum, response_time| {
+                    sum + response_time
+                });
+            Some((
+                player.id,
+                player.name.clone(),
+                sum_of_response_times / count_of_response_times,
+            ))
+        })
+        .collect();
+    average_response_times.sort_by_key(|(_, _, average_response_time)| *average_response_time);
+    average_response_times
+}
+
+/// A configuration is valid, if there are enough questions above the minimum score,
+/// a set answer time and the solution time are neither zero nor above their maximum,
+/// the minority threshold is a percentage and a team setup has at least two teams.
+pub(crate) fn is_valid_configuration(configuration: &crate::model::GameConfiguration) -> bool {
+    let count_of_available_questions =
+        crate::data::calculate_count_of_questions(configuration.minimum_score_per_question);
+    let has_enough_questions =
+        usize::try_from(configuration.count_of_questions).map_or(false, |count_of_questions| {
+            count_of_questions > 0 && count_of_questions <= count_of_available_questions
+        });
+
+    has_enough_questions
+        && configuration.maximum_answer_time_per_question.map_or(
+            true,
+            |maximum_answer_time_per_question| {
+                (1..=crate::model::MAXIMUM_ANSWER_TIME_IN_SECONDS)
+                    .contains(&maximum_answer_time_per_question)
+            },
+        )
+        && (1..=crate::model::MAXIMUM_SOLUTION_TIME_IN_SECONDS)
+            .contains(&configuration.solution_time_in_seconds)
+        && (1..=100).contains(&configuration.scoring_rules.minority_threshold_in_percent)
+        && configuration.scoring_rules.streak_length_for_bonus > 0
+        && configuration.team_setup.map_or(true, |team_setup| {
+            (2..=crate::model::MAXIMUM_COUNT_OF_TEAMS).contains(&team_setup.count_of_teams)
+        })
+        && configuration
+            .game_mode
+            .lives_per_player()
+            .map_or(true, |lives_per_player| {
+                (1..=crate::model::MAXIMUM_COUNT_OF_LIVES).contains(&lives_per_player)
+            })
+}
+
+/// Whether the connection is the current one of its player. A rejoined player has replaced
+/// their previous connection, whose end or messages may only be noticed after the rejoin.
+/// Connections of players, who aren't in the game anymore, aren't current either.
+fn is_current_connection(game: &crate::model::Game, client_info: &ClientInfo) -> bool {
+    game.players.iter().any(|player| {
+        player.id == client_info.player_id && player.connection_id == client_info.connection_id
+    })
+}
+
+/// Hands the host role over to the first connected player, if the host has left
+/// or lost their connection. Without any connected player the host role is kept.
+fn hand_over_host_if_necessary(game: &mut crate::model::Game) {
+    let is_host_connected = game
+        .players
+        .iter()
+        .any(|player| Some(player.id) == game.host && player.disconnected_since.is_none());
+    if !is_host_connected {
+        if let Some(new_host) = game
+            .players
+            .iter()
+            .find(|player| player.disconnected_since.is_none())
+        {
+            game.host = Some(new_host.id);
+        }
+    }
+}
+
+fn create_new_game_state_playing(game: &mut crate::model::Game) -> crate::model::GameState {
+    reset_lives(&mut game.players, game.configuration.game_mode);
+    reset_streaks(&mut game.players);
+    reset_sips(&mut game.players);
+    game.skipped_questions.clear();
+
     let current_question = crate::data_model_bridge::get_random_answered_question(
         game.configuration.minimum_score_per_question,
         None,
@@ -523,6 +1885,7 @@ fn create_new_game_state_playing(game: &crate::model::Game) -> crate::model::Gam
         previous_questions: Vec::new(),
         current_question,
         playing_state: create_new_playing_state_question(&game.configuration),
+        eliminated_players: Vec::new(),
     }
 }
 
@@ -530,19 +1893,18 @@ fn create_new_playing_state_question(
     game_configuration: &crate::model::GameConfiguration,
 ) -> crate::model::PlayingState {
     crate::model::PlayingState::Question {
-        time_until: game_configuration.maximum_answer_time_per_question.map(
-            |maximum_answer_time_per_question| {
-                Utc::now()
-                    + chrono::Duration::seconds(
-                        i64::try_from(maximum_answer_time_per_question).unwrap(),
-                    )
-            },
-        ),
+        shown_at: Utc::now(),
+        time_until: game_configuration
+            .maximum_answer_time_per_question
+            .and_then(calculate_time_until),
         answers: HashMap::new(),
+        response_times: HashMap::new(),
+        stakes: HashMap::new(),
+        skip_request: HashSet::new(),
     }
 }
 
-enum ProcessPlayingUpdateResult {
+pub(crate) enum ProcessPlayingUpdateResult {
     Broadcast,
     DoNothing,
 }
@@ -551,3 +1913,622 @@ enum ProcessClientMessageResult {
     Continue,
     Exit,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Answer, GameState, PlayType, PlayingState};
+    use crate::routes::game::test_utils::{create_game, create_player};
+
+    use super::*;
+
+    /// Lets the players answer the current question correctly or wrong and shows its solution.
+    fn play_question(game: &mut crate::model::Game, are_answers_correct: &[bool]) {
+        let player_ids: Vec<crate::model::PlayerId> =
+            game.players.iter().map(|player| player.id).collect();
+        if let GameState::Playing {
+            current_question,
+            playing_state:
+                PlayingState::Question {
+                    answers,
+                    response_times,
+                    ..
+                },
+            ..
+        } = &mut game.game_state
+        {
+            for (player_id, is_correct) in player_ids.into_iter().zip(are_answers_correct) {
+                let answer = match (current_question.answer, is_correct) {
+                    (correct_answer, true) => correct_answer,
+                    (Answer::TheOnion, false) => Answer::NotTheOnion,
+                    (Answer::NotTheOnion, false) => Answer::TheOnion,
+                };
+                answers.insert(player_id, answer);
+                response_times.insert(player_id, chrono::Duration::seconds(1));
+            }
+        }
+        let _ = process_playing_update(game);
+    }
+
+    /// Lets every player skip the shown solution.
+    fn skip_solution(game: &mut crate::model::Game) {
+        let player_ids: HashSet<crate::model::PlayerId> =
+            game.players.iter().map(|player| player.id).collect();
+        if let GameState::Playing {
+            playing_state: PlayingState::Solution { skip_request, .. },
+            ..
+        } = &mut game.game_state
+        {
+            *skip_request = player_ids;
+        }
+        let _ = process_playing_update(game);
+    }
+
+    fn collect_sips(game: &crate::model::Game) -> Vec<(u16, u16)> {
+        game.players
+            .iter()
+            .map(|player| match player.play_type {
+                PlayType::Player {
+                    sips_taken,
+                    sips_handed_out,
+                    ..
+                } => (sips_taken, sips_handed_out),
+                PlayType::Watcher => panic!("Expected only players"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn restarted_game_starts_without_sips_of_the_previous_round() {
+        let mut game = create_game(
+            vec![
+                create_player("Alice", 0),
+                create_player("Bob", 0),
+                create_player("Carol", 0),
+            ],
+            GameState::InLobby,
+        );
+        game.configuration.count_of_questions = 1;
+
+        let mut drink_leaderboards = Vec::new();
+        for _ in 0..2 {
+            game.game_state = match &game.game_state {
+                GameState::InLobby => create_new_game_state_playing(&mut game),
+                GameState::Aftermath { .. } => {
+                    if let GameState::Aftermath {
+                        restart_requests, ..
+                    } = &mut game.game_state
+                    {
+                        restart_requests.extend(game.players.iter().map(|player| player.id));
+                    }
+                    let _ = process_playing_update(&mut game);
+                    assert_eq!(collect_sips(&game), vec![(0, 0); 3]);
+                    game.game_state.clone()
+                }
+                GameState::Playing { .. } | GameState::Tiebreaker { .. } => unreachable!(),
+            };
+
+            play_question(&mut game, &[true, false, false]);
+            skip_solution(&mut game);
+            match &game.game_state {
+                GameState::Aftermath {
+                    drink_leaderboard, ..
+                } => drink_leaderboards.push(drink_leaderboard.clone()),
+                _ => panic!("Expected the aftermath after the only question"),
+            }
+        }
+
+        assert_eq!(drink_leaderboards[0], drink_leaderboards[1]);
+    }
+
+    #[test]
+    fn configuration_with_too_long_answer_or_solution_time_is_invalid() {
+        let configuration = create_game(Vec::new(), GameState::InLobby).configuration;
+        assert!(is_valid_configuration(&configuration));
+
+        assert!(!is_valid_configuration(&crate::model::GameConfiguration {
+            maximum_answer_time_per_question: Some(u64::MAX),
+            ..configuration
+        }));
+        assert!(!is_valid_configuration(&crate::model::GameConfiguration {
+            solution_time_in_seconds: u64::MAX,
+            ..configuration
+        }));
+    }
+
+    #[tokio::test]
+    async fn late_disconnect_of_a_replaced_connection_keeps_the_rejoined_player() {
+        let mut game = create_game(
+            vec![create_player("Alice", 0), create_player("Bob", 0)],
+            GameState::InLobby,
+        );
+        let alice = game.players[0].clone();
+        let bob_id = game.players[1].id;
+
+        let (broadcast_sender, _broadcast_receiver) = tokio::sync::broadcast::channel(16);
+        let (callback, _callback_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let lobbies_storage = LobbiesStorage::default();
+        let reports_storage = ReportsStorage::default();
+        let old_client_info = ClientInfo {
+            callback: callback.clone(),
+            player_id: alice.id,
+            connection_id: alice.connection_id,
+        };
+        let new_client_info = ClientInfo {
+            callback,
+            player_id: alice.id,
+            connection_id: crate::model::ConnectionId::generate(),
+        };
+
+        for to_lobby_message in [
+            ToLobbyMessage::Rejoin {
+                client_info: new_client_info.clone(),
+                session_token: alice.session_token,
+            },
+            ToLobbyMessage::Disconnect {
+                client_info: old_client_info.clone(),
+            },
+            ToLobbyMessage::Leave {
+                client_info: old_client_info,
+            },
+        ] {
+            let _ = process_client_message(
+                to_lobby_message,
+                &crate::model::InviteCode::generate(),
+                &mut game,
+                &broadcast_sender,
+                &lobbies_storage,
+                &reports_storage,
+            )
+            .await;
+        }
+
+        assert_eq!(game.players.len(), 2);
+        assert!(game.players[0].disconnected_since.is_none());
+        assert_eq!(game.host, Some(alice.id));
+
+        // The end of the current connection still counts
+        let _ = process_client_message(
+            ToLobbyMessage::Disconnect {
+                client_info: new_client_info,
+            },
+            &crate::model::InviteCode::generate(),
+            &mut game,
+            &broadcast_sender,
+            &lobbies_storage,
+            &reports_storage,
+        )
+        .await;
+
+        assert!(game.players[0].disconnected_since.is_some());
+        assert_eq!(game.host, Some(bob_id));
+    }
+
+    #[tokio::test]
+    async fn messages_of_a_replaced_connection_are_ignored() {
+        let mut game = create_game(
+            vec![create_player("Alice", 0), create_player("Bob", 0)],
+            GameState::InLobby,
+        );
+        game.game_state = create_new_game_state_playing(&mut game);
+        let alice = game.players[0].clone();
+
+        let (broadcast_sender, _broadcast_receiver) = tokio::sync::broadcast::channel(16);
+        let (callback, _callback_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let lobbies_storage = LobbiesStorage::default();
+        let reports_storage = ReportsStorage::default();
+        let old_client_info = ClientInfo {
+            callback: callback.clone(),
+            player_id: alice.id,
+            connection_id: alice.connection_id,
+        };
+        let new_client_info = ClientInfo {
+            callback,
+            player_id: alice.id,
+            connection_id: crate::model::ConnectionId::generate(),
+        };
+
+        for to_lobby_message in [
+            ToLobbyMessage::Rejoin {
+                client_info: new_client_info,
+                session_token: alice.session_token,
+            },
+            ToLobbyMessage::ClientMessage {
+                client_info: old_client_info,
+                client_message: shared_model::network::ClientMessage::ChooseAnswer(
+                    shared_model::game::Answer::TheOnion,
+                ),
+            },
+        ] {
+            let _ = process_client_message(
+                to_lobby_message,
+                &crate::model::InviteCode::generate(),
+                &mut game,
+                &broadcast_sender,
+                &lobbies_storage,
+                &reports_storage,
+            )
+            .await;
+        }
+
+        match &game.game_state {
+            GameState::Playing {
+                playing_state: PlayingState::Question { answers, .. },
+                ..
+            } => assert!(answers.is_empty()),
+            _ => panic!("Expected the question to be unanswered"),
+        }
+    }
+
+    #[test]
+    fn disconnected_players_are_not_waited_for() {
+        let mut game = create_game(
+            vec![create_player("Alice", 0), create_player("Bob", 0)],
+            GameState::InLobby,
+        );
+        game.game_state = create_new_game_state_playing(&mut game);
+        game.players[1].disconnected_since = Some(Utc::now());
+
+        play_question(&mut game, &[true]);
+        assert!(matches!(
+            game.game_state,
+            GameState::Playing {
+                playing_state: PlayingState::Solution { .. },
+                ..
+            }
+        ));
+
+        let alice_id = game.players[0].id;
+        if let GameState::Playing {
+            playing_state: PlayingState::Solution { skip_request, .. },
+            ..
+        } = &mut game.game_state
+        {
+            skip_request.insert(alice_id);
+        }
+        let _ = process_playing_update(&mut game);
+        match &game.game_state {
+            GameState::Playing {
+                previous_questions,
+                playing_state: PlayingState::Question { .. },
+                ..
+            } => assert_eq!(previous_questions.len(), 1),
+            _ => panic!("Expected the next question after the skipped solution"),
+        }
+    }
+
+    #[tokio::test]
+    async fn stake_is_locked_after_the_answer_and_the_time_limit() {
+        let mut game = create_game(
+            vec![
+                create_player("Alice", 20),
+                create_player("Bob", 20),
+                create_player("Carol", 20),
+            ],
+            GameState::InLobby,
+        );
+        game.configuration.scoring_mode = crate::model::ScoringMode::ConfidenceBetting;
+        game.game_state = create_new_game_state_playing(&mut game);
+        let client_infos: Vec<ClientInfo> = game
+            .players
+            .iter()
+            .map(|player| ClientInfo {
+                callback: tokio::sync::mpsc::unbounded_channel().0,
+                player_id: player.id,
+                connection_id: player.connection_id,
+            })
+            .collect();
+
+        let (broadcast_sender, _broadcast_receiver) = tokio::sync::broadcast::channel(16);
+        let lobbies_storage = LobbiesStorage::default();
+        let reports_storage = ReportsStorage::default();
+        let (late_callback, mut late_callback_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let late_client_info = ClientInfo {
+            callback: late_callback,
+            ..client_infos[2].clone()
+        };
+
+        for (client_info, client_message) in [
+            (
+                client_infos[0].clone(),
+                shared_model::network::ClientMessage::PlaceStake(5),
+            ),
+            (
+                client_infos[1].clone(),
+                shared_model::network::ClientMessage::ChooseAnswer(
+                    shared_model::game::Answer::TheOnion,
+                ),
+            ),
+            (
+                client_infos[1].clone(),
+                shared_model::network::ClientMessage::PlaceStake(10),
+            ),
+        ] {
+            let _ = process_client_message(
+                ToLobbyMessage::ClientMessage {
+                    client_info,
+                    client_message,
+                },
+                &crate::model::InviteCode::generate(),
+                &mut game,
+                &broadcast_sender,
+                &lobbies_storage,
+                &reports_storage,
+            )
+            .await;
+        }
+        if let GameState::Playing {
+            playing_state: PlayingState::Question { time_until, .. },
+            ..
+        } = &mut game.game_state
+        {
+            *time_until = Some(Utc::now() - chrono::Duration::seconds(1));
+        }
+        let _ = process_client_message(
+            ToLobbyMessage::ClientMessage {
+                client_info: late_client_info,
+                client_message: shared_model::network::ClientMessage::PlaceStake(15),
+            },
+            &crate::model::InviteCode::generate(),
+            &mut game,
+            &broadcast_sender,
+            &lobbies_storage,
+            &reports_storage,
+        )
+        .await;
+
+        match &game.game_state {
+            GameState::Playing {
+                playing_state: PlayingState::Question { stakes, .. },
+                ..
+            } => assert_eq!(stakes, &HashMap::from([(client_infos[0].player_id, 5)])),
+            _ => panic!("Expected the question to be still shown"),
+        }
+        assert!(matches!(
+            late_callback_receiver.try_recv(),
+            Ok(FromLobbyMessage::AnswerNotInTimeLimit)
+        ));
+    }
+
+    #[test]
+    fn tiebreaker_decides_a_tied_first_place_without_waiting_for_non_contenders() {
+        let mut game = create_game(
+            vec![
+                create_player("Alice", 0),
+                create_player("Bob", 0),
+                create_player("Carol", 0),
+            ],
+            GameState::InLobby,
+        );
+        game.configuration.count_of_questions = 1;
+        game.configuration.sudden_death_tiebreaker = true;
+        game.game_state = create_new_game_state_playing(&mut game);
+        let player_ids: Vec<crate::model::PlayerId> =
+            game.players.iter().map(|player| player.id).collect();
+
+        play_question(&mut game, &[true, true, false]);
+        skip_solution(&mut game);
+
+        // Alice and Bob are tied; only Alice answers the tiebreaker question correctly
+        match &mut game.game_state {
+            GameState::Tiebreaker {
+                current_question,
+                playing_state: PlayingState::Question { answers, .. },
+                contenders,
+                ..
+            } => {
+                assert_eq!(contenders, &vec![player_ids[0], player_ids[1]]);
+                let wrong_answer = match current_question.answer {
+                    Answer::TheOnion => Answer::NotTheOnion,
+                    Answer::NotTheOnion => Answer::TheOnion,
+                };
+                answers.insert(player_ids[0], current_question.answer);
+                answers.insert(player_ids[1], wrong_answer);
+            }
+            _ => panic!("Expected a tiebreaker for the tied first place"),
+        }
+        let _ = process_playing_update(&mut game);
+
+        // The skip request of the only remaining contender is enough
+        match &mut game.game_state {
+            GameState::Tiebreaker {
+                playing_state: PlayingState::Solution { skip_request, .. },
+                contenders,
+                eliminated,
+                ..
+            } => {
+                assert_eq!(contenders, &vec![player_ids[0]]);
+                assert_eq!(eliminated, &vec![player_ids[1]]);
+                skip_request.insert(player_ids[0]);
+            }
+            _ => panic!("Expected the solution of the tiebreaker question"),
+        }
+        let _ = process_playing_update(&mut game);
+
+        match &game.game_state {
+            GameState::Aftermath {
+                tiebreaker_winner, ..
+            } => assert_eq!(*tiebreaker_winner, Some(player_ids[0])),
+            _ => panic!("Expected the aftermath after the decided tiebreaker"),
+        }
+    }
+
+    #[test]
+    fn tied_first_place_without_tiebreaker_ends_the_game() {
+        let mut game = create_game(
+            vec![create_player("Alice", 0), create_player("Bob", 0)],
+            GameState::InLobby,
+        );
+        game.configuration.count_of_questions = 1;
+        game.game_state = create_new_game_state_playing(&mut game);
+
+        play_question(&mut game, &[true, true]);
+        skip_solution(&mut game);
+
+        assert!(matches!(
+            game.game_state,
+            GameState::Aftermath {
+                tiebreaker_winner: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn discarded_question_does_not_break_streaks() {
+        let mut game = create_game(
+            vec![
+                create_player("Alice", 0),
+                create_player("Bob", 0),
+                create_player("Carol", 0),
+            ],
+            GameState::InLobby,
+        );
+        game.game_state = create_new_game_state_playing(&mut game);
+
+        play_question(&mut game, &[true, true, false]);
+        skip_solution(&mut game);
+        let skip_votes: HashSet<crate::model::PlayerId> =
+            game.players[..2].iter().map(|player| player.id).collect();
+        if let GameState::Playing {
+            playing_state: PlayingState::Question { skip_request, .. },
+            ..
+        } = &mut game.game_state
+        {
+            *skip_request = skip_votes;
+        }
+        let _ = process_playing_update(&mut game);
+        assert_eq!(game.skipped_questions.len(), 1);
+        play_question(&mut game, &[true, false, true]);
+
+        assert_eq!(
+            game.players
+                .iter()
+                .map(|player| player.streak)
+                .collect::<Vec<_>>(),
+            vec![2, 0, 1]
+        );
+    }
+
+    #[tokio::test]
+    async fn vote_kicked_host_is_replaced() {
+        let mut game = create_game(
+            vec![
+                create_player("Alice", 0),
+                create_player("Bob", 0),
+                create_player("Carol", 0),
+            ],
+            GameState::InLobby,
+        );
+        let alice_id = game.players[0].id;
+        let voters: Vec<crate::model::Player> = game.players[1..].to_vec();
+
+        let (broadcast_sender, _broadcast_receiver) = tokio::sync::broadcast::channel(16);
+        let (callback, _callback_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let lobbies_storage = LobbiesStorage::default();
+        let reports_storage = ReportsStorage::default();
+
+        for voter in voters {
+            let _ = process_client_message(
+                ToLobbyMessage::ClientMessage {
+                    client_info: ClientInfo {
+                        callback: callback.clone(),
+                        player_id: voter.id,
+                        connection_id: voter.connection_id,
+                    },
+                    client_message: shared_model::network::ClientMessage::VoteKick(alice_id.into()),
+                },
+                &crate::model::InviteCode::generate(),
+                &mut game,
+                &broadcast_sender,
+                &lobbies_storage,
+                &reports_storage,
+            )
+            .await;
+        }
+
+        assert!(game.players.iter().all(|player| player.id != alice_id));
+        assert_eq!(game.host, Some(game.players[0].id));
+    }
+
+    #[test]
+    fn single_vote_does_not_kick_in_a_small_lobby() {
+        let players = vec![create_player("Alice", 0), create_player("Bob", 0)];
+        let voters = HashSet::from([players[1].id]);
+
+        assert!(!has_majority_for_kick(&players, players[0].id, &voters));
+    }
+
+    #[test]
+    fn disconnected_players_are_not_counted_for_a_kick() {
+        let mut players = vec![
+            create_player("Alice", 0),
+            create_player("Bob", 0),
+            create_player("Carol", 0),
+            create_player("Dave", 0),
+            create_player("Eve", 0),
+        ];
+        players[3].disconnected_since = Some(Utc::now());
+        players[4].disconnected_since = Some(Utc::now());
+        let voters = HashSet::from([players[1].id, players[2].id]);
+        assert!(has_majority_for_kick(&players, players[0].id, &voters));
+
+        // Votes of disconnected players don't count either
+        let voters = HashSet::from([players[1].id, players[3].id]);
+        assert!(!has_majority_for_kick(&players, players[0].id, &voters));
+    }
+
+    #[tokio::test]
+    async fn messages_of_a_kicked_player_are_ignored() {
+        let mut game = create_game(
+            vec![
+                create_player("Alice", 0),
+                create_player("Bob", 0),
+                create_player("Carol", 0),
+            ],
+            GameState::InLobby,
+        );
+        let alice_id = game.players[0].id;
+        let bob = game.players[1].clone();
+
+        let (broadcast_sender, _broadcast_receiver) = tokio::sync::broadcast::channel(16);
+        let (callback, _callback_receiver) = tokio::sync::mpsc::unbounded_channel();
+        let lobbies_storage = LobbiesStorage::default();
+        let reports_storage = ReportsStorage::default();
+
+        let _ = process_client_message(
+            ToLobbyMessage::ClientMessage {
+                client_info: ClientInfo {
+                    callback: callback.clone(),
+                    player_id: alice_id,
+                    connection_id: game.players[0].connection_id,
+                },
+                client_message: shared_model::network::ClientMessage::KickPlayer(bob.id.into()),
+            },
+            &crate::model::InviteCode::generate(),
+            &mut game,
+            &broadcast_sender,
+            &lobbies_storage,
+            &reports_storage,
+        )
+        .await;
+        assert!(game.players.iter().all(|player| player.id != bob.id));
+
+        let _ = process_client_message(
+            ToLobbyMessage::ClientMessage {
+                client_info: ClientInfo {
+                    callback,
+                    player_id: bob.id,
+                    connection_id: bob.connection_id,
+                },
+                client_message: shared_model::network::ClientMessage::VoteKick(alice_id.into()),
+            },
+            &crate::model::InviteCode::generate(),
+            &mut game,
+            &broadcast_sender,
+            &lobbies_storage,
+            &reports_storage,
+        )
+        .await;
+        assert!(game.kick_votes.is_empty());
+    }
+}
@@ -4,19 +4,31 @@ use actix_web::{web, Error, HttpRequest, HttpResponse};
 
 use crate::routes::game::client::{start_client_network_task, ClientType};
 use crate::routes::game::lobbies_storage::LobbiesStorage;
-use crate::routes::game::lobby::start_lobby_task;
+use crate::routes::game::lobby::{is_valid_configuration, start_lobby_task};
+use crate::routes::reports::ReportsStorage;
 
 pub mod client;
+pub mod drink_rules;
+pub mod elimination;
 pub mod from_lobby_message;
 pub mod lobbies_storage;
 pub mod lobby;
+pub mod scoring;
+pub mod streaks;
+pub mod teams;
+#[cfg(test)]
+mod test_utils;
+pub mod tiebreaker;
 pub mod to_lobby_message;
 
-#[tracing::instrument(name = "Create Lobby", skip(req, body, lobbies))]
+const DEFAULT_SOLUTION_TIME_IN_SECONDS: u64 = 30;
+
+#[tracing::instrument(name = "Create Lobby", skip(req, body, lobbies, reports))]
 pub async fn create_lobby(
     req: HttpRequest,
     body: web::Payload,
     lobbies: web::Data<LobbiesStorage>,
+    reports: web::Data<ReportsStorage>,
     query: web::Query<CreateLobbyQuery>,
 ) -> Result<HttpResponse, Error> {
     let CreateLobbyQuery {
@@ -25,32 +37,116 @@ pub async fn create_lobby(
         count_of_questions,
         minimum_score_per_question,
         maximum_answer_seconds_per_question: maximum_answer_time_per_question,
+        solution_seconds,
+        scoring_mode,
+        points_for_correct_answer,
+        points_for_minority_bonus,
+        minority_threshold_in_percent,
+        points_penalty_for_wrong_answer,
+        streak_length_for_bonus,
+        points_for_streak_bonus,
+        sips_for_wrong_answer,
+        sips_for_missing_answer,
+        sips_for_everyone_wrong,
+        sips_to_hand_out_for_minority_correct,
+        sips_for_broken_streak,
+        penalty_mode,
     } = query.into_inner();
 
-    let (response, session, msg_stream) = actix_ws::handle(&req, body)?;
+    let penalty_mode = penalty_mode
+        .as_deref()
+        .map(crate::model::PenaltyMode::from_str)
+        .transpose()
+        .map_err(actix_web::error::ErrorBadRequest)?
+        .unwrap_or_default();
 
-    let invite_code = start_lobby_task(
-        count_of_questions,
+    let scoring_mode = scoring_mode
+        .as_deref()
+        .map(crate::model::ScoringMode::from_str)
+        .transpose()
+        .map_err(actix_web::error::ErrorBadRequest)?
+        .unwrap_or_default();
+
+    let solution_time_in_seconds = solution_seconds.unwrap_or(DEFAULT_SOLUTION_TIME_IN_SECONDS);
+
+    let default_scoring_rules = crate::model::ScoringRules::default();
+    let scoring_rules = crate::model::ScoringRules {
+        points_for_correct_answer: points_for_correct_answer
+            .unwrap_or(default_scoring_rules.points_for_correct_answer),
+        points_for_minority_bonus: points_for_minority_bonus
+            .unwrap_or(default_scoring_rules.points_for_minority_bonus),
+        minority_threshold_in_percent: minority_threshold_in_percent
+            .unwrap_or(default_scoring_rules.minority_threshold_in_percent),
+        points_penalty_for_wrong_answer,
+        streak_length_for_bonus: streak_length_for_bonus
+            .unwrap_or(default_scoring_rules.streak_length_for_bonus),
+        points_for_streak_bonus: points_for_streak_bonus
+            .unwrap_or(default_scoring_rules.points_for_streak_bonus),
+    };
+
+    let default_drink_rules = crate::model::DrinkRules::default();
+    let drink_rules = crate::model::DrinkRules {
+        sips_for_wrong_answer: sips_for_wrong_answer
+            .unwrap_or(default_drink_rules.sips_for_wrong_answer),
+        sips_for_missing_answer: sips_for_missing_answer
+            .unwrap_or(default_drink_rules.sips_for_missing_answer),
+        sips_for_everyone_wrong: sips_for_everyone_wrong
+            .unwrap_or(default_drink_rules.sips_for_everyone_wrong),
+        sips_to_hand_out_for_minority_correct: sips_to_hand_out_for_minority_correct
+            .unwrap_or(default_drink_rules.sips_to_hand_out_for_minority_correct),
+        sips_for_broken_streak: sips_for_broken_streak
+            .unwrap_or(default_drink_rules.sips_for_broken_streak),
+    };
+
+    let configuration = crate::model::GameConfiguration {
+        count_of_questions: count_of_questions.unwrap_or_else(|| {
+            u64::try_from(crate::data::calculate_count_of_questions(
+                minimum_score_per_question,
+            ))
+            .unwrap()
+        }),
         minimum_score_per_question,
         maximum_answer_time_per_question,
+        solution_time_in_seconds,
+        scoring_mode,
+        scoring_rules,
+        drink_rules,
+        penalty_mode,
+        // Teams are set up by the host in the lobby, when everyone has joined
+        team_setup: None,
+        sudden_death_tiebreaker: false,
+        game_mode: crate::model::GameMode::default(),
+        marathon: false,
+    };
+    if !is_valid_configuration(&configuration) {
+        return Err(actix_web::error::ErrorBadRequest(format!(
+            "Invalid game configuration {configuration:?}"
+        )));
+    }
+
+    let (response, session, msg_stream) = actix_ws::handle(&req, body)?;
+
+    let invite_code = start_lobby_task(
+        configuration,
         LobbiesStorage::clone(&lobbies),
+        ReportsStorage::clone(&reports),
     )
     .await;
 
     tracing::info!(
         "Created Lobby \"{invite_code}\" by player \"{player_name}\" (just_watch:{just_watch}) with \
-        {count_of_questions:?} questions, {minimum_score_per_question:?} minimum score and \
-        {maximum_answer_time_per_question:?} maximum answer time"
+        {configuration:?}"
     );
 
     start_client_network_task(
-        crate::model::PlayerName::from_str(&player_name).unwrap(),
         invite_code.clone(),
-        just_watch,
         LobbiesStorage::clone(&lobbies),
         session,
         msg_stream,
-        ClientType::LobbyCreator,
+        ClientType::LobbyCreator {
+            player_name: crate::model::PlayerName::from_str(&player_name).unwrap(),
+            just_watch,
+        },
     )
     .await;
 
@@ -68,6 +164,20 @@ pub struct CreateLobbyQuery {
     count_of_questions: Option<u64>,
     minimum_score_per_question: Option<i64>,
     maximum_answer_seconds_per_question: Option<u64>,
+    solution_seconds: Option<u64>,
+    scoring_mode: Option<String>,
+    points_for_correct_answer: Option<u16>,
+    points_for_minority_bonus: Option<u16>,
+    minority_threshold_in_percent: Option<u8>,
+    points_penalty_for_wrong_answer: Option<u16>,
+    streak_length_for_bonus: Option<u8>,
+    points_for_streak_bonus: Option<u16>,
+    sips_for_wrong_answer: Option<u8>,
+    sips_for_missing_answer: Option<u8>,
+    sips_for_everyone_wrong: Option<u8>,
+    sips_to_hand_out_for_minority_correct: Option<u8>,
+    sips_for_broken_streak: Option<u8>,
+    penalty_mode: Option<String>,
 }
 
 #[tracing::instrument(name = "Join Lobby", skip(req, body, lobbies))]
@@ -87,13 +197,14 @@ pub async fn join_lobby(
     let (response, session, msg_stream) = actix_ws::handle(&req, body)?;
 
     start_client_network_task(
-        crate::model::PlayerName::from_str(&player_name).unwrap(),
         crate::model::InviteCode::from_str(&invite_code).unwrap(),
-        just_watch,
         LobbiesStorage::clone(&lobbies),
         session,
         msg_stream,
-        ClientType::LobbyJoiner,
+        ClientType::LobbyJoiner {
+            player_name: crate::model::PlayerName::from_str(&player_name).unwrap(),
+            just_watch,
+        },
     )
     .await;
 
@@ -109,3 +220,63 @@ pub struct JoinLobbyQuery {
     player_name: String,
     just_watch: bool,
 }
+
+#[tracing::instrument(name = "Rejoin Lobby", skip(req, body, lobbies))]
+pub async fn rejoin_lobby(
+    req: HttpRequest,
+    body: web::Payload,
+    lobbies: web::Data<LobbiesStorage>,
+    path: web::Path<String>,
+    query: web::Query<RejoinLobbyQuery>,
+) -> Result<HttpResponse, Error> {
+    let invite_code = crate::model::InviteCode::from_str(&path.into_inner())
+        .map_err(actix_web::error::ErrorBadRequest)?;
+    let session_token = crate::model::SessionToken::from_str(&query.into_inner().session_token)
+        .map_err(actix_web::error::ErrorBadRequest)?;
+
+    let (response, session, msg_stream) = actix_ws::handle(&req, body)?;
+
+    start_client_network_task(
+        invite_code.clone(),
+        LobbiesStorage::clone(&lobbies),
+        session,
+        msg_stream,
+        ClientType::LobbyRejoiner { session_token },
+    )
+    .await;
+
+    tracing::info!("Player tried rejoining lobby \"{invite_code}\"");
+
+    Ok(response)
+}
+
+#[derive(Debug, Clone, Hash, serde::Deserialize)]
+pub struct RejoinLobbyQuery {
+    session_token: String,
+}
+
+#[tracing::instrument(name = "Display Lobby", skip(req, body, lobbies))]
+pub async fn display_lobby(
+    req: HttpRequest,
+    body: web::Payload,
+    lobbies: web::Data<LobbiesStorage>,
+    path: web::Path<String>,
+) -> Result<HttpResponse, Error> {
+    let invite_code = crate::model::InviteCode::from_str(&path.into_inner())
+        .map_err(actix_web::error::ErrorBadRequest)?;
+
+    let (response, session, msg_stream) = actix_ws::handle(&req, body)?;
+
+    start_client_network_task(
+        invite_code.clone(),
+        LobbiesStorage::clone(&lobbies),
+        session,
+        msg_stream,
+        ClientType::Display,
+    )
+    .await;
+
+    tracing::info!("Display connected to lobby \"{invite_code}\"");
+
+    Ok(response)
+}
@@ -0,0 +1,436 @@
+use std::collections::HashMap;
+
+/// Everything a scoring strategy may look at, when a question is resolved.
+pub struct ScoringContext<'a> {
+    pub scoring_rules: &'a crate::model::ScoringRules,
+    pub players: &'a [crate::model::Player],
+    pub answers: &'a HashMap<crate::model::PlayerId, crate::model::Answer>,
+    pub response_times: &'a HashMap<crate::model::PlayerId, chrono::Duration>,
+    pub stakes: &'a HashMap<crate::model::PlayerId, u16>,
+    /// The streaks of the players before the resolved question.
+    pub streaks: &'a HashMap<crate::model::PlayerId, u16>,
+    pub correct_answer: crate::model::Answer,
+}
+
+pub trait ScoringStrategy {
+    /// Returns the change of points for every player with `PlayType::Player`,
+    /// whose points change with the resolved question.
+    fn score(&self, context: &ScoringContext) -> HashMap<crate::model::PlayerId, i32>;
+}
+
+/// Points for correct answers, a bonus for a correct minority, a bonus for correct players
+/// on fire and an optional penalty for wrong answers.
+pub struct ClassicScoring;
+
+impl ScoringStrategy for ClassicScoring {
+    fn score(&self, context: &ScoringContext) -> HashMap<crate::model::PlayerId, i32> {
+        without_unchanged(classic_point_changes(context))
+    }
+}
+
+/// Classic scoring, where correct players additionally get a bonus by the order of their answers.
+/// The fastest correct player gets the points for a correct answer once more,
+/// the following ones get a linearly decreasing share of it.
+pub struct SpeedBonusScoring;
+
+impl ScoringStrategy for SpeedBonusScoring {
+    fn score(&self, context: &ScoringContext) -> HashMap<crate::model::PlayerId, i32> {
+        let mut point_changes = classic_point_changes(context);
+
+        let mut correct_players_by_speed: Vec<(crate::model::PlayerId, chrono::Duration)> = context
+            .players
+            .iter()
+            .filter(|player| player.is_player())
+            .filter(|player| context.answers.get(&player.id) == Some(&context.correct_answer))
+            .filter_map(|player| {
+                context
+                    .response_times
+                    .get(&player.id)
+                    .map(|response_time| (player.id, *response_time))
+            })
+            .collect();
+        correct_players_by_speed.sort_by_key(|(_, response_time)| *response_time);
+
+        let count_of_correct_players = i32::try_from(correct_players_by_speed.len()).unwrap();
+        let maximum_speed_bonus = i32::from(context.scoring_rules.points_for_correct_answer);
+        correct_players_by_speed
+            .into_iter()
+            .zip(0..)
+            .for_each(|((player_id, _), rank)| {
+                *point_changes.entry(player_id).or_default() += maximum_speed_bonus
+                    * (count_of_correct_players - rank)
+                    / count_of_correct_players;
+            });
+
+        without_unchanged(point_changes)
+    }
+}
+
+/// Classic scoring, where players win their stake with a correct answer and lose it otherwise.
+/// A stake without an answer is lost as well, so that staking can't be undone by staying silent.
+pub struct ConfidenceBettingScoring;
+
+impl ScoringStrategy for ConfidenceBettingScoring {
+    fn score(&self, context: &ScoringContext) -> HashMap<crate::model::PlayerId, i32> {
+        let mut point_changes = classic_point_changes(context);
+
+        context
+            .players
+            .iter()
+            .filter(|player| player.is_player())
+            .for_each(|player| {
+                let stake = i32::from(context.stakes.get(&player.id).copied().unwrap_or(0));
+                match context.answers.get(&player.id) {
+                    Some(answer) if *answer == context.correct_answer => {
+                        *point_changes.entry(player.id).or_default() += stake;
+                    }
+                    Some(_) | None => *point_changes.entry(player.id).or_default() -= stake,
+                }
+            });
+
+        without_unchanged(point_changes)
+    }
+}
+
+#[must_use]
+pub fn select_scoring_strategy(
+    scoring_mode: crate::model::ScoringMode,
+) -> &'static dyn ScoringStrategy {
+    match scoring_mode {
+        crate::model::ScoringMode::Classic => &ClassicScoring,
+        crate::model::ScoringMode::SpeedBonus => &SpeedBonusScoring,
+        crate::model::ScoringMode::ConfidenceBetting => &ConfidenceBettingScoring,
+    }
+}
+
+/// Applies a change of points without falling below zero or overflowing.
+#[must_use]
+pub fn apply_point_change(points: u16, point_change: i32) -> u16 {
+    u16::try_from((i32::from(points) + point_change).max(0)).unwrap_or(u16::MAX)
+}
+
+fn classic_point_changes(context: &ScoringContext) -> HashMap<crate::model::PlayerId, i32> {
+    let participating_players: Vec<&crate::model::Player> = context
+        .players
+        .iter()
+        .filter(|player| player.is_player())
+        .collect();
+
+    let count_of_correct_players = participating_players
+        .iter()
+        .filter(|player| context.answers.get(&player.id) == Some(&context.correct_answer))
+        .count();
+    let are_correct_players_a_minority = context
+        .scoring_rules
+        .is_minority(count_of_correct_players, participating_players.len());
+
+    participating_players
+        .into_iter()
+        .map(|player| {
+            let point_change = match context.answers.get(&player.id) {
+                Some(answer) if *answer == context.correct_answer => {
+                    let minority_bonus = if are_correct_players_a_minority {
+                        context.scoring_rules.points_for_minority_bonus
+                    } else {
+                        0
+                    };
+                    let streak = context
+                        .streaks
+                        .get(&player.id)
+                        .copied()
+                        .unwrap_or(0)
+                        .saturating_add(1);
+                    let streak_bonus = if context.scoring_rules.is_on_fire(streak) {
+                        context.scoring_rules.points_for_streak_bonus
+                    } else {
+                        0
+                    };
+                    i32::from(context.scoring_rules.points_for_correct_answer)
+                        + i32::from(minority_bonus)
+                        + i32::from(streak_bonus)
+                }
+                Some(_) => -i32::from(
+                    context
+                        .scoring_rules
+                        .points_penalty_for_wrong_answer
+                        .unwrap_or(0),
+                ),
+                None => 0,
+            };
+            (player.id, point_change)
+        })
+        .collect()
+}
+
+fn without_unchanged(
+    point_changes: HashMap<crate::model::PlayerId, i32>,
+) -> HashMap<crate::model::PlayerId, i32> {
+    point_changes
+        .into_iter()
+        .filter(|(_, point_change)| *point_change != 0)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet, VecDeque};
+    use std::str::FromStr;
+
+    use chrono::{Duration, Utc};
+
+    use crate::model::{
+        Answer, AnsweredQuestion, ConnectionId, Game, GameConfiguration, GameMode, GameState,
+        PlayType, PlayedQuestion, Player, PlayerId, PlayerName, PlayingState, QuestionId,
+        ScoringMode, ScoringRules, SessionToken,
+    };
+    use crate::routes::game::lobby::process_playing_update;
+
+    fn create_player(name: &str, points: u16) -> Player {
+        Player {
+            id: PlayerId::generate(),
+            name: PlayerName::from_str(name).unwrap(),
+            play_type: PlayType::Player {
+                points,
+                sips_taken: 0,
+                sips_handed_out: 0,
+            },
+            session_token: SessionToken::generate(),
+            connection_id: ConnectionId::generate(),
+            disconnected_since: None,
+            team: None,
+            lives: None,
+            streak: 0,
+        }
+    }
+
+    /// Creates a game in `PlayingState::Question`, where every player has answered,
+    /// so that the next playing update switches to `PlayingState::Solution`.
+    fn create_answered_game(
+        scoring_mode: ScoringMode,
+        scoring_rules: ScoringRules,
+        players: Vec<Player>,
+        answers: Vec<(usize, Answer, i64, u16)>,
+    ) -> Game {
+        let mut question_answers = HashMap::new();
+        let mut response_times = HashMap::new();
+        let mut stakes = HashMap::new();
+        for (player_index, answer, answered_after_seconds, stake) in answers {
+            let player_id = players[player_index].id;
+            question_answers.insert(player_id, answer);
+            response_times.insert(player_id, Duration::seconds(answered_after_seconds));
+            if stake > 0 {
+                stakes.insert(player_id, stake);
+            }
+        }
+
+        Game {
+            configuration: GameConfiguration {
+                count_of_questions: 10,
+                minimum_score_per_question: None,
+                maximum_answer_time_per_question: None,
+                solution_time_in_seconds: 30,
+                scoring_mode,
+                scoring_rules,
+                drink_rules: Default::default(),
+                penalty_mode: Default::default(),
+                team_setup: None,
+                sudden_death_tiebreaker: false,
+                game_mode: GameMode::Classic,
+                marathon: false,
+            },
+            game_state: GameState::Playing {
+                previous_questions: Vec::new(),
+                current_question: AnsweredQuestion {
+                    question_id: QuestionId::generate(),
+                    answer: Answer::TheOnion,
+                },
+                playing_state: PlayingState::Question {
+                    shown_at: Utc::now(),
+                    time_until: None,
+                    answers: question_answers,
+                    response_times,
+                    stakes,
+                    skip_request: HashSet::new(),
+                },
+                eliminated_players: Vec::new(),
+            },
+            players,
+            host: None,
+            paused_at: None,
+            kick_votes: HashMap::new(),
+            blocked_names: HashSet::new(),
+            skipped_questions: HashSet::new(),
+            chat_history: VecDeque::new(),
+            last_chat_message_at: HashMap::new(),
+        }
+    }
+
+    fn resolve_and_collect_points(mut game: Game) -> Vec<u16> {
+        let _ = process_playing_update(&mut game);
+        assert!(matches!(
+            game.game_state,
+            GameState::Playing {
+                playing_state: PlayingState::Solution { .. },
+                ..
+            }
+        ));
+        game.players
+            .iter()
+            .map(|player| match player.play_type {
+                PlayType::Player { points, .. } => points,
+                PlayType::Watcher => panic!("Expected only players"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn classic_awards_points_for_correct_answers() {
+        let game = create_answered_game(
+            ScoringMode::Classic,
+            ScoringRules::default(),
+            vec![
+                create_player("Alice", 0),
+                create_player("Bob", 0),
+                create_player("Carol", 0),
+            ],
+            vec![
+                (0, Answer::TheOnion, 1, 0),
+                (1, Answer::NotTheOnion, 2, 0),
+                (2, Answer::TheOnion, 3, 0),
+            ],
+        );
+
+        assert_eq!(resolve_and_collect_points(game), vec![10, 0, 10]);
+    }
+
+    #[test]
+    fn classic_awards_minority_bonus_and_wrong_answer_penalty() {
+        let game = create_answered_game(
+            ScoringMode::Classic,
+            ScoringRules {
+                points_penalty_for_wrong_answer: Some(3),
+                ..ScoringRules::default()
+            },
+            vec![
+                create_player("Alice", 0),
+                create_player("Bob", 20),
+                create_player("Carol", 1),
+            ],
+            vec![
+                (0, Answer::TheOnion, 1, 0),
+                (1, Answer::NotTheOnion, 2, 0),
+                (2, Answer::NotTheOnion, 3, 0),
+            ],
+        );
+
+        assert_eq!(resolve_and_collect_points(game), vec![15, 17, 0]);
+    }
+
+    #[test]
+    fn classic_awards_streak_bonus_to_players_on_fire() {
+        let scoring_rules = ScoringRules {
+            points_for_streak_bonus: 5,
+            ..ScoringRules::default()
+        };
+        let players = vec![create_player("Alice", 20), create_player("Bob", 10)];
+        let played_questions = (0..2)
+            .map(|_| PlayedQuestion {
+                answered_question: AnsweredQuestion {
+                    question_id: QuestionId::generate(),
+                    answer: Answer::NotTheOnion,
+                },
+                answers: HashMap::from([
+                    (players[0].id, Answer::NotTheOnion),
+                    (players[1].id, Answer::TheOnion),
+                ]),
+                response_times: HashMap::new(),
+            })
+            .collect();
+        let mut game = create_answered_game(
+            ScoringMode::Classic,
+            scoring_rules,
+            players,
+            vec![(0, Answer::TheOnion, 1, 0), (1, Answer::NotTheOnion, 2, 0)],
+        );
+        if let GameState::Playing {
+            previous_questions, ..
+        } = &mut game.game_state
+        {
+            *previous_questions = played_questions;
+        }
+
+        // Streaks are opt-in, so the default rules don't award any bonus
+        let mut game_with_default_rules = game.clone();
+        game_with_default_rules.configuration.scoring_rules = ScoringRules::default();
+        assert_eq!(
+            resolve_and_collect_points(game_with_default_rules),
+            vec![30, 10]
+        );
+
+        assert_eq!(resolve_and_collect_points(game), vec![35, 10]);
+    }
+
+    #[test]
+    fn speed_bonus_rewards_faster_correct_answers() {
+        let game = create_answered_game(
+            ScoringMode::SpeedBonus,
+            ScoringRules::default(),
+            vec![
+                create_player("Alice", 0),
+                create_player("Bob", 0),
+                create_player("Carol", 0),
+            ],
+            vec![
+                (0, Answer::TheOnion, 5, 0),
+                (1, Answer::NotTheOnion, 1, 0),
+                (2, Answer::TheOnion, 2, 0),
+            ],
+        );
+
+        assert_eq!(resolve_and_collect_points(game), vec![15, 0, 20]);
+    }
+
+    #[test]
+    fn confidence_betting_wins_and_loses_stakes() {
+        let game = create_answered_game(
+            ScoringMode::ConfidenceBetting,
+            ScoringRules::default(),
+            vec![
+                create_player("Alice", 20),
+                create_player("Bob", 20),
+                create_player("Carol", 20),
+            ],
+            vec![
+                (0, Answer::TheOnion, 1, 8),
+                (1, Answer::NotTheOnion, 2, 15),
+                (2, Answer::TheOnion, 3, 0),
+            ],
+        );
+
+        assert_eq!(resolve_and_collect_points(game), vec![38, 5, 30]);
+    }
+
+    #[test]
+    fn confidence_betting_loses_stakes_without_answer() {
+        let mut game = create_answered_game(
+            ScoringMode::ConfidenceBetting,
+            ScoringRules::default(),
+            vec![create_player("Alice", 20), create_player("Bob", 20)],
+            vec![(0, Answer::TheOnion, 1, 0)],
+        );
+        let bob_id = game.players[1].id;
+        if let GameState::Playing {
+            playing_state:
+                PlayingState::Question {
+                    time_until, stakes, ..
+                },
+            ..
+        } = &mut game.game_state
+        {
+            stakes.insert(bob_id, 12);
+            *time_until = Some(Utc::now() - Duration::seconds(1));
+        }
+
+        assert_eq!(resolve_and_collect_points(game), vec![30, 8]);
+    }
+}
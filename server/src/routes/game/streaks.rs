@@ -0,0 +1,256 @@
+use std::collections::{HashMap, HashSet};
+
+/// Counts the latest played questions in a row, which the player has answered correctly.
+#[must_use]
+pub fn calculate_streak(
+    player_id: crate::model::PlayerId,
+    played_questions: &[crate::model::PlayedQuestion],
+) -> u16 {
+    let streak = played_questions
+        .iter()
+        .rev()
+        .take_while(|played_question| {
+            played_question.answers.get(&player_id)
+                == Some(&played_question.answered_question.answer)
+        })
+        .count();
+    u16::try_from(streak).unwrap_or(u16::MAX)
+}
+
+/// The streaks of all players with `PlayType::Player` before the current question.
+#[must_use]
+pub fn calculate_streaks(
+    players: &[crate::model::Player],
+    played_questions: &[crate::model::PlayedQuestion],
+) -> HashMap<crate::model::PlayerId, u16> {
+    players
+        .iter()
+        .filter(|player| player.is_player())
+        .map(|player| (player.id, calculate_streak(player.id, played_questions)))
+        .collect()
+}
+
+/// The players, who were on fire before the current question and haven't answered it correctly.
+#[must_use]
+pub fn find_broken_streaks(
+    scoring_rules: &crate::model::ScoringRules,
+    streaks: &HashMap<crate::model::PlayerId, u16>,
+    answers: &HashMap<crate::model::PlayerId, crate::model::Answer>,
+    correct_answer: crate::model::Answer,
+) -> HashSet<crate::model::PlayerId> {
+    streaks
+        .iter()
+        .filter(|(_, streak)| scoring_rules.is_on_fire(**streak))
+        .filter(|(player_id, _)| answers.get(player_id) != Some(&correct_answer))
+        .map(|(player_id, _)| *player_id)
+        .collect()
+}
+
+/// Continues the streak of every player with `PlayType::Player` with a correct answer
+/// and ends it otherwise.
+pub fn update_streaks(
+    players: &mut [crate::model::Player],
+    streaks: &HashMap<crate::model::PlayerId, u16>,
+    answers: &HashMap<crate::model::PlayerId, crate::model::Answer>,
+    correct_answer: crate::model::Answer,
+) {
+    players
+        .iter_mut()
+        .filter(|player| player.is_player())
+        .for_each(|player| {
+            player.streak = if answers.get(&player.id) == Some(&correct_answer) {
+                streaks
+                    .get(&player.id)
+                    .copied()
+                    .unwrap_or(0)
+                    .saturating_add(1)
+            } else {
+                0
+            };
+        });
+}
+
+/// Lets every player start the new game without a streak.
+pub fn reset_streaks(players: &mut [crate::model::Player]) {
+    players.iter_mut().for_each(|player| player.streak = 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{
+        Answer, AnsweredQuestion, DrinkReason, DrinkRules, PenaltyMode, PlayType, PlayedQuestion,
+        Player, PlayerId, QuestionId, ScoringRules,
+    };
+    use crate::routes::game::drink_rules::evaluate_drink_rules;
+    use crate::routes::game::test_utils::create_player;
+
+    use super::*;
+
+    /// Scoring rules with streaks turned on, where two correct answers in a row are on fire.
+    fn create_streak_scoring_rules() -> ScoringRules {
+        ScoringRules {
+            streak_length_for_bonus: 2,
+            points_for_streak_bonus: 5,
+            ..ScoringRules::default()
+        }
+    }
+
+    /// Creates played questions with the correct answer `Answer::TheOnion`,
+    /// where the player has given the answers; `None` is a question left unanswered.
+    fn create_played_questions(
+        player_id: PlayerId,
+        answers: &[Option<Answer>],
+    ) -> Vec<PlayedQuestion> {
+        answers
+            .iter()
+            .map(|answer| PlayedQuestion {
+                answered_question: AnsweredQuestion {
+                    question_id: QuestionId::generate(),
+                    answer: Answer::TheOnion,
+                },
+                answers: answer
+                    .map(|answer| HashMap::from([(player_id, answer)]))
+                    .unwrap_or_default(),
+                response_times: HashMap::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn streak_counts_the_latest_correct_answers_in_a_row() {
+        let player = create_player("Alice", 0);
+        let played_questions = create_played_questions(
+            player.id,
+            &[
+                Some(Answer::TheOnion),
+                Some(Answer::NotTheOnion),
+                Some(Answer::TheOnion),
+                Some(Answer::TheOnion),
+            ],
+        );
+
+        assert_eq!(calculate_streak(player.id, &played_questions), 2);
+        assert_eq!(calculate_streak(player.id, &[]), 0);
+    }
+
+    #[test]
+    fn unanswered_question_breaks_the_streak() {
+        let player = create_player("Alice", 0);
+        let played_questions = create_played_questions(
+            player.id,
+            &[Some(Answer::TheOnion), Some(Answer::TheOnion), None],
+        );
+
+        assert_eq!(calculate_streak(player.id, &played_questions), 0);
+    }
+
+    #[test]
+    fn watchers_have_no_streaks() {
+        let mut players = vec![create_player("Alice", 0), create_player("Bob", 0)];
+        players[1].play_type = PlayType::Watcher;
+        let played_questions = create_played_questions(players[0].id, &[Some(Answer::TheOnion)]);
+
+        assert_eq!(
+            calculate_streaks(&players, &played_questions),
+            HashMap::from([(players[0].id, 1)])
+        );
+    }
+
+    #[test]
+    fn only_streaks_on_fire_are_broken_by_wrong_or_missing_answers() {
+        let players: Vec<Player> = ["Alice", "Bob", "Carol", "Dave"]
+            .into_iter()
+            .map(|name| create_player(name, 0))
+            .collect();
+        let streaks = HashMap::from([
+            (players[0].id, 3),
+            (players[1].id, 2),
+            (players[2].id, 2),
+            (players[3].id, 1),
+        ]);
+        let answers = HashMap::from([
+            (players[0].id, Answer::NotTheOnion),
+            (players[2].id, Answer::TheOnion),
+            (players[3].id, Answer::NotTheOnion),
+        ]);
+
+        assert_eq!(
+            find_broken_streaks(
+                &create_streak_scoring_rules(),
+                &streaks,
+                &answers,
+                Answer::TheOnion
+            ),
+            HashSet::from([players[0].id, players[1].id])
+        );
+        // Without streak bonus points, nobody is on fire
+        assert!(find_broken_streaks(
+            &ScoringRules::default(),
+            &streaks,
+            &answers,
+            Answer::TheOnion
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn broken_streak_costs_its_sips() {
+        let players = vec![create_player("Alice", 0), create_player("Bob", 0)];
+        let streaks = HashMap::from([(players[0].id, 2), (players[1].id, 0)]);
+        // Alice leaves the question unanswered, Bob answers correctly
+        let answers = HashMap::from([(players[1].id, Answer::TheOnion)]);
+        let scoring_rules = create_streak_scoring_rules();
+        let drink_rules = DrinkRules {
+            sips_for_broken_streak: 3,
+            ..DrinkRules::default()
+        };
+
+        let broken_streaks =
+            find_broken_streaks(&scoring_rules, &streaks, &answers, Answer::TheOnion);
+        let drinks = evaluate_drink_rules(
+            &drink_rules,
+            &scoring_rules,
+            PenaltyMode::Alcohol,
+            &players,
+            &answers,
+            &broken_streaks,
+            Answer::TheOnion,
+        );
+
+        let alice_drinks = &drinks[&players[0].id];
+        assert_eq!(
+            alice_drinks.sips,
+            u16::from(drink_rules.sips_for_missing_answer + drink_rules.sips_for_broken_streak)
+        );
+        assert_eq!(
+            alice_drinks.reasons,
+            vec![DrinkReason::MissingAnswer, DrinkReason::StreakBroken]
+        );
+        assert!(!drinks.contains_key(&players[1].id));
+    }
+
+    #[test]
+    fn streaks_continue_with_correct_answers_and_end_otherwise() {
+        let mut players: Vec<Player> = ["Alice", "Bob", "Carol", "Dave"]
+            .into_iter()
+            .map(|name| create_player(name, 0))
+            .collect();
+        players[3].play_type = PlayType::Watcher;
+        players[3].streak = 4;
+        let streaks = HashMap::from([(players[0].id, 2), (players[1].id, 2), (players[2].id, 2)]);
+        let answers = HashMap::from([
+            (players[0].id, Answer::TheOnion),
+            (players[1].id, Answer::NotTheOnion),
+        ]);
+
+        update_streaks(&mut players, &streaks, &answers, Answer::TheOnion);
+
+        assert_eq!(
+            players
+                .iter()
+                .map(|player| player.streak)
+                .collect::<Vec<_>>(),
+            vec![3, 0, 0, 4]
+        );
+    }
+}
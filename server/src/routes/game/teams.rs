@@ -0,0 +1,413 @@
+use std::collections::HashMap;
+
+/// Shuffles all players with `PlayType::Player` evenly into the teams of the team setup.
+/// Without a team setup and for watchers the team is removed.
+pub fn balance_teams(
+    players: &mut [crate::model::Player],
+    team_setup: Option<crate::model::TeamSetup>,
+) {
+    use rand::seq::SliceRandom;
+
+    players.iter_mut().for_each(|player| player.team = None);
+
+    if let Some(team_setup) = team_setup {
+        let mut participating_players: Vec<&mut crate::model::Player> = players
+            .iter_mut()
+            .filter(|player| player.is_player())
+            .collect();
+        participating_players.shuffle(&mut rand::thread_rng());
+
+        participating_players
+            .into_iter()
+            .zip(team_setup.teams().cycle())
+            .for_each(|(player, team)| player.team = Some(team));
+    }
+}
+
+/// Puts a player with `PlayType::Player` without a team into the smallest team, so that players
+/// joining or switching their play type don't end up outside of the team setup.
+pub fn assign_to_smallest_team(
+    players: &mut [crate::model::Player],
+    player_id: crate::model::PlayerId,
+    team_setup: Option<crate::model::TeamSetup>,
+) {
+    let Some(team_setup) = team_setup else {
+        return;
+    };
+
+    let smallest_team = team_setup.teams().min_by_key(|team| {
+        players
+            .iter()
+            .filter(|player| player.is_player() && player.team == Some(*team))
+            .count()
+    });
+
+    if let Some(player) = players
+        .iter_mut()
+        .find(|player| player.id == player_id && player.is_player() && player.team.is_none())
+    {
+        player.team = smallest_team;
+    }
+}
+
+/// The captain of a team is its first connected member with `PlayType::Player`,
+/// so that a team doesn't wait for the answer of a disconnected captain.
+#[must_use]
+pub fn find_team_captain(
+    players: &[crate::model::Player],
+    team: crate::model::TeamId,
+) -> Option<crate::model::PlayerId> {
+    players
+        .iter()
+        .find(|player| {
+            player.is_player() && player.team == Some(team) && player.disconnected_since.is_none()
+        })
+        .map(|player| player.id)
+}
+
+/// Whether every connected player with `PlayType::Player` has an answer, which will count.
+/// With `TeamAnswerMode::Captain` a team member only waits for the answer of their captain.
+/// Players, who have lost their connection, aren't waited for; without any connected player
+/// the question isn't answered early.
+#[must_use]
+pub fn have_all_players_answered(
+    team_setup: Option<crate::model::TeamSetup>,
+    players: &[crate::model::Player],
+    answers: &HashMap<crate::model::PlayerId, crate::model::Answer>,
+) -> bool {
+    let mut connected_players = players
+        .iter()
+        .filter(|player| player.is_player() && player.disconnected_since.is_none())
+        .peekable();
+    connected_players.peek().is_some()
+        && connected_players.all(|player| match (team_setup, player.team) {
+            (
+                Some(crate::model::TeamSetup {
+                    team_answer_mode: crate::model::TeamAnswerMode::Captain,
+                    ..
+                }),
+                Some(team),
+            ) => find_team_captain(players, team)
+                .map_or(false, |captain| answers.contains_key(&captain)),
+            _ => answers.contains_key(&player.id),
+        })
+}
+
+/// Replaces the answers and response times of all team members with the ones of their team,
+/// so that points and drinks are settled per team.
+///
+/// With `TeamAnswerMode::MajorityVote` the most chosen answer wins; a tie is broken
+/// by the captain's answer or leaves the team without an answer, if the captain hasn't voted
+/// for one of the tied answers. The team's response time is the one of its slowest vote
+/// for the team answer.
+/// With `TeamAnswerMode::Captain` only the captain's answer and response time count.
+/// Without a team setup and for players without a team nothing is replaced.
+#[must_use]
+pub fn resolve_team_answers(
+    team_setup: Option<crate::model::TeamSetup>,
+    players: &[crate::model::Player],
+    answers: &HashMap<crate::model::PlayerId, crate::model::Answer>,
+    response_times: &HashMap<crate::model::PlayerId, chrono::Duration>,
+) -> (
+    HashMap<crate::model::PlayerId, crate::model::Answer>,
+    HashMap<crate::model::PlayerId, chrono::Duration>,
+) {
+    let Some(team_setup) = team_setup else {
+        return (answers.clone(), response_times.clone());
+    };
+
+    let mut team_answers = answers.clone();
+    let mut team_response_times = response_times.clone();
+
+    for team in team_setup.teams() {
+        let team_members: Vec<crate::model::PlayerId> = players
+            .iter()
+            .filter(|player| player.is_player() && player.team == Some(team))
+            .map(|player| player.id)
+            .collect();
+        let captain = find_team_captain(players, team);
+
+        let team_answer = match team_setup.team_answer_mode {
+            crate::model::TeamAnswerMode::MajorityVote => {
+                find_majority_answer(&team_members, captain, answers)
+            }
+            crate::model::TeamAnswerMode::Captain => {
+                captain.and_then(|captain| answers.get(&captain).copied())
+            }
+        };
+        let team_response_time =
+            team_answer.and_then(|team_answer| match team_setup.team_answer_mode {
+                crate::model::TeamAnswerMode::MajorityVote => team_members
+                    .iter()
+                    .filter(|member| answers.get(member) == Some(&team_answer))
+                    .filter_map(|member| response_times.get(member))
+                    .max()
+                    .copied(),
+                crate::model::TeamAnswerMode::Captain => {
+                    captain.and_then(|captain| response_times.get(&captain).copied())
+                }
+            });
+
+        for member in team_members {
+            match team_answer {
+                Some(team_answer) => team_answers.insert(member, team_answer),
+                None => team_answers.remove(&member),
+            };
+            match team_response_time {
+                Some(team_response_time) => team_response_times.insert(member, team_response_time),
+                None => team_response_times.remove(&member),
+            };
+        }
+    }
+
+    (team_answers, team_response_times)
+}
+
+fn find_majority_answer(
+    team_members: &[crate::model::PlayerId],
+    captain: Option<crate::model::PlayerId>,
+    answers: &HashMap<crate::model::PlayerId, crate::model::Answer>,
+) -> Option<crate::model::Answer> {
+    let count_votes = |answer: crate::model::Answer| {
+        team_members
+            .iter()
+            .filter(|member| answers.get(member) == Some(&answer))
+            .count()
+    };
+    let votes_for_the_onion = count_votes(crate::model::Answer::TheOnion);
+    let votes_for_not_the_onion = count_votes(crate::model::Answer::NotTheOnion);
+
+    match votes_for_the_onion.cmp(&votes_for_not_the_onion) {
+        std::cmp::Ordering::Greater => Some(crate::model::Answer::TheOnion),
+        std::cmp::Ordering::Less => Some(crate::model::Answer::NotTheOnion),
+        std::cmp::Ordering::Equal if votes_for_the_onion == 0 => None,
+        std::cmp::Ordering::Equal => captain.and_then(|captain| answers.get(&captain).copied()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use chrono::{Duration, Utc};
+
+    use crate::model::{
+        Answer, AnsweredQuestion, GameState, PlayType, Player, PlayerId, PlayingState, QuestionId,
+        TeamAnswerMode, TeamId, TeamSetup,
+    };
+    use crate::routes::game::lobby::process_playing_update;
+    use crate::routes::game::test_utils::{create_game, create_player};
+
+    use super::*;
+
+    fn create_team_setup(team_answer_mode: TeamAnswerMode) -> TeamSetup {
+        TeamSetup {
+            count_of_teams: 2,
+            team_answer_mode,
+        }
+    }
+
+    /// Creates players named after their index, which are in the given teams.
+    fn create_team_players(teams: &[u8]) -> Vec<Player> {
+        teams
+            .iter()
+            .enumerate()
+            .map(|(index, team)| {
+                let mut player = create_player(&format!("Player {index}"), 0);
+                player.team = Some(TeamId(*team));
+                player
+            })
+            .collect()
+    }
+
+    fn create_answers(
+        players: &[Player],
+        answers: &[(usize, Answer, i64)],
+    ) -> (
+        HashMap<PlayerId, Answer>,
+        HashMap<PlayerId, chrono::Duration>,
+    ) {
+        answers
+            .iter()
+            .map(|(player_index, answer, answered_after_seconds)| {
+                let player_id = players[*player_index].id;
+                (
+                    (player_id, *answer),
+                    (player_id, Duration::seconds(*answered_after_seconds)),
+                )
+            })
+            .unzip()
+    }
+
+    #[test]
+    fn majority_vote_gives_every_team_member_the_most_chosen_answer() {
+        let players = create_team_players(&[0, 0, 0, 1]);
+        let (answers, response_times) = create_answers(
+            &players,
+            &[
+                (0, Answer::TheOnion, 3),
+                (1, Answer::TheOnion, 5),
+                (2, Answer::NotTheOnion, 1),
+                (3, Answer::NotTheOnion, 2),
+            ],
+        );
+
+        let (team_answers, team_response_times) = resolve_team_answers(
+            Some(create_team_setup(TeamAnswerMode::MajorityVote)),
+            &players,
+            &answers,
+            &response_times,
+        );
+
+        for player in &players[..3] {
+            assert_eq!(team_answers.get(&player.id), Some(&Answer::TheOnion));
+            // The team has answered with its slowest vote for the team answer
+            assert_eq!(
+                team_response_times.get(&player.id),
+                Some(&Duration::seconds(5))
+            );
+        }
+        assert_eq!(team_answers.get(&players[3].id), Some(&Answer::NotTheOnion));
+    }
+
+    #[test]
+    fn majority_vote_tie_is_broken_by_the_captain() {
+        let players = create_team_players(&[0, 0, 1, 1, 1]);
+        let (answers, response_times) = create_answers(
+            &players,
+            &[
+                (0, Answer::NotTheOnion, 1),
+                (1, Answer::TheOnion, 2),
+                // The captain of the second team hasn't voted for one of the tied answers
+                (3, Answer::TheOnion, 3),
+                (4, Answer::NotTheOnion, 4),
+            ],
+        );
+
+        let (team_answers, team_response_times) = resolve_team_answers(
+            Some(create_team_setup(TeamAnswerMode::MajorityVote)),
+            &players,
+            &answers,
+            &response_times,
+        );
+
+        assert_eq!(team_answers.get(&players[0].id), Some(&Answer::NotTheOnion));
+        assert_eq!(team_answers.get(&players[1].id), Some(&Answer::NotTheOnion));
+        for player in &players[2..] {
+            assert_eq!(team_answers.get(&player.id), None);
+            assert_eq!(team_response_times.get(&player.id), None);
+        }
+    }
+
+    #[test]
+    fn captain_answers_for_the_team() {
+        let players = create_team_players(&[0, 0, 1, 1]);
+        let (answers, response_times) = create_answers(
+            &players,
+            &[
+                (0, Answer::TheOnion, 4),
+                (1, Answer::NotTheOnion, 1),
+                (3, Answer::TheOnion, 2),
+            ],
+        );
+        let team_setup = Some(create_team_setup(TeamAnswerMode::Captain));
+
+        // The second team waits for its captain
+        assert!(!have_all_players_answered(team_setup, &players, &answers));
+
+        let (team_answers, team_response_times) =
+            resolve_team_answers(team_setup, &players, &answers, &response_times);
+
+        for player in &players[..2] {
+            assert_eq!(team_answers.get(&player.id), Some(&Answer::TheOnion));
+            assert_eq!(
+                team_response_times.get(&player.id),
+                Some(&Duration::seconds(4))
+            );
+        }
+        for player in &players[2..] {
+            assert_eq!(team_answers.get(&player.id), None);
+        }
+    }
+
+    #[test]
+    fn disconnected_captain_is_replaced_by_the_next_connected_member() {
+        let mut players = create_team_players(&[0, 0, 1]);
+        players[0].disconnected_since = Some(Utc::now());
+        let (answers, response_times) = create_answers(
+            &players,
+            &[(1, Answer::NotTheOnion, 1), (2, Answer::TheOnion, 2)],
+        );
+        let team_setup = Some(create_team_setup(TeamAnswerMode::Captain));
+
+        assert_eq!(find_team_captain(&players, TeamId(0)), Some(players[1].id));
+        assert!(have_all_players_answered(team_setup, &players, &answers));
+
+        let (team_answers, _) =
+            resolve_team_answers(team_setup, &players, &answers, &response_times);
+
+        assert_eq!(team_answers.get(&players[0].id), Some(&Answer::NotTheOnion));
+        assert_eq!(team_answers.get(&players[1].id), Some(&Answer::NotTheOnion));
+    }
+
+    #[test]
+    fn points_and_drinks_are_settled_per_team() {
+        let players = create_team_players(&[0, 0, 0, 1, 1]);
+        let (answers, response_times) = create_answers(
+            &players,
+            &[
+                (0, Answer::TheOnion, 1),
+                (1, Answer::TheOnion, 2),
+                (2, Answer::NotTheOnion, 3),
+                (3, Answer::NotTheOnion, 4),
+                (4, Answer::NotTheOnion, 5),
+            ],
+        );
+        let mut game = create_game(
+            players,
+            GameState::Playing {
+                previous_questions: Vec::new(),
+                current_question: AnsweredQuestion {
+                    question_id: QuestionId::generate(),
+                    answer: Answer::TheOnion,
+                },
+                playing_state: PlayingState::Question {
+                    shown_at: Utc::now(),
+                    time_until: None,
+                    answers,
+                    response_times,
+                    stakes: HashMap::new(),
+                    skip_request: HashSet::new(),
+                },
+                eliminated_players: Vec::new(),
+            },
+        );
+        game.configuration.team_setup = Some(create_team_setup(TeamAnswerMode::MajorityVote));
+
+        let _ = process_playing_update(&mut game);
+
+        let points: Vec<u16> = game
+            .players
+            .iter()
+            .map(|player| match player.play_type {
+                PlayType::Player { points, .. } => points,
+                PlayType::Watcher => panic!("Expected only players"),
+            })
+            .collect();
+        assert_eq!(points, vec![10, 10, 10, 0, 0]);
+        let GameState::Playing {
+            playing_state: PlayingState::Solution { drinks, .. },
+            ..
+        } = &game.game_state
+        else {
+            panic!("Expected the solution after every player has answered");
+        };
+        let player_ids: Vec<PlayerId> = game.players.iter().map(|player| player.id).collect();
+        assert!(player_ids[..3]
+            .iter()
+            .all(|player_id| !drinks.contains_key(player_id)));
+        assert!(player_ids[3..].iter().all(|player_id| drinks
+            .get(player_id)
+            .map_or(false, |drinks| drinks.sips > 0)));
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
+
+use crate::model::{
+    ConnectionId, Game, GameConfiguration, GameMode, GameState, PlayType, Player, PlayerId,
+    PlayerName, ScoringMode, SessionToken,
+};
+
+pub fn create_player(name: &str, points: u16) -> Player {
+    Player {
+        id: PlayerId::generate(),
+        name: PlayerName::from_str(name).unwrap(),
+        play_type: PlayType::Player {
+            points,
+            sips_taken: 0,
+            sips_handed_out: 0,
+        },
+        session_token: SessionToken::generate(),
+        connection_id: ConnectionId::generate(),
+        disconnected_since: None,
+        team: None,
+        lives: None,
+        streak: 0,
+    }
+}
+
+/// Creates a classic game with the default rules, where the first player is the host.
+pub fn create_game(players: Vec<Player>, game_state: GameState) -> Game {
+    Game {
+        configuration: GameConfiguration {
+            count_of_questions: 10,
+            minimum_score_per_question: None,
+            maximum_answer_time_per_question: None,
+            solution_time_in_seconds: 30,
+            scoring_mode: ScoringMode::Classic,
+            scoring_rules: Default::default(),
+            drink_rules: Default::default(),
+            penalty_mode: Default::default(),
+            team_setup: None,
+            sudden_death_tiebreaker: false,
+            game_mode: GameMode::Classic,
+            marathon: false,
+        },
+        game_state,
+        host: players.first().map(|player| player.id),
+        players,
+        paused_at: None,
+        kick_votes: HashMap::new(),
+        blocked_names: HashSet::new(),
+        skipped_questions: HashSet::new(),
+        chat_history: VecDeque::new(),
+        last_chat_message_at: HashMap::new(),
+    }
+}
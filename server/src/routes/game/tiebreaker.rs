@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+/// The players with `PlayType::Player` sharing the most points, if a sudden-death tiebreaker
+/// has to decide between them. It's empty, if the tiebreaker is disabled, there is no tie,
+/// teams are playing, because team members always share their points, or in game modes
+/// with lives, where all remaining players share the win.
+#[must_use]
+pub fn find_tied_top_players(
+    configuration: &crate::model::GameConfiguration,
+    players: &[crate::model::Player],
+) -> Vec<crate::model::PlayerId> {
+    if !configuration.sudden_death_tiebreaker
+        || configuration.team_setup.is_some()
+        || configuration.game_mode.lives_per_player().is_some()
+    {
+        return Vec::new();
+    }
+
+    let points_of_players: Vec<(crate::model::PlayerId, u16)> = players
+        .iter()
+        .filter_map(|player| match player.play_type {
+            crate::model::PlayType::Player { points, .. } => Some((player.id, points)),
+            crate::model::PlayType::Watcher => None,
+        })
+        .collect();
+    let Some(maximum_points) = points_of_players.iter().map(|(_, points)| *points).max() else {
+        return Vec::new();
+    };
+
+    let top_players: Vec<crate::model::PlayerId> = points_of_players
+        .into_iter()
+        .filter(|(_, points)| *points == maximum_points)
+        .map(|(player_id, _)| player_id)
+        .collect();
+    if top_players.len() < 2 {
+        Vec::new()
+    } else {
+        top_players
+    }
+}
+
+/// Moves every contender without the correct answer to the eliminated ones.
+/// If no contender has answered correctly, all of them stay in the tiebreaker.
+pub fn eliminate_contenders(
+    contenders: &mut Vec<crate::model::PlayerId>,
+    eliminated: &mut Vec<crate::model::PlayerId>,
+    answers: &HashMap<crate::model::PlayerId, crate::model::Answer>,
+    correct_answer: crate::model::Answer,
+) {
+    let (correct_contenders, wrong_contenders): (Vec<_>, Vec<_>) = contenders
+        .iter()
+        .partition(|contender| answers.get(contender) == Some(&correct_answer));
+
+    if !correct_contenders.is_empty() {
+        *contenders = correct_contenders;
+        eliminated.extend(wrong_contenders);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::{Answer, GameMode, GameState, PlayType, Player, TeamAnswerMode, TeamSetup};
+    use crate::routes::game::test_utils::{create_game, create_player};
+
+    use super::*;
+
+    fn create_players(points: &[u16]) -> Vec<Player> {
+        points
+            .iter()
+            .enumerate()
+            .map(|(index, points)| create_player(&format!("Player {index}"), *points))
+            .collect()
+    }
+
+    fn create_tiebreaker_configuration() -> crate::model::GameConfiguration {
+        let mut configuration = create_game(Vec::new(), GameState::InLobby).configuration;
+        configuration.sudden_death_tiebreaker = true;
+        configuration
+    }
+
+    #[test]
+    fn players_tied_on_the_first_place_are_found() {
+        let players = create_players(&[30, 20, 30]);
+
+        assert_eq!(
+            find_tied_top_players(&create_tiebreaker_configuration(), &players),
+            vec![players[0].id, players[2].id]
+        );
+    }
+
+    #[test]
+    fn single_leader_or_tie_below_the_first_place_needs_no_tiebreaker() {
+        let configuration = create_tiebreaker_configuration();
+
+        assert!(find_tied_top_players(&configuration, &create_players(&[30, 20, 20])).is_empty());
+        assert!(find_tied_top_players(&configuration, &create_players(&[30])).is_empty());
+        assert!(find_tied_top_players(&configuration, &[]).is_empty());
+    }
+
+    #[test]
+    fn watchers_are_no_contenders() {
+        let mut players = create_players(&[30, 30, 30]);
+        players[1].play_type = PlayType::Watcher;
+
+        assert_eq!(
+            find_tied_top_players(&create_tiebreaker_configuration(), &players),
+            vec![players[0].id, players[2].id]
+        );
+    }
+
+    #[test]
+    fn tiebreaker_is_disabled_by_configuration_teams_and_lives() {
+        let players = create_players(&[30, 30]);
+        let configuration = create_tiebreaker_configuration();
+
+        let disabled_configurations = [
+            crate::model::GameConfiguration {
+                sudden_death_tiebreaker: false,
+                ..configuration
+            },
+            crate::model::GameConfiguration {
+                team_setup: Some(TeamSetup {
+                    count_of_teams: 2,
+                    team_answer_mode: TeamAnswerMode::MajorityVote,
+                }),
+                ..configuration
+            },
+            crate::model::GameConfiguration {
+                game_mode: GameMode::Lives { count_of_lives: 3 },
+                ..configuration
+            },
+            crate::model::GameConfiguration {
+                game_mode: GameMode::Elimination,
+                ..configuration
+            },
+        ];
+        for disabled_configuration in disabled_configurations {
+            assert!(find_tied_top_players(&disabled_configuration, &players).is_empty());
+        }
+    }
+
+    #[test]
+    fn contenders_without_the_correct_answer_are_eliminated() {
+        let players = create_players(&[0, 0, 0]);
+        let mut contenders: Vec<_> = players.iter().map(|player| player.id).collect();
+        let mut eliminated = Vec::new();
+        let answers = HashMap::from([
+            (players[0].id, Answer::TheOnion),
+            (players[1].id, Answer::NotTheOnion),
+        ]);
+
+        eliminate_contenders(&mut contenders, &mut eliminated, &answers, Answer::TheOnion);
+
+        assert_eq!(contenders, vec![players[0].id]);
+        assert_eq!(eliminated, vec![players[1].id, players[2].id]);
+    }
+
+    #[test]
+    fn all_contenders_stay_when_nobody_answers_correctly() {
+        let players = create_players(&[0, 0]);
+        let mut contenders: Vec<_> = players.iter().map(|player| player.id).collect();
+        let mut eliminated = Vec::new();
+        let answers = HashMap::from([(players[0].id, Answer::NotTheOnion)]);
+
+        eliminate_contenders(&mut contenders, &mut eliminated, &answers, Answer::TheOnion);
+
+        assert_eq!(contenders, vec![players[0].id, players[1].id]);
+        assert!(eliminated.is_empty());
+    }
+}
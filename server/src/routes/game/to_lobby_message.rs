@@ -2,7 +2,7 @@ use onion_or_not_the_onion_drinking_game_2_shared_library::model as shared_model
 
 use crate::routes::game::from_lobby_message::FromLobbyMessage;
 
-#[derive(Clone, Debug)]
+#[derive(Debug)]
 pub enum ToLobbyMessage {
     Register {
         client_info: ClientInfo,
@@ -10,9 +10,24 @@ pub enum ToLobbyMessage {
         just_watch: bool,
         register_type: RegisterType,
     },
+    /// A big-screen display, which receives all game updates without being a player.
+    RegisterDisplay {
+        client_info: ClientInfo,
+    },
+    ResolveSessionToken {
+        session_token: crate::model::SessionToken,
+        callback: tokio::sync::oneshot::Sender<Option<crate::model::PlayerId>>,
+    },
+    Rejoin {
+        client_info: ClientInfo,
+        session_token: crate::model::SessionToken,
+    },
     Disconnect {
         client_info: ClientInfo,
     },
+    Leave {
+        client_info: ClientInfo,
+    },
     IntervalUpdate,
     ClientMessage {
         client_info: ClientInfo,
@@ -24,6 +39,7 @@ pub enum ToLobbyMessage {
 pub struct ClientInfo {
     pub callback: tokio::sync::mpsc::UnboundedSender<FromLobbyMessage>,
     pub player_id: crate::model::PlayerId,
+    pub connection_id: crate::model::ConnectionId,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
@@ -2,7 +2,7 @@ use std::io::ErrorKind;
 use std::path::PathBuf;
 
 use actix_web::http::StatusCode;
-use actix_web::{web, HttpResponse, ResponseError};
+use actix_web::{web, HttpRequest, HttpResponse, ResponseError};
 
 use futures::stream::StreamExt;
 
@@ -28,11 +28,15 @@ static TRUNK_DIST_DIRECTORY: Lazy<PathBuf> = Lazy::new(|| {
     }
 });
 
-#[tracing::instrument(name = "Index")]
-pub async fn index() -> HttpResponse {
+#[tracing::instrument(name = "Index", skip(req), fields(path = req.path()))]
+pub async fn index(req: HttpRequest) -> HttpResponse {
+    // The client routes, e.g. "/join/ABCD", are rendered with the path of the request
+    let path = req.path().to_owned();
     let renderer = onion_or_not_the_onion_drinking_game_2_client::yew::ServerRenderer::<
         onion_or_not_the_onion_drinking_game_2_client::AppComponent,
-    >::new();
+    >::with_props(move || {
+        onion_or_not_the_onion_drinking_game_2_client::AppComponentProps { path: Some(path) }
+    });
     let body = futures::stream::once(async move { INDEX_HTML_BEFORE.clone() })
         .chain(renderer.render_stream())
         .chain(futures::stream::once(
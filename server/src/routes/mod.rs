@@ -1,3 +1,4 @@
 pub mod distribution;
 pub mod game;
 pub mod index;
+pub mod reports;
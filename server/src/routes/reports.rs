@@ -0,0 +1,199 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use actix_web::error::ErrorUnauthorized;
+use actix_web::{http, web, HttpRequest, Responder};
+
+use anyhow::Context;
+
+use secrecy::ExposeSecret;
+
+use tokio::io::AsyncWriteExt;
+
+/// Questions reported by players during the game, so that the dataset can be pruned.
+#[derive(Clone, Default)]
+pub struct ReportsStorage {
+    internal: Arc<tokio::sync::Mutex<Vec<Report>>>,
+    /// The JSON lines file, which every report is appended to, so that reports outlive restarts.
+    /// It has its own lock, so that listing the reports doesn't wait for appending to the file.
+    optional_file_path: Option<Arc<tokio::sync::Mutex<PathBuf>>>,
+}
+
+impl ReportsStorage {
+    /// Loads the reports persisted in the JSON lines file, which is created with the first report.
+    pub async fn load(file_path: PathBuf) -> anyhow::Result<Self> {
+        let reports = match tokio::fs::read_to_string(&file_path).await {
+            Ok(content) => content
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<Result<Vec<Report>, _>>()
+                .with_context(|| format!("Could not parse reports from {file_path:?}."))?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(error) => {
+                return Err(error)
+                    .with_context(|| format!("Could not read reports from {file_path:?}."))
+            }
+        };
+
+        Ok(Self {
+            internal: Arc::new(tokio::sync::Mutex::new(reports)),
+            optional_file_path: Some(Arc::new(tokio::sync::Mutex::new(file_path))),
+        })
+    }
+
+    pub async fn record(&self, report: Report) {
+        let mut locked_internal = tokio::sync::Mutex::lock(&self.internal).await;
+        // Keeping the report in memory anyway, so that it can still be listed until a restart
+        locked_internal.push(report.clone());
+        drop(locked_internal);
+
+        if let Some(file_path) = &self.optional_file_path {
+            let locked_file_path = tokio::sync::Mutex::lock(file_path).await;
+            if let Err(error) = append_report(&locked_file_path, &report).await {
+                tracing::error!("Could not persist report {report:?}: {error:#}");
+            }
+            drop(locked_file_path);
+        }
+    }
+
+    pub async fn list(&self) -> Vec<Report> {
+        let locked_internal = tokio::sync::Mutex::lock(&self.internal).await;
+        let output = locked_internal.clone();
+        drop(locked_internal);
+
+        output
+    }
+}
+
+async fn append_report(file_path: &Path, report: &Report) -> anyhow::Result<()> {
+    if let Some(directory) = file_path
+        .parent()
+        .filter(|directory| !directory.as_os_str().is_empty())
+    {
+        tokio::fs::create_dir_all(directory).await?;
+    }
+    let mut line = serde_json::to_string(report)?;
+    line.push('\n');
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(file_path)
+        .await?;
+    file.write_all(line.as_bytes()).await?;
+    file.flush().await?;
+    Ok(())
+}
+
+/// A reported question identified by the `id` of its Reddit submission.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Report {
+    pub id: String,
+    pub permalink: String,
+    pub title: String,
+    pub reason: crate::model::ReportReason,
+    pub reported_at_utc: i64,
+}
+
+impl Report {
+    #[must_use]
+    pub fn new(
+        reddit_submission_data: &crate::model::RedditSubmissionData,
+        reason: crate::model::ReportReason,
+    ) -> Self {
+        Self {
+            id: reddit_submission_data.id.clone(),
+            permalink: reddit_submission_data.permalink.clone(),
+            title: reddit_submission_data.title.clone(),
+            reason,
+            reported_at_utc: chrono::Utc::now().timestamp(),
+        }
+    }
+}
+
+/// Whether the request has the configured token as bearer token.
+/// Without a configured token, nobody is authorized.
+fn is_authorized(
+    req: &HttpRequest,
+    reports_configuration: &crate::configuration::ReportsConfiguration,
+) -> bool {
+    let Some(token) = reports_configuration
+        .token
+        .as_ref()
+        .map(ExposeSecret::expose_secret)
+        .filter(|token| !token.is_empty())
+    else {
+        return false;
+    };
+    req.headers()
+        .get(http::header::AUTHORIZATION)
+        .and_then(|header_value| header_value.to_str().ok())
+        .and_then(|header_value| header_value.strip_prefix("Bearer "))
+        .map_or(false, |bearer_token| {
+            is_equal_in_constant_time(bearer_token, token)
+        })
+}
+
+/// Compares the strings without stopping at the first difference,
+/// so that the time taken doesn't tell how much of a guessed token is right.
+fn is_equal_in_constant_time(left: &str, right: &str) -> bool {
+    left.len() == right.len()
+        && left
+            .bytes()
+            .zip(right.bytes())
+            .fold(0, |difference, (left_byte, right_byte)| {
+                difference | (left_byte ^ right_byte)
+            })
+            == 0
+}
+
+#[tracing::instrument(name = "Reports", skip(req, reports_storage, reports_configuration))]
+pub async fn reports(
+    req: HttpRequest,
+    reports_storage: web::Data<ReportsStorage>,
+    reports_configuration: web::Data<crate::configuration::ReportsConfiguration>,
+) -> Result<impl Responder, actix_web::Error> {
+    if !is_authorized(&req, &reports_configuration) {
+        return Err(ErrorUnauthorized("Missing or wrong reports token"));
+    }
+
+    Ok(web::Json(reports_storage.list().await))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::ReportReason;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reports_are_loaded_again_from_their_file() {
+        let file_path = std::env::temp_dir()
+            .join(format!("reports-{}", uuid::Uuid::new_v4()))
+            .join("reports.jsonl");
+        let report = Report {
+            id: "abc123".to_owned(),
+            permalink: "/r/nottheonion/comments/abc123/".to_owned(),
+            title: "Title".to_owned(),
+            reason: ReportReason::Duplicate,
+            reported_at_utc: 1_700_000_000,
+        };
+
+        let reports_storage = ReportsStorage::load(file_path.clone()).await.unwrap();
+        assert!(reports_storage.list().await.is_empty());
+        reports_storage.record(report.clone()).await;
+
+        let reloaded_reports_storage = ReportsStorage::load(file_path.clone()).await.unwrap();
+        assert_eq!(reloaded_reports_storage.list().await, vec![report]);
+
+        let _ = std::fs::remove_dir_all(file_path.parent().unwrap());
+    }
+
+    #[test]
+    fn tokens_are_compared_completely() {
+        assert!(is_equal_in_constant_time("secret", "secret"));
+        assert!(!is_equal_in_constant_time("secret", "secreT"));
+        assert!(!is_equal_in_constant_time("secret", "secret2"));
+        assert!(!is_equal_in_constant_time("", "secret"));
+    }
+}
@@ -6,11 +6,12 @@ use actix_web::{web, App, HttpServer};
 
 use tracing_actix_web::TracingLogger;
 
-use crate::configuration::Configuration;
+use crate::configuration::{Configuration, ReportsConfiguration};
 use crate::routes::distribution::distribution;
 use crate::routes::game::lobbies_storage::LobbiesStorage;
-use crate::routes::game::{create_lobby, join_lobby};
+use crate::routes::game::{create_lobby, display_lobby, join_lobby, rejoin_lobby};
 use crate::routes::index::{index, static_file};
+use crate::routes::reports::{reports, ReportsStorage};
 
 pub struct Application {
     #[allow(dead_code)]
@@ -26,7 +27,8 @@ impl Application {
         );
         let tcp_listener = TcpListener::bind(application_address)?;
         let port = tcp_listener.local_addr().unwrap().port();
-        let server = run(tcp_listener)?;
+        let reports_storage = ReportsStorage::load(configuration.reports.file_path.clone()).await?;
+        let server = run(tcp_listener, reports_storage, configuration.reports)?;
         Ok(Self { port, server })
     }
 
@@ -35,19 +37,31 @@ impl Application {
     }
 }
 
-fn run(tcp_listener: TcpListener) -> anyhow::Result<Server> {
+fn run(
+    tcp_listener: TcpListener,
+    reports_storage: ReportsStorage,
+    reports_configuration: ReportsConfiguration,
+) -> anyhow::Result<Server> {
     let lobbies_storage = LobbiesStorage::default();
+    let reports_configuration = web::Data::new(reports_configuration);
     let server = HttpServer::new(move || {
         App::new()
             .wrap(TracingLogger::default())
             .wrap(Compress::default())
             .route("/", web::get().to(index))
             .route("/index.html", web::get().to(index))
+            .route("/join/{invite_code}", web::get().to(index))
+            .route("/play/{invite_code}", web::get().to(index))
             .route("/api/create", web::get().to(create_lobby))
             .route("/api/join/{invite_code}", web::get().to(join_lobby))
+            .route("/api/rejoin/{invite_code}", web::get().to(rejoin_lobby))
+            .route("/api/display/{invite_code}", web::get().to(display_lobby))
             .route("/api/distribution", web::get().to(distribution))
+            .route("/api/reports", web::get().to(reports))
             .route("/{filename:.*}", web::get().to(static_file))
             .app_data(web::Data::new(lobbies_storage.clone()))
+            .app_data(web::Data::new(reports_storage.clone()))
+            .app_data(reports_configuration.clone())
     })
     .listen(tcp_listener)?
     .run();
@@ -13,6 +13,13 @@ pub struct Game {
     pub configuration: GameConfiguration,
     pub game_state: GameState,
     pub players: Vec<Player>,
+    pub host: Option<PlayerId>,
+    /// Start of the pause, which freezes the remaining time of the current question or solution.
+    pub paused_at: Option<DateTime<Utc>>,
+    /// Players, who have been voted to be kicked, with the players, who have voted for it.
+    pub kick_votes: HashMap<PlayerId, HashSet<PlayerId>>,
+    /// The latest chat messages and reactions, oldest first.
+    pub chat_history: Vec<ChatMessage>,
     pub this_player_id: PlayerId,
 }
 
@@ -22,6 +29,28 @@ impl Game {
             .iter()
             .find(|player| player.id == self.this_player_id)
     }
+
+    pub fn is_this_player_host(&self) -> bool {
+        self.host == Some(self.this_player_id)
+    }
+
+    /// Appends a chat message, which arrived after the last full update,
+    /// and drops the oldest ones beyond `MAXIMUM_COUNT_OF_CHAT_MESSAGES`.
+    pub fn push_chat_message(&mut self, chat_message: ChatMessage) {
+        self.chat_history.push(chat_message);
+        let count_of_dropped_chat_messages = self
+            .chat_history
+            .len()
+            .saturating_sub(MAXIMUM_COUNT_OF_CHAT_MESSAGES);
+        self.chat_history.drain(..count_of_dropped_chat_messages);
+    }
+
+    /// The captain of a team is its first connected member with `PlayType::Player`.
+    pub fn get_team_captain(&self, team: TeamId) -> Option<&Player> {
+        self.players.iter().find(|player| {
+            player.team == Some(team) && !player.is_watcher() && !player.disconnected
+        })
+    }
 }
 
 /* INVITE CODE */
@@ -46,25 +75,171 @@ pub struct GameConfiguration {
     pub count_of_questions: u64,
     pub minimum_score_per_question: Option<i64>,
     pub maximum_answer_time_per_question: Option<u64>,
+    pub solution_time_in_seconds: u64,
+    pub scoring_mode: ScoringMode,
+    pub scoring_rules: ScoringRules,
+    pub drink_rules: DrinkRules,
+    pub penalty_mode: PenaltyMode,
+    pub team_setup: Option<TeamSetup>,
+    pub sudden_death_tiebreaker: bool,
+    pub game_mode: GameMode,
+    /// Ignores `count_of_questions` and plays until no question is left or the host ends the game.
+    pub marathon: bool,
+}
+
+/* GAME MODE */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub enum GameMode {
+    Classic,
+    /// A wrong or missing answer turns a player into a watcher until only one is left.
+    Elimination,
+    /// Like `GameMode::Elimination`, but a wrong or missing answer only costs one of the lives.
+    Lives {
+        count_of_lives: u8,
+    },
+}
+
+/* TEAM SETUP */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub struct TeamSetup {
+    pub count_of_teams: u8,
+    pub team_answer_mode: TeamAnswerMode,
+}
+
+/* TEAM ANSWER MODE */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub enum TeamAnswerMode {
+    MajorityVote,
+    Captain,
+}
+
+/* TEAM ID */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub struct TeamId(pub u8);
+
+impl Display for TeamId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", u16::from(self.0) + 1)
+    }
+}
+
+/* SCORING MODE */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub enum ScoringMode {
+    Classic,
+    SpeedBonus,
+    ConfidenceBetting,
+}
+
+/* SCORING RULES */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub struct ScoringRules {
+    pub points_for_correct_answer: u16,
+    pub points_for_minority_bonus: u16,
+    pub minority_threshold_in_percent: u8,
+    pub points_penalty_for_wrong_answer: Option<u16>,
+    /// Count of correct answers in a row, from which on every correct answer gets a bonus.
+    pub streak_length_for_bonus: u8,
+    pub points_for_streak_bonus: u16,
+}
+
+impl ScoringRules {
+    /// Whether a streak is long enough for the streak bonus, so that players are on fire.
+    /// Without bonus points, streaks are turned off and nobody is on fire.
+    pub fn is_on_fire(&self, streak: u16) -> bool {
+        self.points_for_streak_bonus > 0 && streak >= u16::from(self.streak_length_for_bonus)
+    }
+}
+
+/* DRINK RULES */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub struct DrinkRules {
+    pub sips_for_wrong_answer: u8,
+    pub sips_for_missing_answer: u8,
+    pub sips_for_everyone_wrong: u8,
+    pub sips_to_hand_out_for_minority_correct: u8,
+    pub sips_for_broken_streak: u8,
+}
+
+/* PENALTY MODE */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub enum PenaltyMode {
+    Alcohol,
+    SoftDrink,
+    Dare,
 }
 
 /* GAME STATE */
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub enum GameState {
     InLobby,
     Playing {
         index_of_current_question: usize,
         playing_state: PlayingState,
+        /// Players, who have lost all their lives, in order of their elimination.
+        eliminated_players: Vec<PlayerId>,
+    },
+    /// Sudden-death questions for the players tied on the first place.
+    Tiebreaker {
+        index_of_current_question: usize,
+        playing_state: PlayingState,
+        contenders: Vec<PlayerId>,
+        eliminated: Vec<PlayerId>,
     },
     Aftermath {
         ranked_players: Vec<(PlayerId, PlayerName, u16)>,
+        drink_leaderboard: Vec<(PlayerId, PlayerName, u16, u16)>,
+        /// Average time in milliseconds each player needed to answer, fastest first.
+        average_response_times: Vec<(PlayerId, PlayerName, u64)>,
+        tiebreaker_winner: Option<PlayerId>,
+        /// Players, who have lost all their lives, in order of their elimination
+        /// with the index of the question, which eliminated them.
+        eliminated_players: Vec<(PlayerId, usize)>,
+        count_of_played_questions: usize,
         restart_requests: Vec<PlayerId>,
     },
 }
 
+impl GameState {
+    /// The playing state of the regular questions or of the tiebreaker.
+    pub fn playing_state(&self) -> Option<&PlayingState> {
+        match self {
+            GameState::Playing { playing_state, .. }
+            | GameState::Tiebreaker { playing_state, .. } => Some(playing_state),
+            GameState::InLobby | GameState::Aftermath { .. } => None,
+        }
+    }
+}
+
 /* PLAYING STATE */
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub enum PlayingState {
     Question {
@@ -72,12 +247,20 @@ pub enum PlayingState {
         time_until: Option<DateTime<Utc>>,
         answers: Vec<PlayerId>,
         own_answer: Option<Answer>,
+        own_stake: Option<u16>,
+        /// Players, who have voted to discard the question before its solution is revealed.
+        skip_request: HashSet<PlayerId>,
     },
     Solution {
         current_question: AnsweredQuestion,
         time_until: DateTime<Utc>,
         answers: HashMap<PlayerId, Answer>,
+        /// Time in milliseconds the fastest correct player needed to answer.
+        fastest_correct_answer: Option<(PlayerId, PlayerName, u64)>,
         skip_request: HashSet<PlayerId>,
+        drinks: HashMap<PlayerId, Drinks>,
+        stakes: HashMap<PlayerId, u16>,
+        own_report: Option<ReportReason>,
     },
 }
 
@@ -112,6 +295,113 @@ pub enum Answer {
     NotTheOnion,
 }
 
+/* DRINKS */
+
+#[derive(
+    Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub struct Drinks {
+    pub sips: u16,
+    pub sips_to_hand_out: u16,
+    pub reasons: Vec<DrinkReason>,
+    pub penalty_card: Option<PenaltyCard>,
+}
+
+/* PENALTY CARD */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub struct PenaltyCard {
+    pub penalty_mode: PenaltyMode,
+    pub number: u8,
+}
+
+/* DRINK REASON */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub enum DrinkReason {
+    WrongAnswer,
+    MissingAnswer,
+    EveryoneWrong,
+    MinorityCorrect,
+    /// A streak long enough for the streak bonus ended with a wrong or missing answer.
+    StreakBroken,
+}
+
+/* REPORT REASON */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub enum ReportReason {
+    DeletedPost,
+    Duplicate,
+    NeedsImage,
+    Misleading,
+}
+
+/* CHAT MESSAGE */
+
+pub const MAXIMUM_CHAT_MESSAGE_LENGTH: usize = 200;
+pub const MAXIMUM_COUNT_OF_CHAT_MESSAGES: usize = 50;
+
+#[derive(Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ChatMessage {
+    pub player_id: PlayerId,
+    pub player_name: PlayerName,
+    pub content: ChatContent,
+    pub sent_at: DateTime<Utc>,
+}
+
+/* CHAT CONTENT */
+
+#[derive(
+    Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub enum ChatContent {
+    Text(String),
+    Reaction(Emoji),
+}
+
+/* EMOJI */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub enum Emoji {
+    Laughing,
+    Surprised,
+    Facepalm,
+    ThumbsUp,
+    Cheers,
+}
+
+impl Emoji {
+    pub const ALL: [Emoji; 5] = [
+        Emoji::Laughing,
+        Emoji::Surprised,
+        Emoji::Facepalm,
+        Emoji::ThumbsUp,
+        Emoji::Cheers,
+    ];
+}
+
+impl Display for Emoji {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let emoji = match self {
+            Emoji::Laughing => "😂",
+            Emoji::Surprised => "😮",
+            Emoji::Facepalm => "🤦",
+            Emoji::ThumbsUp => "👍",
+            Emoji::Cheers => "🍻",
+        };
+        write!(f, "{emoji}")
+    }
+}
+
 /* PLAYER */
 
 #[derive(
@@ -121,6 +411,13 @@ pub struct Player {
     pub id: PlayerId,
     pub name: PlayerName,
     pub play_type: PlayType,
+    pub team: Option<TeamId>,
+    /// Remaining lives in `GameMode::Elimination` and `GameMode::Lives`.
+    pub lives: Option<u8>,
+    /// Count of the latest questions in a row, which the player has answered correctly.
+    pub streak: u16,
+    /// Whether the player has lost their connection and may still rejoin.
+    pub disconnected: bool,
 }
 
 impl Player {
@@ -142,6 +439,19 @@ impl Display for PlayerId {
     }
 }
 
+/* SESSION TOKEN */
+
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
+)]
+pub struct SessionToken(pub Uuid);
+
+impl Display for SessionToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 /* PLAYER NAME */
 
 #[derive(
@@ -161,6 +471,10 @@ impl Display for PlayerName {
     Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, serde::Serialize, serde::Deserialize,
 )]
 pub enum PlayType {
-    Player { points: u16 },
+    Player {
+        points: u16,
+        sips_taken: u16,
+        sips_handed_out: u16,
+    },
     Watcher,
 }
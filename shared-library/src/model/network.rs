@@ -1,13 +1,33 @@
-use crate::model::game::Game;
+use chrono::{DateTime, Utc};
+
+use crate::model::game::{ChatMessage, Game, SessionToken};
 
 #[derive(Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub enum ClientMessage {
     RequestFullUpdate,
 
     StartGame,
+    EndGame,
+    Pause,
+    Resume,
+    KickPlayer(crate::model::game::PlayerId),
+    SwitchPlayType(crate::model::game::PlayerId),
+    TransferHost(crate::model::game::PlayerId),
+    UpdateConfiguration(crate::model::game::GameConfiguration),
+    AssignTeam(crate::model::game::PlayerId, crate::model::game::TeamId),
+    BalanceTeams,
+    VoteKick(crate::model::game::PlayerId),
+
     ChooseAnswer(crate::model::game::Answer),
+    PlaceStake(u16),
     RequestSkip,
+    ReportQuestion {
+        reason: crate::model::game::ReportReason,
+    },
     RequestPlayAgain,
+
+    Chat(String),
+    React(crate::model::game::Emoji),
 }
 
 impl TryFrom<&[u8]> for ClientMessage {
@@ -52,16 +72,30 @@ impl From<bincode::Error> for ClientMessageTryIntoByteVecError {
 
 #[derive(Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
 pub enum ServerMessage {
-    LobbyCreated(Game),
-    LobbyJoined(Game),
+    LobbyCreated(Game, SessionToken),
+    LobbyJoined(Game, SessionToken),
+    /// The game for a big-screen display, which doesn't take part in it.
+    DisplayConnected(Game),
 
     GameFullUpdate(Game),
+    ChatMessage(ChatMessage),
 
     AnswerNotInTimeLimit,
     PlayerNameAlreadyInUse,
+    InvalidConfiguration,
+    RejoinFailed,
+    Kicked,
+}
+
+/// A ServerMessage together with the server's clock at the time of sending it,
+/// so that clients can correct the countdowns for their own clocks.
+#[derive(Clone, Eq, PartialEq, Debug, serde::Serialize, serde::Deserialize)]
+pub struct TimestampedServerMessage {
+    pub sent_at: DateTime<Utc>,
+    pub server_message: ServerMessage,
 }
 
-impl TryFrom<&[u8]> for ServerMessage {
+impl TryFrom<&[u8]> for TimestampedServerMessage {
     type Error = ServerMessageTryFromByteSliceError;
 
     fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
@@ -69,7 +103,7 @@ impl TryFrom<&[u8]> for ServerMessage {
     }
 }
 
-impl TryInto<Vec<u8>> for ServerMessage {
+impl TryInto<Vec<u8>> for TimestampedServerMessage {
     type Error = ServerMessageTryIntoByteVecError;
 
     fn try_into(self) -> Result<Vec<u8>, Self::Error> {